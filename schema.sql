@@ -8,6 +8,16 @@ PRAGMA foreign_keys = ON;
 -- CORE TABLES
 -- ============================================================================
 
+-- series: Book series with numbered volumes (e.g., "The Expanse")
+CREATE TABLE series (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    author TEXT,
+    expected_volumes INTEGER CHECK(expected_volumes IS NULL OR expected_volumes > 0),
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
 -- books: Main book/library entity
 -- Stores all book metadata, progress tracking, and organization status
 CREATE TABLE books (
@@ -22,6 +32,8 @@ CREATE TABLE books (
     total_minutes INTEGER, -- For audiobooks
     current_page_text INTEGER DEFAULT 0,
     current_minutes_audio INTEGER DEFAULT 0,
+    progress_unit TEXT NOT NULL DEFAULT 'page' CHECK(progress_unit IN ('page', 'percentage', 'location')), -- Unit sessions record positions in
+    total_locations INTEGER CHECK(total_locations IS NULL OR total_locations > 0), -- Kindle locations, for the location unit
     status TEXT NOT NULL DEFAULT 'not_started' CHECK(status IN ('not_started', 'reading', 'paused', 'abandoned', 'completed', 'rereading')),
     is_archived INTEGER DEFAULT 0 CHECK(is_archived IN (0, 1)),
     is_wishlist INTEGER DEFAULT 0 CHECK(is_wishlist IN (0, 1)),
@@ -29,7 +41,57 @@ CREATE TABLE books (
     url TEXT, -- For articles and PDFs
     added_at TEXT NOT NULL DEFAULT (datetime('now')),
     updated_at TEXT NOT NULL DEFAULT (datetime('now')),
-    status_changed_at TEXT -- When status was last changed
+    status_changed_at TEXT, -- When status was last changed
+    series_id INTEGER, -- FK to series (null if standalone)
+    series_index REAL, -- Volume number within the series (e.g., 3, 2.5)
+    FOREIGN KEY (series_id) REFERENCES series(id) ON DELETE SET NULL
+);
+
+-- book_editions: Additional formats of a book (the book row is the primary edition)
+-- Progress is shared across editions by position percentage
+CREATE TABLE book_editions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    book_id INTEGER NOT NULL,
+    format TEXT NOT NULL CHECK(format IN ('physical_book', 'ebook', 'audiobook', 'article', 'PDF', 'comic')),
+    label TEXT, -- e.g., "Folio paperback"
+    isbn TEXT,
+    total_pages INTEGER,
+    total_minutes INTEGER, -- For audiobooks
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+-- audiobook_chapters: Chapter list of an audiobook with each chapter's start in the recording
+CREATE TABLE audiobook_chapters (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    book_id INTEGER NOT NULL,
+    position INTEGER NOT NULL, -- 0-based order within the book
+    title TEXT NOT NULL,
+    start_seconds INTEGER NOT NULL CHECK(start_seconds >= 0),
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+    UNIQUE(book_id, position)
+);
+
+-- authors: People credited on books (authors, translators, editors, narrators)
+-- Names are unique ignoring case so "le guin" and "Le Guin" resolve to one row
+CREATE TABLE authors (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- book_authors: Many-to-many relationship between books and authors with a role
+-- books.author keeps the display string of the 'author' role contributors
+CREATE TABLE book_authors (
+    book_id INTEGER NOT NULL,
+    author_id INTEGER NOT NULL,
+    role TEXT NOT NULL DEFAULT 'author' CHECK(role IN ('author', 'translator', 'editor', 'narrator')),
+    position INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (book_id, author_id, role),
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES authors(id) ON DELETE CASCADE
 );
 
 -- book_readings: Tracks multiple readings (rereads) of the same book
@@ -42,6 +104,8 @@ CREATE TABLE book_readings (
     completed_at TEXT,
     status TEXT NOT NULL DEFAULT 'not_started' CHECK(status IN ('not_started', 'reading', 'paused', 'abandoned', 'completed')),
     created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    rating REAL CHECK(rating IS NULL OR (rating >= 0.5 AND rating <= 5.0)), -- Half-star steps
+    review TEXT,
     FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
     UNIQUE(book_id, reading_number)
 );
@@ -63,12 +127,72 @@ CREATE TABLE reading_sessions (
     photo_path TEXT, -- Path to page scan photo (if used)
     created_at TEXT NOT NULL DEFAULT (datetime('now')),
     updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    edition_id INTEGER, -- FK to book_editions (null = the book's own format)
+    started_at TEXT, -- RFC3339 start timestamp with the local offset
+    ended_at TEXT, -- RFC3339 end timestamp (may be on the day after session_date)
+    timezone TEXT, -- IANA time zone the session was recorded in
+    location TEXT, -- Where the session took place, e.g. "Home", "Train"
+    device TEXT CHECK(device IS NULL OR device IN ('paper', 'ereader', 'phone', 'tablet', 'computer', 'audio', 'other')),
+    mood_before INTEGER CHECK(mood_before IS NULL OR mood_before BETWEEN 1 AND 5),
+    mood_after INTEGER CHECK(mood_after IS NULL OR mood_after BETWEEN 1 AND 5),
+    energy_before INTEGER CHECK(energy_before IS NULL OR energy_before BETWEEN 1 AND 5),
+    energy_after INTEGER CHECK(energy_after IS NULL OR energy_after BETWEEN 1 AND 5),
+    interruptions INTEGER CHECK(interruptions IS NULL OR interruptions >= 0),
+    playback_speed REAL CHECK(playback_speed IS NULL OR playback_speed > 0), -- Audiobooks, e.g. 1.5
+    position_unit TEXT CHECK(position_unit IS NULL OR position_unit IN ('percentage', 'location')), -- Set when positions were recorded instead of pages
+    start_position REAL, -- In position_unit; start_page/end_page hold the converted pages
+    end_position REAL,
     FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
     FOREIGN KEY (reading_id) REFERENCES book_readings(id) ON DELETE SET NULL,
+    FOREIGN KEY (edition_id) REFERENCES book_editions(id) ON DELETE SET NULL,
     CHECK(end_page IS NULL OR start_page IS NULL OR end_page >= start_page),
     CHECK(duration_seconds IS NULL OR duration_seconds >= 0)
 );
 
+-- session_photos: Page scans attached to reading sessions, stored in the app data folder by content hash
+CREATE TABLE session_photos (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id INTEGER NOT NULL,
+    hash TEXT NOT NULL, -- SHA-256 of the image; shared by photos with the same content
+    extension TEXT NOT NULL CHECK(extension IN ('jpg', 'png', 'webp')),
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    source TEXT, -- File path the photo was attached from
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (session_id) REFERENCES reading_sessions(id) ON DELETE CASCADE
+);
+
+-- active_sessions: Session timer in progress, saved as a reading session when finished
+CREATE TABLE active_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    book_id INTEGER NOT NULL,
+    reading_id INTEGER,
+    edition_id INTEGER,
+    start_page INTEGER,
+    started_at TEXT NOT NULL, -- RFC3339 timestamp
+    paused_at TEXT, -- Start of the current pause (null while running)
+    paused_seconds INTEGER NOT NULL DEFAULT 0 CHECK(paused_seconds >= 0), -- Completed pauses
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+    FOREIGN KEY (reading_id) REFERENCES book_readings(id) ON DELETE SET NULL,
+    FOREIGN KEY (edition_id) REFERENCES book_editions(id) ON DELETE SET NULL
+);
+
+-- progress_corrections: Audit log of corrected book positions and the session changes they made
+CREATE TABLE progress_corrections (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    book_id INTEGER NOT NULL,
+    mode TEXT NOT NULL CHECK(mode IN ('adjustment', 'rebase')),
+    unit TEXT NOT NULL CHECK(unit IN ('pages', 'minutes')),
+    previous_value INTEGER NOT NULL, -- Position before the correction
+    corrected_value INTEGER NOT NULL,
+    adjustment_session_id INTEGER, -- Session added by an adjustment correction
+    changes TEXT NOT NULL DEFAULT '[]', -- JSON array of {session_id, field, old_value, new_value}
+    note TEXT,
+    created_at TEXT NOT NULL, -- RFC3339 timestamp
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+    FOREIGN KEY (adjustment_session_id) REFERENCES reading_sessions(id) ON DELETE SET NULL
+);
+
 -- notes: Table for book annotations and notes
 CREATE TABLE notes (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -190,6 +314,12 @@ CREATE TABLE settings (
     updated_at TEXT NOT NULL DEFAULT (datetime('now'))
 );
 
+-- applied_migrations: One-off data migrations that have already run
+CREATE TABLE applied_migrations (
+    name TEXT PRIMARY KEY,
+    applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
 -- backups: Backup metadata and history
 CREATE TABLE backups (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -200,6 +330,31 @@ CREATE TABLE backups (
     created_at TEXT NOT NULL DEFAULT (datetime('now'))
 );
 
+-- book_covers: Cover images stored in the app data folder, named by content hash
+CREATE TABLE book_covers (
+    book_id INTEGER PRIMARY KEY,
+    hash TEXT NOT NULL, -- SHA-256 of the image; shared by books with the same cover
+    extension TEXT NOT NULL CHECK(extension IN ('jpg', 'png', 'webp')),
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    source TEXT, -- File path or URL the cover was imported from
+    imported_at TEXT NOT NULL,
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+-- book_metadata_cache: Metadata fetched by ISBN, kept for offline lookups
+CREATE TABLE book_metadata_cache (
+    isbn TEXT PRIMARY KEY, -- ISBN-13
+    title TEXT NOT NULL,
+    authors TEXT NOT NULL DEFAULT '[]', -- JSON array of names
+    total_pages INTEGER,
+    publication_year INTEGER,
+    cover_url TEXT,
+    genre TEXT,
+    source TEXT NOT NULL, -- Provider that supplied the data, e.g. 'open_library'
+    fetched_at TEXT NOT NULL
+);
+
 -- ============================================================================
 -- INDEXES FOR PERFORMANCE
 -- ============================================================================
@@ -212,6 +367,20 @@ CREATE INDEX idx_books_is_wishlist ON books(is_wishlist);
 CREATE INDEX idx_books_added_at ON books(added_at);
 CREATE INDEX idx_books_title ON books(title);
 CREATE INDEX idx_books_author ON books(author);
+CREATE INDEX idx_books_series ON books(series_id, series_index);
+
+-- book_covers indexes
+CREATE INDEX idx_book_covers_hash ON book_covers(hash);
+
+-- series indexes
+CREATE INDEX idx_series_name ON series(name);
+
+-- book_editions indexes
+CREATE INDEX idx_book_editions_book_id ON book_editions(book_id);
+CREATE INDEX idx_reading_sessions_edition_id ON reading_sessions(edition_id);
+
+-- authors indexes
+CREATE INDEX idx_book_authors_author_id ON book_authors(author_id);
 
 -- book_readings indexes
 CREATE INDEX idx_book_readings_book_id ON book_readings(book_id);
@@ -224,6 +393,11 @@ CREATE INDEX idx_reading_sessions_reading_id ON reading_sessions(reading_id);
 CREATE INDEX idx_reading_sessions_session_date ON reading_sessions(session_date);
 CREATE INDEX idx_reading_sessions_date_book ON reading_sessions(session_date, book_id);
 
+-- session_photos indexes
+CREATE INDEX idx_session_photos_session_id ON session_photos(session_id);
+CREATE INDEX idx_session_photos_hash ON session_photos(hash);
+CREATE UNIQUE INDEX idx_session_photos_session_hash ON session_photos(session_id, hash);
+
 -- notes indexes
 CREATE INDEX idx_notes_book_id ON notes(book_id);
 CREATE INDEX idx_notes_reading_id ON notes(reading_id);
@@ -287,6 +461,27 @@ BEGIN
     UPDATE collections SET updated_at = datetime('now') WHERE id = NEW.id;
 END;
 
+-- Trigger to update updated_at timestamp on series
+CREATE TRIGGER update_series_timestamp 
+AFTER UPDATE ON series
+BEGIN
+    UPDATE series SET updated_at = datetime('now') WHERE id = NEW.id;
+END;
+
+-- Trigger to update updated_at timestamp on book_editions
+CREATE TRIGGER update_book_editions_timestamp 
+AFTER UPDATE ON book_editions
+BEGIN
+    UPDATE book_editions SET updated_at = datetime('now') WHERE id = NEW.id;
+END;
+
+-- Trigger to update updated_at timestamp on authors
+CREATE TRIGGER update_authors_timestamp 
+AFTER UPDATE ON authors
+BEGIN
+    UPDATE authors SET updated_at = datetime('now') WHERE id = NEW.id;
+END;
+
 -- Trigger to update updated_at timestamp on goals
 CREATE TRIGGER update_goals_timestamp 
 AFTER UPDATE ON goals
@@ -323,3 +518,18 @@ BEGIN
     UPDATE books SET status_changed_at = datetime('now') WHERE id = NEW.id;
 END;
 
+-- ============================================================================
+-- VIEWS
+-- ============================================================================
+
+-- book_ratings: Per-book rating aggregates across all rated readings
+CREATE VIEW book_ratings AS
+SELECT r.book_id,
+       AVG(r.rating) AS average_rating,
+       (SELECT r2.rating FROM book_readings r2
+        WHERE r2.book_id = r.book_id AND r2.rating IS NOT NULL
+        ORDER BY r2.reading_number DESC LIMIT 1) AS latest_rating,
+       COUNT(r.rating) AS ratings_count
+FROM book_readings r
+WHERE r.rating IS NOT NULL
+GROUP BY r.book_id;
@@ -0,0 +1,86 @@
+use crate::app::dtos::{
+    AuthorDto, AuthorDetailDto, BookContributorDto, CreateAuthorCommand, UpdateAuthorCommand,
+    SetBookContributorsCommand, MergeAuthorsCommand, ListAuthorsFilters,
+};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::AuthorService;
+
+/// Tauri command: Create a new author
+#[tauri::command]
+pub fn create_author(
+    command: CreateAuthorCommand,
+    state: tauri::State<AppState>,
+) -> Result<AuthorDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().create(command)
+}
+
+/// Tauri command: List all authors
+#[tauri::command]
+pub fn list_authors(
+    filters: Option<ListAuthorsFilters>,
+    state: tauri::State<AppState>,
+) -> Result<Vec<AuthorDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().list(filters.unwrap_or_default())
+}
+
+/// Tauri command: Get an author page with their books and reading stats
+#[tauri::command]
+pub fn get_author(
+    id: i64,
+    state: tauri::State<AppState>,
+) -> Result<AuthorDetailDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().get(id)
+}
+
+/// Tauri command: Rename an author
+#[tauri::command]
+pub fn update_author(
+    command: UpdateAuthorCommand,
+    state: tauri::State<AppState>,
+) -> Result<AuthorDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().update(command)
+}
+
+/// Tauri command: Delete an author that has no books
+#[tauri::command]
+pub fn delete_author(
+    id: i64,
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().delete(id)
+}
+
+/// Tauri command: Merge a duplicate author into another
+#[tauri::command]
+pub fn merge_authors(
+    command: MergeAuthorsCommand,
+    state: tauri::State<AppState>,
+) -> Result<AuthorDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().merge(command)
+}
+
+/// Tauri command: Get the contributors (authors, translators, editors, narrators) of a book
+#[tauri::command]
+pub fn get_book_contributors(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Vec<BookContributorDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().get_book_contributors(book_id)
+}
+
+/// Tauri command: Replace the contributors of a book
+#[tauri::command]
+pub fn set_book_contributors(
+    command: SetBookContributorsCommand,
+    state: tauri::State<AppState>,
+) -> Result<Vec<BookContributorDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.author_service().set_book_contributors(command)
+}
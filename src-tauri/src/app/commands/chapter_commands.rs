@@ -0,0 +1,33 @@
+use crate::app::dtos::{ChapterDto, ChapterProgressDto, SetChaptersCommand};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::ChapterService;
+
+/// Tauri command: List the chapters of an audiobook
+#[tauri::command]
+pub fn list_chapters(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Vec<ChapterDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.chapter_service().list(book_id)
+}
+
+/// Tauri command: Replace the chapters of an audiobook
+#[tauri::command]
+pub fn set_chapters(
+    command: SetChaptersCommand,
+    state: tauri::State<AppState>,
+) -> Result<Vec<ChapterDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.chapter_service().set_chapters(command)
+}
+
+/// Tauri command: Get an audiobook's listening progress by chapter
+#[tauri::command]
+pub fn get_chapter_progress(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<ChapterProgressDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.chapter_service().get_progress(book_id)
+}
@@ -1,4 +1,4 @@
-use crate::app::dtos::{CollectionDto, CreateCollectionCommand, UpdateCollectionCommand, AddBooksToCollectionCommand, ListCollectionsFilters};
+use crate::app::dtos::{CollectionDto, CreateCollectionCommand, UpdateCollectionCommand, AddBooksToCollectionCommand, ListCollectionsFilters, CollectionStatsDto};
 use crate::app::state::AppState;
 use crate::core::interfaces::primary::CollectionService;
 
@@ -63,3 +63,13 @@ pub fn remove_book_from_collection(
     container.collection_service().remove_book(collection_id, book_id)
 }
 
+/// Tauri command: Get aggregated reading statistics for a collection
+#[tauri::command]
+pub fn get_collection_stats(
+    collection_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<CollectionStatsDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.collection_service().get_stats(collection_id)
+}
+
@@ -0,0 +1,71 @@
+use crate::app::dtos::{BookCoverDto, CoverBackupDto, CoverImageDto, ImportCoverCommand, RemoteCoversResultDto};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::CoverService;
+
+/// Tauri command: Import a book cover from a file path or URL into the app's covers folder
+#[tauri::command]
+pub fn import_book_cover(
+    command: ImportCoverCommand,
+    state: tauri::State<AppState>,
+) -> Result<BookCoverDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().import_cover(command)
+}
+
+/// Tauri command: Download and store every book's cover_url that is not stored locally yet
+#[tauri::command]
+pub fn import_remote_covers(
+    state: tauri::State<AppState>,
+) -> Result<RemoteCoversResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().import_remote_covers()
+}
+
+/// Tauri command: Get a book's local cover (or its thumbnail) as a data URL
+#[tauri::command]
+pub fn get_book_cover(
+    book_id: i64,
+    thumbnail: Option<bool>,
+    state: tauri::State<AppState>,
+) -> Result<Option<CoverImageDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().get_cover_image(book_id, thumbnail.unwrap_or(false))
+}
+
+/// Tauri command: Remove a book's local cover
+#[tauri::command]
+pub fn remove_book_cover(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().remove_cover(book_id)
+}
+
+/// Tauri command: Delete stored cover files that no book uses anymore
+#[tauri::command]
+pub fn cleanup_orphaned_covers(
+    state: tauri::State<AppState>,
+) -> Result<usize, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().cleanup_orphaned_covers()
+}
+
+/// Tauri command: Get all local covers for inclusion in a full backup
+#[tauri::command]
+pub fn backup_book_covers(
+    state: tauri::State<AppState>,
+) -> Result<Vec<CoverBackupDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().backup_covers()
+}
+
+/// Tauri command: Restore covers from a full backup
+#[tauri::command]
+pub fn restore_book_covers(
+    covers: Vec<CoverBackupDto>,
+    state: tauri::State<AppState>,
+) -> Result<usize, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.cover_service().restore_covers(covers)
+}
@@ -0,0 +1,53 @@
+use crate::app::dtos::{EditionDto, WorkProgressDto, CreateEditionCommand, UpdateEditionCommand};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::EditionService;
+
+/// Tauri command: Add an edition (another format) to a book
+#[tauri::command]
+pub fn create_edition(
+    command: CreateEditionCommand,
+    state: tauri::State<AppState>,
+) -> Result<EditionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.edition_service().create(command)
+}
+
+/// Tauri command: List the editions of a book
+#[tauri::command]
+pub fn list_editions(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Vec<EditionDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.edition_service().list(book_id)
+}
+
+/// Tauri command: Update an edition
+#[tauri::command]
+pub fn update_edition(
+    command: UpdateEditionCommand,
+    state: tauri::State<AppState>,
+) -> Result<EditionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.edition_service().update(command)
+}
+
+/// Tauri command: Delete an edition by ID
+#[tauri::command]
+pub fn delete_edition(
+    id: i64,
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.edition_service().delete(id)
+}
+
+/// Tauri command: Get a book's progress unified across its editions
+#[tauri::command]
+pub fn get_work_progress(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<WorkProgressDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.edition_service().get_work_progress(book_id)
+}
@@ -0,0 +1,89 @@
+use crate::app::dtos::{
+    ExportCitationsCommand, ExportFileDto, ExportMarkdownCommand, ExportTableCommand, MarkdownExportDto,
+};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::{
+    AgendaService, BookService, ExportService, GoalService, JournalService, NoteService, SessionService,
+};
+use serde::Serialize;
+
+/// Tauri command: Export the whole library in the given format ("storygraph_csv")
+#[tauri::command]
+pub fn export_library(
+    format: String,
+    state: tauri::State<AppState>,
+) -> Result<ExportFileDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.export_service().export_library(format)
+}
+
+/// Tauri command: Export notes and journal as Markdown files (Obsidian vault layout)
+#[tauri::command]
+pub fn export_markdown(
+    command: ExportMarkdownCommand,
+    state: tauri::State<AppState>,
+) -> Result<MarkdownExportDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.export_service().export_markdown(command)
+}
+
+/// Tauri command: Export a printable PDF of a book's summary, reading timeline and notes
+#[tauri::command]
+pub fn export_book_pdf(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<ExportFileDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    let summary = container.book_service().generate_summary(book_id)?;
+    container.export_service().export_book_pdf(book_id, summary)
+}
+
+/// Tauri command: Export books, sessions, notes, goals, journal entries or agenda blocks as CSV/JSONL
+#[tauri::command]
+pub fn export_table(
+    command: ExportTableCommand,
+    state: tauri::State<AppState>,
+) -> Result<ExportFileDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+
+    // Rows come from the list services, so filters and columns match the list commands
+    let records = match command.dataset.as_str() {
+        "books" => to_records(container.book_service().list(command.book_filters.unwrap_or_default())?)?,
+        "sessions" => to_records(container.session_service().list(command.session_filters.unwrap_or_default())?)?,
+        "notes" => to_records(container.note_service().list(command.note_filters.unwrap_or_default())?)?,
+        "goals" => to_records(container.goal_service().list(command.goal_filters.unwrap_or_default())?)?,
+        "journal_entries" => to_records(container.journal_service().list(command.journal_filters.unwrap_or_default())?)?,
+        "agenda_blocks" => to_records(container.agenda_service().list(command.agenda_filters.unwrap_or_default())?)?,
+        other => return Err(format!("Invalid export dataset: {}", other)),
+    };
+
+    container.export_service().export_records(&command.dataset, command.format, command.fields, records)
+}
+
+/// Tauri command: Export citations (BibTeX, RIS or CSL-JSON) for a book, a collection or a query
+#[tauri::command]
+pub fn export_citations(
+    command: ExportCitationsCommand,
+    state: tauri::State<AppState>,
+) -> Result<ExportFileDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+
+    let book_ids = match command.book_id {
+        Some(id) => vec![id],
+        None => container
+            .book_service()
+            .list(command.filters.unwrap_or_default())?
+            .into_iter()
+            .filter_map(|book| book.id)
+            .collect(),
+    };
+
+    container.export_service().export_citations(command.format, book_ids)
+}
+
+fn to_records<T: Serialize>(items: Vec<T>) -> Result<Vec<serde_json::Value>, String> {
+    items
+        .into_iter()
+        .map(|item| serde_json::to_value(item).map_err(|e| format!("Failed to serialize record: {}", e)))
+        .collect()
+}
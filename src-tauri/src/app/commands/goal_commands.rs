@@ -1,8 +1,10 @@
-use crate::app::dtos::goal_dto::{GoalDto, CreateGoalCommand, StatisticsDto as GoalStatisticsDto, MonthlyPagesDto, ListGoalsFilters};
+use crate::app::dtos::goal_dto::{GoalDto, CreateGoalCommand, GoalStatisticsDto, MonthlyPagesDto, YearlyRatingsDto, RatingCountDto, ListGoalsFilters};
 use crate::app::state::AppState;
-use crate::core::interfaces::primary::GoalService;
-use crate::core::interfaces::secondary::{SessionRepository, BookRepository};
+use crate::core::interfaces::primary::{GoalService, StatisticsService};
+use crate::core::interfaces::secondary::{SessionRepository, BookRepository, ReadingRepository};
 use crate::core::domains::book::BookStatus;
+use crate::core::domains::statistics_calculator::StatisticsCalculator;
+use crate::core::services::session_service::user_timezone_setting;
 use chrono::Datelike;
 
 /// Tauri command: Create a new goal
@@ -50,7 +52,6 @@ pub fn delete_goal(
 pub fn get_statistics(
     state: tauri::State<AppState>,
 ) -> Result<GoalStatisticsDto, String> {
-    // This returns GoalStatisticsDto which is different from StatisticsDto
     // We need to calculate this from repositories directly
     let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
     
@@ -59,10 +60,13 @@ pub fn get_statistics(
     let db_conn = state.db_connection.lock().map_err(|e| format!("Lock error: {}", e))?;
     let sqlite_conn = db_conn.get_connection();
     let session_repo = crate::infra::sqlite::repositories::SqliteSessionRepository::new(sqlite_conn.clone());
-    let book_repo = crate::infra::sqlite::repositories::SqliteBookRepository::new(sqlite_conn);
-    
-    let now = chrono::Utc::now();
-    let current_date = now.date_naive();
+    let book_repo = crate::infra::sqlite::repositories::SqliteBookRepository::new(sqlite_conn.clone());
+    let reading_repo = crate::infra::sqlite::repositories::SqliteReadingRepository::new(sqlite_conn.clone());
+    let settings_repo = crate::infra::sqlite::repositories::SqliteSettingsRepository::new(sqlite_conn);
+
+    // Today and month boundaries follow the user's time zone
+    let current_date = user_timezone_setting(&settings_repo)?.today();
+    let statistics = container.statistics_service().get()?;
     let current_year = Datelike::year(&current_date);
     let current_month = Datelike::month(&current_date);
 
@@ -109,7 +113,7 @@ pub fn get_statistics(
     // Pages per month (last 12 months)
     let mut pages_per_month = Vec::new();
     for i in 0..12 {
-        let date = now.date_naive() - chrono::Duration::days(30 * i);
+        let date = current_date - chrono::Duration::days(30 * i);
         let year = Datelike::year(&date);
         let month = Datelike::month(&date);
         let month_start = chrono::NaiveDate::from_ymd_opt(year, month, 1)
@@ -139,13 +143,40 @@ pub fn get_statistics(
     }
     pages_per_month.reverse();
 
+    // Ratings per year (each rated reading counts, so rereads are included)
+    let all_readings = reading_repo.find_all()?;
+    let ratings_by_year = StatisticsCalculator::ratings_by_year(&all_readings)
+        .into_iter()
+        .map(|(year, ratings)| {
+            let mut distribution: Vec<RatingCountDto> = Vec::new();
+            for rating in &ratings {
+                match distribution.iter_mut().find(|d| d.rating == *rating) {
+                    Some(entry) => entry.count += 1,
+                    None => distribution.push(RatingCountDto { rating: *rating, count: 1 }),
+                }
+            }
+            distribution.sort_by(|a, b| a.rating.partial_cmp(&b.rating).unwrap_or(std::cmp::Ordering::Equal));
+
+            YearlyRatingsDto {
+                year,
+                readings_rated: ratings.len() as i32,
+                average_rating: ratings.iter().sum::<f64>() / ratings.len() as f64,
+                distribution,
+            }
+        })
+        .collect();
+
     Ok(GoalStatisticsDto {
+        today: statistics.today,
+        this_month: statistics.this_month,
         pages_read_this_month,
         total_pages_read,
         books_completed,
         sessions_this_month,
         average_pages_per_session,
         pages_per_month,
+        ratings_by_year,
+        reading_speed: statistics.reading_speed,
     })
 }
 
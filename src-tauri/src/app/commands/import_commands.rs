@@ -0,0 +1,67 @@
+use crate::app::dtos::{
+    ImportLibraryCommand, ImportPreviewDto, ImportResultDto, ImportKindleClippingsCommand,
+    KindleImportResultDto, ImportKoreaderCommand, ImportCalibreCommand, ExternalImportReportDto,
+    ImportCitationsCommand,
+};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::ImportService;
+
+/// Tauri command: Parse a library export and report what would be imported, without saving
+#[tauri::command]
+pub fn preview_library_import(
+    command: ImportLibraryCommand,
+    state: tauri::State<AppState>,
+) -> Result<ImportPreviewDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().preview(command)
+}
+
+/// Tauri command: Import a library export (books, shelves as tags, readings and ratings)
+#[tauri::command]
+pub fn import_library(
+    command: ImportLibraryCommand,
+    state: tauri::State<AppState>,
+) -> Result<ImportResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().import(command)
+}
+
+/// Tauri command: Import highlights and notes from a Kindle "My Clippings.txt" file
+#[tauri::command]
+pub fn import_kindle_clippings(
+    command: ImportKindleClippingsCommand,
+    state: tauri::State<AppState>,
+) -> Result<KindleImportResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().import_kindle_clippings(command)
+}
+
+/// Tauri command: Import highlights from KOReader sidecar files (dry_run reports matches only)
+#[tauri::command]
+pub fn import_koreader_annotations(
+    command: ImportKoreaderCommand,
+    state: tauri::State<AppState>,
+) -> Result<ExternalImportReportDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().import_koreader(command)
+}
+
+/// Tauri command: Import or update books from a Calibre library (dry_run reports matches only)
+#[tauri::command]
+pub fn import_calibre_library(
+    command: ImportCalibreCommand,
+    state: tauri::State<AppState>,
+) -> Result<ExternalImportReportDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().import_calibre(command)
+}
+
+/// Tauri command: Import BibTeX or RIS references as Article/Pdf books
+#[tauri::command]
+pub fn import_citations(
+    command: ImportCitationsCommand,
+    state: tauri::State<AppState>,
+) -> Result<ImportResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.import_service().import_citations(command)
+}
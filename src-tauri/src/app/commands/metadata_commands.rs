@@ -0,0 +1,24 @@
+use crate::app::dtos::{BookMetadataDto, IsbnDto};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::MetadataService;
+
+/// Tauri command: Validate an ISBN-10/13 and return both forms
+#[tauri::command]
+pub fn validate_isbn(
+    isbn: String,
+    state: tauri::State<AppState>,
+) -> Result<IsbnDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.metadata_service().validate_isbn(isbn)
+}
+
+/// Tauri command: Look up book metadata by ISBN (cache first, then the configured provider)
+#[tauri::command]
+pub fn lookup_isbn_metadata(
+    isbn: String,
+    refresh: Option<bool>,
+    state: tauri::State<AppState>,
+) -> Result<BookMetadataDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.metadata_service().lookup_isbn(isbn, refresh.unwrap_or(false))
+}
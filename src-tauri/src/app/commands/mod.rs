@@ -10,6 +10,16 @@ pub mod agenda_commands;
 pub mod reading_commands;
 pub mod backup_commands;
 pub mod integrity_commands;
+pub mod series_commands;
+pub mod author_commands;
+pub mod edition_commands;
+pub mod chapter_commands;
+pub mod import_commands;
+pub mod export_commands;
+pub mod metadata_commands;
+pub mod cover_commands;
+pub mod session_photo_commands;
+pub mod statistics_commands;
 
 pub use book_commands::*;
 pub use session_commands::*;
@@ -23,3 +33,13 @@ pub use agenda_commands::*;
 pub use reading_commands::*;
 pub use backup_commands::*;
 pub use integrity_commands::*;
+pub use series_commands::*;
+pub use author_commands::*;
+pub use edition_commands::*;
+pub use chapter_commands::*;
+pub use import_commands::*;
+pub use export_commands::*;
+pub use metadata_commands::*;
+pub use cover_commands::*;
+pub use session_photo_commands::*;
+pub use statistics_commands::*;
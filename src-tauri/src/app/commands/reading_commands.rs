@@ -1,4 +1,4 @@
-use crate::app::dtos::reading_dto::{CreateReadingCommand, ReadingDto, ListReadingsFilters};
+use crate::app::dtos::reading_dto::{CreateReadingCommand, RateReadingCommand, ReadingDto, ListReadingsFilters};
 use crate::app::state::AppState;
 use crate::core::interfaces::primary::ReadingService;
 
@@ -42,3 +42,12 @@ pub fn get_current_reading(
     container.reading_service().get_current(book_id)
 }
 
+/// Tauri command: Rate and review a reading (defaults to the book's latest reading)
+#[tauri::command]
+pub fn rate_reading(
+    command: RateReadingCommand,
+    state: tauri::State<AppState>,
+) -> Result<ReadingDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.reading_service().rate(command)
+}
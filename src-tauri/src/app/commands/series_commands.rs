@@ -0,0 +1,63 @@
+use crate::app::dtos::{BookDto, SeriesDto, SeriesDetailDto, CreateSeriesCommand, UpdateSeriesCommand, ListSeriesFilters};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::SeriesService;
+
+/// Tauri command: Create a new series
+#[tauri::command]
+pub fn create_series(
+    command: CreateSeriesCommand,
+    state: tauri::State<AppState>,
+) -> Result<SeriesDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().create(command)
+}
+
+/// Tauri command: List all series
+#[tauri::command]
+pub fn list_series(
+    filters: Option<ListSeriesFilters>,
+    state: tauri::State<AppState>,
+) -> Result<Vec<SeriesDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().list(filters.unwrap_or_default())
+}
+
+/// Tauri command: Get a series with its volumes, gaps and next volume to read
+#[tauri::command]
+pub fn get_series(
+    id: i64,
+    state: tauri::State<AppState>,
+) -> Result<SeriesDetailDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().get(id)
+}
+
+/// Tauri command: Update a series
+#[tauri::command]
+pub fn update_series(
+    command: UpdateSeriesCommand,
+    state: tauri::State<AppState>,
+) -> Result<SeriesDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().update(command)
+}
+
+/// Tauri command: Delete a series by ID (books are kept and detached)
+#[tauri::command]
+pub fn delete_series(
+    id: i64,
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().delete(id)
+}
+
+/// Tauri command: Suggest the next unread volume after a completed book
+#[tauri::command]
+pub fn get_next_in_series(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Option<BookDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.series_service().continue_series(book_id)
+}
@@ -1,4 +1,9 @@
-use crate::app::dtos::{SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters};
+use crate::app::dtos::{
+    SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters,
+    ActiveSessionDto, StartActiveSessionCommand, FinishActiveSessionCommand, SessionValidationDto,
+    CreateSessionsBulkCommand, ImportSessionsCsvCommand, SessionCsvPreviewDto, BulkSessionsResultDto,
+    CorrectProgressCommand, ProgressCorrectionDto,
+};
 use crate::app::state::AppState;
 use crate::core::interfaces::primary::SessionService;
 
@@ -52,3 +57,128 @@ pub fn delete_session(
     container.session_service().delete(id)
 }
 
+/// Tauri command: Check a new session for errors and warnings without saving it
+#[tauri::command]
+pub fn validate_new_session(
+    command: CreateSessionCommand,
+    state: tauri::State<AppState>,
+) -> Result<SessionValidationDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().validate_new(command)
+}
+
+/// Tauri command: Check a session update for errors and warnings without saving it
+#[tauri::command]
+pub fn validate_session_update(
+    command: UpdateSessionCommand,
+    state: tauri::State<AppState>,
+) -> Result<SessionValidationDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().validate_update(command)
+}
+
+/// Tauri command: Create many sessions in one transaction
+#[tauri::command]
+pub fn create_sessions_bulk(
+    command: CreateSessionsBulkCommand,
+    state: tauri::State<AppState>,
+) -> Result<BulkSessionsResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().create_bulk(command)
+}
+
+/// Tauri command: Preview a session CSV import without writing anything
+#[tauri::command]
+pub fn preview_sessions_csv_import(
+    command: ImportSessionsCsvCommand,
+    state: tauri::State<AppState>,
+) -> Result<SessionCsvPreviewDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().preview_csv_import(command)
+}
+
+/// Tauri command: Import sessions from CSV, skipping rows with errors
+#[tauri::command]
+pub fn import_sessions_csv(
+    command: ImportSessionsCsvCommand,
+    state: tauri::State<AppState>,
+) -> Result<BulkSessionsResultDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().import_csv(command)
+}
+
+/// Tauri command: Correct a book's current position by adding an adjustment session or re-basing sessions
+#[tauri::command]
+pub fn correct_progress(
+    command: CorrectProgressCommand,
+    state: tauri::State<AppState>,
+) -> Result<ProgressCorrectionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().correct_progress(command)
+}
+
+/// Tauri command: List the progress corrections made to a book, most recent first
+#[tauri::command]
+pub fn list_progress_corrections(
+    book_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Vec<ProgressCorrectionDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().list_progress_corrections(book_id)
+}
+
+/// Tauri command: Start the live session timer for a book
+#[tauri::command]
+pub fn start_session_timer(
+    command: StartActiveSessionCommand,
+    state: tauri::State<AppState>,
+) -> Result<ActiveSessionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().start_timer(command)
+}
+
+/// Tauri command: Pause the running session timer
+#[tauri::command]
+pub fn pause_session_timer(
+    state: tauri::State<AppState>,
+) -> Result<ActiveSessionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().pause_timer()
+}
+
+/// Tauri command: Resume the paused session timer
+#[tauri::command]
+pub fn resume_session_timer(
+    state: tauri::State<AppState>,
+) -> Result<ActiveSessionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().resume_timer()
+}
+
+/// Tauri command: Get the running session timer, if any
+#[tauri::command]
+pub fn get_active_session_timer(
+    state: tauri::State<AppState>,
+) -> Result<Option<ActiveSessionDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().get_active_timer()
+}
+
+/// Tauri command: Finish the session timer and save it as a reading session
+#[tauri::command]
+pub fn finish_session_timer(
+    command: FinishActiveSessionCommand,
+    state: tauri::State<AppState>,
+) -> Result<SessionDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().finish_timer(command)
+}
+
+/// Tauri command: Discard the running session timer without saving
+#[tauri::command]
+pub fn cancel_session_timer(
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_service().cancel_timer()
+}
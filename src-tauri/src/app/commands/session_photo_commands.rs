@@ -0,0 +1,72 @@
+use crate::app::dtos::{AttachSessionPhotoCommand, SessionPhotoBackupDto, SessionPhotoDto, SessionPhotoImageDto};
+use crate::app::state::AppState;
+use crate::core::interfaces::primary::SessionPhotoService;
+
+/// Tauri command: Copy a photo (e.g. a page scan) into the app's photos folder and attach it to a session
+#[tauri::command]
+pub fn attach_session_photo(
+    command: AttachSessionPhotoCommand,
+    state: tauri::State<AppState>,
+) -> Result<SessionPhotoDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().attach_photo(command)
+}
+
+/// Tauri command: List the photos attached to a session
+#[tauri::command]
+pub fn list_session_photos(
+    session_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<Vec<SessionPhotoDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().list_photos(session_id)
+}
+
+/// Tauri command: Get a session photo (or its thumbnail) as a data URL
+#[tauri::command]
+pub fn get_session_photo(
+    photo_id: i64,
+    thumbnail: Option<bool>,
+    state: tauri::State<AppState>,
+) -> Result<SessionPhotoImageDto, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().get_photo_image(photo_id, thumbnail.unwrap_or(false))
+}
+
+/// Tauri command: Remove a photo from its session
+#[tauri::command]
+pub fn delete_session_photo(
+    photo_id: i64,
+    state: tauri::State<AppState>,
+) -> Result<(), String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().delete_photo(photo_id)
+}
+
+/// Tauri command: Delete stored photo files that no session uses anymore
+#[tauri::command]
+pub fn cleanup_orphaned_session_photos(
+    state: tauri::State<AppState>,
+) -> Result<usize, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().cleanup_orphaned_photos()
+}
+
+/// Tauri command: Get all session photos for inclusion in a full backup
+#[tauri::command]
+pub fn backup_session_photos(
+    state: tauri::State<AppState>,
+) -> Result<Vec<SessionPhotoBackupDto>, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().backup_photos()
+}
+
+/// Tauri command: Restore session photos from a full backup
+#[tauri::command]
+pub fn restore_session_photos(
+    photos: Vec<SessionPhotoBackupDto>,
+    state: tauri::State<AppState>,
+) -> Result<usize, String> {
+    let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
+    container.session_photo_service().restore_photos(photos)
+}
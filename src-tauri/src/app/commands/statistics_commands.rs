@@ -1,13 +1,13 @@
-use crate::app::dtos::statistics_dto::StatisticsDto;
+use crate::app::dtos::statistics_dto::{ContextStatisticsDto, ContextStatisticsFilters};
 use crate::app::state::AppState;
 use crate::core::interfaces::primary::statistics_service::StatisticsService;
 
-/// Tauri command: Get statistics
+/// Tauri command: Get reading pace and mood changes by location, device and time of day
 #[tauri::command]
-pub fn get_statistics(
+pub fn get_context_statistics(
+    filters: Option<ContextStatisticsFilters>,
     state: tauri::State<AppState>,
-) -> Result<StatisticsDto, String> {
+) -> Result<ContextStatisticsDto, String> {
     let container = state.container.lock().map_err(|e| format!("DI lock error: {}", e))?;
-    container.statistics_service().get()
+    container.statistics_service().get_context(filters.unwrap_or_default())
 }
-
@@ -1,22 +1,36 @@
 use crate::core::services::{
     AgendaServiceImpl,
+    AuthorServiceImpl,
     BookServiceImpl,
+    ChapterServiceImpl,
     GoalServiceImpl,
     SessionServiceImpl,
+    SessionPhotoServiceImpl,
     NoteServiceImpl,
     JournalServiceImpl,
     CollectionServiceImpl,
+    EditionServiceImpl,
+    ImportServiceImpl,
+    ExportServiceImpl,
+    MetadataServiceImpl,
+    CoverServiceImpl,
     TagServiceImpl,
     ReadingServiceImpl,
+    SeriesServiceImpl,
     SettingsServiceImpl,
     StatisticsServiceImpl,
 };
-use crate::infra::sqlite::database::DatabaseConnection;
+use crate::infra::filesystem::{FsCoverStorage, FsExportFileWriter, FsPhotoStorage};
+use crate::infra::http::{OpenLibraryProvider, UreqHttpFetcher};
+use crate::infra::sqlite::database::{DatabaseConnection, SqliteTransactionManager};
 use crate::infra::sqlite::repositories::{
     SqliteAgendaRepository, SqliteBookRepository, SqliteGoalRepository,
     SqliteSessionRepository, SqliteNoteRepository, SqliteJournalRepository,
     SqliteCollectionRepository, SqliteTagRepository, SqliteReadingRepository,
-    SqliteSettingsRepository,
+    SqliteSettingsRepository, SqliteSeriesRepository, SqliteAuthorRepository,
+    SqliteEditionRepository, SqliteCalibreLibraryReader, SqliteMetadataCacheRepository,
+    SqliteBookCoverRepository, SqliteActiveSessionRepository, SqliteProgressCorrectionRepository,
+    SqliteSessionPhotoRepository, SqliteChapterRepository,
 };
 
 /// Dependency injection container that holds all repositories
@@ -33,6 +47,22 @@ pub struct DIContainer {
     tag_repository: SqliteTagRepository,
     reading_repository: SqliteReadingRepository,
     settings_repository: SqliteSettingsRepository,
+    series_repository: SqliteSeriesRepository,
+    author_repository: SqliteAuthorRepository,
+    edition_repository: SqliteEditionRepository,
+    metadata_cache_repository: SqliteMetadataCacheRepository,
+    cover_repository: SqliteBookCoverRepository,
+    active_session_repository: SqliteActiveSessionRepository,
+    progress_correction_repository: SqliteProgressCorrectionRepository,
+    session_photo_repository: SqliteSessionPhotoRepository,
+    chapter_repository: SqliteChapterRepository,
+    calibre_reader: SqliteCalibreLibraryReader,
+    file_writer: FsExportFileWriter,
+    metadata_provider: OpenLibraryProvider,
+    cover_storage: FsCoverStorage,
+    photo_storage: FsPhotoStorage,
+    http_fetcher: UreqHttpFetcher,
+    transaction_manager: SqliteTransactionManager,
 }
 
 impl DIContainer {
@@ -49,7 +79,17 @@ impl DIContainer {
         let collection_repo = SqliteCollectionRepository::new(connection.clone());
         let tag_repo = SqliteTagRepository::new(connection.clone());
         let reading_repo = SqliteReadingRepository::new(connection.clone());
-        let settings_repo = SqliteSettingsRepository::new(connection);
+        let settings_repo = SqliteSettingsRepository::new(connection.clone());
+        let series_repo = SqliteSeriesRepository::new(connection.clone());
+        let author_repo = SqliteAuthorRepository::new(connection.clone());
+        let edition_repo = SqliteEditionRepository::new(connection.clone());
+        let metadata_cache_repo = SqliteMetadataCacheRepository::new(connection.clone());
+        let cover_repo = SqliteBookCoverRepository::new(connection.clone());
+        let active_session_repo = SqliteActiveSessionRepository::new(connection.clone());
+        let progress_correction_repo = SqliteProgressCorrectionRepository::new(connection.clone());
+        let session_photo_repo = SqliteSessionPhotoRepository::new(connection.clone());
+        let chapter_repo = SqliteChapterRepository::new(connection.clone());
+        let transaction_manager = SqliteTransactionManager::new(connection);
         
         DIContainer {
             agenda_repository: agenda_repo,
@@ -62,72 +102,191 @@ impl DIContainer {
             tag_repository: tag_repo,
             reading_repository: reading_repo,
             settings_repository: settings_repo,
+            series_repository: series_repo,
+            author_repository: author_repo,
+            edition_repository: edition_repo,
+            metadata_cache_repository: metadata_cache_repo,
+            cover_repository: cover_repo,
+            active_session_repository: active_session_repo,
+            progress_correction_repository: progress_correction_repo,
+            session_photo_repository: session_photo_repo,
+            chapter_repository: chapter_repo,
+            calibre_reader: SqliteCalibreLibraryReader::new(),
+            file_writer: FsExportFileWriter::new(),
+            metadata_provider: OpenLibraryProvider::new(),
+            cover_storage: FsCoverStorage::new(),
+            photo_storage: FsPhotoStorage::new(),
+            http_fetcher: UreqHttpFetcher::new(),
+            transaction_manager,
         }
     }
     
-    pub fn agenda_service(&self) -> AgendaServiceImpl {
+    pub fn agenda_service(&self) -> AgendaServiceImpl<'_> {
         AgendaServiceImpl::new(&self.agenda_repository)
     }
 
-    pub fn book_service(&self) -> BookServiceImpl {
-        BookServiceImpl::new(&self.book_repository, &self.note_repository)
+    pub fn book_service(&self) -> BookServiceImpl<'_> {
+        BookServiceImpl::new(
+            &self.book_repository,
+            &self.note_repository,
+            &self.author_repository,
+            &self.session_repository,
+            &self.settings_repository,
+            &self.session_photo_repository,
+            &self.photo_storage,
+            &self.cover_repository,
+            &self.cover_storage,
+        )
     }
 
-    pub fn goal_service(&self) -> GoalServiceImpl {
+    pub fn goal_service(&self) -> GoalServiceImpl<'_> {
         GoalServiceImpl::new(
             &self.goal_repository,
             &self.session_repository,
             &self.book_repository,
+            &self.settings_repository,
         )
     }
 
-    pub fn settings_service(&self) -> SettingsServiceImpl {
+    pub fn settings_service(&self) -> SettingsServiceImpl<'_> {
         SettingsServiceImpl::new(&self.settings_repository)
     }
 
-    pub fn statistics_service(&self) -> StatisticsServiceImpl {
+    pub fn statistics_service(&self) -> StatisticsServiceImpl<'_> {
         StatisticsServiceImpl::new(
             &self.session_repository,
             &self.book_repository,
+            &self.settings_repository,
         )
     }
 
-    pub fn session_service(&self) -> SessionServiceImpl {
+    pub fn session_service(&self) -> SessionServiceImpl<'_> {
         SessionServiceImpl::new(
             &self.session_repository,
             &self.book_repository,
+            &self.edition_repository,
+            &self.active_session_repository,
+            &self.settings_repository,
+            &self.progress_correction_repository,
+            &self.session_photo_repository,
+            &self.photo_storage,
+            &self.reading_repository,
+            &self.transaction_manager,
         )
     }
 
-    pub fn note_service(&self) -> NoteServiceImpl {
+    pub fn session_photo_service(&self) -> SessionPhotoServiceImpl<'_> {
+        SessionPhotoServiceImpl::new(
+            &self.session_repository,
+            &self.session_photo_repository,
+            &self.photo_storage,
+        )
+    }
+
+    pub fn note_service(&self) -> NoteServiceImpl<'_> {
         NoteServiceImpl::new(
             &self.note_repository,
             &self.book_repository,
         )
     }
 
-    pub fn journal_service(&self) -> JournalServiceImpl {
+    pub fn journal_service(&self) -> JournalServiceImpl<'_> {
         JournalServiceImpl::new(&self.journal_repository)
     }
 
-    pub fn collection_service(&self) -> CollectionServiceImpl {
+    pub fn collection_service(&self) -> CollectionServiceImpl<'_> {
         CollectionServiceImpl::new(
             &self.collection_repository,
             &self.book_repository,
+            &self.session_repository,
         )
     }
 
-    pub fn tag_service(&self) -> TagServiceImpl {
+    pub fn tag_service(&self) -> TagServiceImpl<'_> {
         TagServiceImpl::new(
             &self.tag_repository,
             &self.book_repository,
         )
     }
 
-    pub fn reading_service(&self) -> ReadingServiceImpl {
+    pub fn reading_service(&self) -> ReadingServiceImpl<'_> {
         ReadingServiceImpl::new(
             &self.reading_repository,
             &self.book_repository,
         )
     }
+
+    pub fn series_service(&self) -> SeriesServiceImpl<'_> {
+        SeriesServiceImpl::new(
+            &self.series_repository,
+            &self.book_repository,
+        )
+    }
+
+    pub fn author_service(&self) -> AuthorServiceImpl<'_> {
+        AuthorServiceImpl::new(
+            &self.author_repository,
+            &self.book_repository,
+            &self.session_repository,
+        )
+    }
+
+    pub fn edition_service(&self) -> EditionServiceImpl<'_> {
+        EditionServiceImpl::new(
+            &self.edition_repository,
+            &self.book_repository,
+            &self.session_repository,
+        )
+    }
+
+    pub fn chapter_service(&self) -> ChapterServiceImpl<'_> {
+        ChapterServiceImpl::new(
+            &self.chapter_repository,
+            &self.book_repository,
+        )
+    }
+
+    pub fn import_service(&self) -> ImportServiceImpl<'_> {
+        ImportServiceImpl::new(
+            &self.book_repository,
+            &self.reading_repository,
+            &self.tag_repository,
+            &self.author_repository,
+            &self.note_repository,
+            &self.series_repository,
+            &self.calibre_reader,
+            &self.settings_repository,
+            &self.transaction_manager,
+        )
+    }
+
+    pub fn export_service(&self) -> ExportServiceImpl<'_> {
+        ExportServiceImpl::new(
+            &self.book_repository,
+            &self.reading_repository,
+            &self.tag_repository,
+            &self.note_repository,
+            &self.journal_repository,
+            &self.session_repository,
+            &self.author_repository,
+            &self.file_writer,
+        )
+    }
+
+    pub fn metadata_service(&self) -> MetadataServiceImpl<'_> {
+        MetadataServiceImpl::new(
+            &self.metadata_cache_repository,
+            &self.settings_repository,
+            &self.metadata_provider,
+        )
+    }
+
+    pub fn cover_service(&self) -> CoverServiceImpl<'_> {
+        CoverServiceImpl::new(
+            &self.book_repository,
+            &self.cover_repository,
+            &self.cover_storage,
+            &self.http_fetcher,
+        )
+    }
 }
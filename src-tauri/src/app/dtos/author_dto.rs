@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use crate::app::dtos::book_dto::BookDto;
+use crate::core::domains::author::{Author, BookContributor, ContributorRole};
+
+/// Author Data Transfer Object for API communication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorDto {
+    pub id: Option<i64>,
+    pub name: String,
+    pub created_at: String, // ISO8601 string
+    pub updated_at: String, // ISO8601 string
+}
+
+impl From<Author> for AuthorDto {
+    fn from(author: Author) -> Self {
+        AuthorDto {
+            id: author.id,
+            name: author.name,
+            created_at: author.created_at.to_rfc3339(),
+            updated_at: author.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// A contributor as shown on a book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookContributorDto {
+    pub author_id: Option<i64>,
+    pub name: String,
+    pub role: String, // "author", "translator", "editor", "narrator"
+    pub position: i32,
+}
+
+impl From<BookContributor> for BookContributorDto {
+    fn from(contributor: BookContributor) -> Self {
+        BookContributorDto {
+            author_id: contributor.author.id,
+            name: contributor.author.name,
+            role: contributor_role_to_string(&contributor.role),
+            position: contributor.position,
+        }
+    }
+}
+
+/// A book on an author page with the roles the author had in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorBookDto {
+    pub book: BookDto,
+    pub roles: Vec<String>,
+}
+
+/// Aggregated reading statistics across an author's books
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorStatsDto {
+    pub total_books: i32,
+    pub completed_books: i32,
+    pub pages_read: i32,
+    pub minutes_listened: i32,
+    pub time_spent_seconds: i32,
+}
+
+/// Author page: the author, all their books and aggregated stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorDetailDto {
+    pub author: AuthorDto,
+    pub books: Vec<AuthorBookDto>,
+    pub stats: AuthorStatsDto,
+}
+
+pub fn contributor_role_to_string(role: &ContributorRole) -> String {
+    match role {
+        ContributorRole::Author => "author".to_string(),
+        ContributorRole::Translator => "translator".to_string(),
+        ContributorRole::Editor => "editor".to_string(),
+        ContributorRole::Narrator => "narrator".to_string(),
+    }
+}
+
+pub fn string_to_contributor_role(s: &str) -> Result<ContributorRole, String> {
+    match s {
+        "author" => Ok(ContributorRole::Author),
+        "translator" => Ok(ContributorRole::Translator),
+        "editor" => Ok(ContributorRole::Editor),
+        "narrator" => Ok(ContributorRole::Narrator),
+        _ => Err(format!("Invalid contributor role: {}", s)),
+    }
+}
+
+/// Command for creating an author
+#[derive(Debug, Deserialize)]
+pub struct CreateAuthorCommand {
+    pub name: String,
+}
+
+/// Command for updating an author
+#[derive(Debug, Deserialize)]
+pub struct UpdateAuthorCommand {
+    pub id: i64,
+    pub name: Option<String>,
+}
+
+/// A contributor entry when setting a book's contributors (authors are matched by name)
+#[derive(Debug, Deserialize)]
+pub struct ContributorInput {
+    pub name: String,
+    pub role: String,
+}
+
+/// Command for replacing the contributors of a book
+#[derive(Debug, Deserialize)]
+pub struct SetBookContributorsCommand {
+    pub book_id: i64,
+    pub contributors: Vec<ContributorInput>,
+}
+
+/// Command for merging a duplicate author into another
+#[derive(Debug, Deserialize)]
+pub struct MergeAuthorsCommand {
+    pub source_id: i64, // Deleted after the merge
+    pub target_id: i64,
+}
+
+/// Filters for listing authors
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListAuthorsFilters {
+    // Currently no filters, but structure is ready for future additions
+}
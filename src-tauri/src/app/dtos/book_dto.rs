@@ -1,5 +1,8 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::app::dtos::session_dto::format_duration;
 use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::progress_unit::{PositionScale, ProgressUnit};
+use crate::core::domains::reading_speed::{FinishPrediction, SpeedUnit};
 
 /// Book Data Transfer Object for API communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +18,12 @@ pub struct BookDto {
     pub total_minutes: Option<i32>,
     pub current_page_text: i32,
     pub current_minutes_audio: i32,
+    #[serde(default)]
+    pub progress_unit: Option<String>, // page, percentage or location
+    #[serde(default)]
+    pub total_locations: Option<i32>,
+    #[serde(default)]
+    pub current_position: Option<f64>, // Current page as a percentage or location, for those units
     pub status: String, // Serialized BookStatus
     pub is_archived: bool,
     pub is_wishlist: bool,
@@ -24,13 +33,72 @@ pub struct BookDto {
     pub updated_at: String, // ISO8601 string
     pub status_changed_at: Option<String>, // ISO8601 string
     pub progress_percentage: f64,
+    pub series_id: Option<i64>,
+    pub series_index: Option<f64>,
+    pub average_rating: Option<f64>, // Across all rated readings (0.5 - 5.0)
+    pub latest_rating: Option<f64>, // Rating of the most recent rated reading
+    pub ratings_count: i32,
+    #[serde(default)]
+    pub prediction: Option<FinishPredictionDto>, // Set for unfinished books with a known length
+    #[serde(default)]
+    pub next_in_series: Option<Box<BookDto>>, // Next unread volume, set when an update completes the book
+}
+
+/// Time remaining and estimated finish date of a book in progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinishPredictionDto {
+    pub unit: String, // "pages" or "minutes" (audio)
+    pub remaining: i32,
+    pub pages_per_hour: Option<f64>,
+    pub listening_speed: Option<f64>, // Audio minutes per minute listened
+    pub speed_source: Option<String>, // "book" or "global" (too few sessions of this book)
+    pub per_day: Option<f64>, // Recent cadence in pages or audio minutes per day
+    pub time_remaining_seconds: Option<i64>,
+    pub time_remaining_formatted: Option<String>,
+    pub estimated_finish_date: Option<String>, // YYYY-MM-DD
+}
+
+impl From<FinishPrediction> for FinishPredictionDto {
+    fn from(prediction: FinishPrediction) -> Self {
+        let (pages_per_hour, listening_speed) = match (&prediction.speed, prediction.unit) {
+            (Some(speed), SpeedUnit::Pages) => (Some(round2(speed.units_per_hour)), None),
+            (Some(speed), SpeedUnit::AudioMinutes) => (None, Some(round2(speed.units_per_minute()))),
+            (None, _) => (None, None),
+        };
+
+        let speed_source = prediction.speed.as_ref().map(|_| {
+            if prediction.speed_is_global { "global" } else { "book" }.to_string()
+        });
+
+        FinishPredictionDto {
+            unit: match prediction.unit {
+                SpeedUnit::Pages => "pages".to_string(),
+                SpeedUnit::AudioMinutes => "minutes".to_string(),
+            },
+            remaining: prediction.remaining_units,
+            pages_per_hour,
+            listening_speed,
+            speed_source,
+            per_day: prediction.units_per_day.map(round2),
+            time_remaining_seconds: prediction.time_remaining_seconds,
+            time_remaining_formatted: prediction.time_remaining_seconds.map(|s| format_duration(s.min(i32::MAX as i64) as i32)),
+            estimated_finish_date: prediction.estimated_finish_date.map(|d| d.format("%Y-%m-%d").to_string()),
+        }
+    }
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
 }
 
 impl From<Book> for BookDto {
     fn from(book: Book) -> Self {
         // Calculate progress before moving fields
         let progress_percentage = book.calculate_progress();
-        
+        let current_position = PositionScale::for_book(&book)
+            .ok()
+            .map(|scale| scale.from_page(book.current_page_text));
+
         BookDto {
             id: book.id,
             title: book.title,
@@ -43,6 +111,9 @@ impl From<Book> for BookDto {
             total_minutes: book.total_minutes,
             current_page_text: book.current_page_text,
             current_minutes_audio: book.current_minutes_audio,
+            progress_unit: Some(book.progress_unit.as_str().to_string()),
+            total_locations: book.total_locations,
+            current_position,
             status: book_status_to_string(&book.status),
             is_archived: book.is_archived,
             is_wishlist: book.is_wishlist,
@@ -52,6 +123,13 @@ impl From<Book> for BookDto {
             updated_at: book.updated_at.to_rfc3339(),
             status_changed_at: book.status_changed_at.map(|dt| dt.to_rfc3339()),
             progress_percentage,
+            series_id: book.series_id,
+            series_index: book.series_index,
+            average_rating: book.average_rating,
+            latest_rating: book.latest_rating,
+            ratings_count: book.ratings_count,
+            prediction: None,
+            next_in_series: None,
         }
     }
 }
@@ -72,6 +150,8 @@ impl TryFrom<BookDto> for Book {
             total_minutes: dto.total_minutes,
             current_page_text: dto.current_page_text,
             current_minutes_audio: dto.current_minutes_audio,
+            progress_unit: dto.progress_unit.as_deref().map(ProgressUnit::parse).transpose()?.unwrap_or(ProgressUnit::Page),
+            total_locations: dto.total_locations,
             status: string_to_book_status(&dto.status)?,
             is_archived: dto.is_archived,
             is_wishlist: dto.is_wishlist,
@@ -83,16 +163,21 @@ impl TryFrom<BookDto> for Book {
             updated_at: chrono::DateTime::parse_from_rfc3339(&dto.updated_at)
                 .map_err(|e| format!("Invalid updated_at: {}", e))?
                 .with_timezone(&chrono::Utc),
-            status_changed_at: dto.status_changed_at.map(|s| {
+            status_changed_at: dto.status_changed_at.and_then(|s| {
                 chrono::DateTime::parse_from_rfc3339(&s)
                     .ok()
                     .map(|dt| dt.with_timezone(&chrono::Utc))
-            }).flatten(),
+            }),
+            series_id: dto.series_id,
+            series_index: dto.series_index,
+            average_rating: dto.average_rating,
+            latest_rating: dto.latest_rating,
+            ratings_count: dto.ratings_count,
         })
     }
 }
 
-fn book_status_to_string(status: &BookStatus) -> String {
+pub(crate) fn book_status_to_string(status: &BookStatus) -> String {
     match status {
         BookStatus::NotStarted => "not_started".to_string(),
         BookStatus::Reading => "reading".to_string(),
@@ -115,7 +200,7 @@ fn string_to_book_status(s: &str) -> Result<BookStatus, String> {
     }
 }
 
-fn book_type_to_string(book_type: &BookType) -> String {
+pub(crate) fn book_type_to_string(book_type: &BookType) -> String {
     match book_type {
         BookType::PhysicalBook => "physical_book".to_string(),
         BookType::Ebook => "ebook".to_string(),
@@ -126,7 +211,7 @@ fn book_type_to_string(book_type: &BookType) -> String {
     }
 }
 
-fn string_to_book_type(s: &str) -> Result<BookType, String> {
+pub(crate) fn string_to_book_type(s: &str) -> Result<BookType, String> {
     match s {
         "physical_book" => Ok(BookType::PhysicalBook),
         "ebook" => Ok(BookType::Ebook),
@@ -151,6 +236,10 @@ pub struct CreateBookCommand {
     pub total_minutes: Option<i32>,
     pub cover_url: Option<String>,
     pub url: Option<String>,
+    pub series_id: Option<i64>,
+    pub series_index: Option<f64>,
+    pub progress_unit: Option<String>, // page, percentage or location; defaults to the defaultProgressUnit setting
+    pub total_locations: Option<i32>, // Required for the location unit
 }
 
 /// Command for updating a book
@@ -167,11 +256,26 @@ pub struct UpdateBookCommand {
     pub total_minutes: Option<i32>,
     pub current_page_text: Option<i32>,
     pub current_minutes_audio: Option<i32>,
+    pub progress_unit: Option<String>,
+    pub total_locations: Option<i32>,
     pub status: Option<String>,
     pub is_archived: Option<bool>,
     pub is_wishlist: Option<bool>,
     pub cover_url: Option<String>,
     pub url: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub series_id: Option<Option<i64>>, // None = not provided, Some(None) = remove from the series
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub series_index: Option<Option<f64>>, // None = not provided, Some(None) = clear the volume number
+}
+
+/// Tells a field sent as null (Some(None)) apart from a missing one (None)
+pub(crate) fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 /// Filters for listing books
@@ -182,5 +286,7 @@ pub struct ListBooksFilters {
     pub is_archived: Option<bool>,
     pub is_wishlist: Option<bool>,
     pub collection_id: Option<i64>,
+    pub min_rating: Option<f64>, // Only books whose average rating is at least this
+    pub sort_by: Option<String>, // "rating" (highest first) or default (most recently added)
 }
 
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::audiobook_chapter::{format_timestamp, AudiobookChapter, ChapterProgress};
+
+/// Audiobook chapter Data Transfer Object for API communication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterDto {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub position: i32,
+    pub title: String,
+    pub start_seconds: i32,
+    pub start_timestamp: String, // H:MM:SS
+}
+
+impl From<AudiobookChapter> for ChapterDto {
+    fn from(chapter: AudiobookChapter) -> Self {
+        ChapterDto {
+            id: chapter.id,
+            book_id: chapter.book_id,
+            position: chapter.position,
+            title: chapter.title,
+            start_seconds: chapter.start_seconds,
+            start_timestamp: format_timestamp(chapter.start_seconds),
+        }
+    }
+}
+
+/// A chapter entry when setting a book's chapters
+#[derive(Debug, Deserialize)]
+pub struct ChapterInput {
+    pub title: String,
+    pub start: String, // H:MM:SS, M:SS or seconds into the recording
+}
+
+/// Command for replacing the chapters of an audiobook
+#[derive(Debug, Deserialize)]
+pub struct SetChaptersCommand {
+    pub book_id: i64,
+    pub chapters: Vec<ChapterInput>, // Any order; sorted by start
+}
+
+/// Listening progress through one chapter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterProgressItemDto {
+    pub chapter: ChapterDto,
+    pub end_seconds: Option<i32>,
+    pub listened_seconds: i32,
+    pub percentage: Option<f64>,
+    pub status: String, // finished, current or upcoming
+}
+
+impl From<ChapterProgress> for ChapterProgressItemDto {
+    fn from(progress: ChapterProgress) -> Self {
+        ChapterProgressItemDto {
+            percentage: progress.percentage(),
+            status: progress.status.as_str().to_string(),
+            end_seconds: progress.end_seconds,
+            listened_seconds: progress.listened_seconds,
+            chapter: ChapterDto::from(progress.chapter),
+        }
+    }
+}
+
+/// Progress of an audiobook reported by chapter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChapterProgressDto {
+    pub book_id: i64,
+    pub position_seconds: i32,
+    pub total_seconds: Option<i32>,
+    pub chapters_finished: i32,
+    pub chapters_total: i32,
+    pub current_chapter: Option<ChapterProgressItemDto>,
+    pub chapters: Vec<ChapterProgressItemDto>,
+}
@@ -51,3 +51,29 @@ pub struct ListCollectionsFilters {
     // Currently no filters, but structure is ready for future additions
 }
 
+/// Aggregated reading statistics for a single collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionStatsDto {
+    pub collection_id: i64,
+    pub collection_name: String,
+    pub total_books: i32,
+    pub completed_books: i32,
+    pub completion_percentage: f64, // 0-100
+    pub total_pages: i32,
+    pub pages_read: i32,
+    pub total_minutes: i32, // For audiobooks
+    pub minutes_listened: i32, // For audiobooks
+    pub time_spent_seconds: i32, // Sum of session durations
+    pub average_days_to_finish: Option<f64>, // Completed books only
+    pub average_rating: Option<f64>, // Mean of the rated books' average ratings (0.5 - 5.0)
+    pub completions_timeline: Vec<MonthlyCompletionsDto>,
+}
+
+/// Number of books completed in a given month
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonthlyCompletionsDto {
+    pub year: i32,
+    pub month: u32,
+    pub books_completed: i32,
+}
+
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::book_cover::BookCover;
+
+/// Command to import a cover into the app's covers folder
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportCoverCommand {
+    pub book_id: i64,
+    pub source: String, // Local file path or http(s) URL
+}
+
+/// Local cover of a book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookCoverDto {
+    pub book_id: i64,
+    pub hash: String,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub source: Option<String>,
+    pub imported_at: String, // RFC3339
+}
+
+/// Cover image content, ready to use as an <img> source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverImageDto {
+    pub book_id: i64,
+    pub mime_type: String,
+    pub data_url: String, // data:<mime>;base64,...
+}
+
+/// Cover file carried inside a full backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverBackupDto {
+    pub book_id: i64,
+    pub file_name: String,
+    pub source: Option<String>,
+    pub data_base64: String,
+}
+
+/// A book whose remote cover could not be stored locally
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverImportErrorDto {
+    pub book_id: i64,
+    pub title: String,
+    pub error: String,
+}
+
+/// Outcome of storing every book's remote cover locally
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteCoversResultDto {
+    pub imported_count: usize,
+    pub already_local_count: usize,
+    pub errors: Vec<CoverImportErrorDto>,
+}
+
+impl From<BookCover> for BookCoverDto {
+    fn from(cover: BookCover) -> Self {
+        BookCoverDto {
+            mime_type: cover.mime_type().to_string(),
+            book_id: cover.book_id,
+            hash: cover.hash,
+            width: cover.width,
+            height: cover.height,
+            source: cover.source,
+            imported_at: cover.imported_at.to_rfc3339(),
+        }
+    }
+}
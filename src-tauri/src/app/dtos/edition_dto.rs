@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use crate::app::dtos::book_dto::book_type_to_string;
+use crate::core::domains::edition::Edition;
+
+/// Edition Data Transfer Object for API communication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditionDto {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub format: String, // Serialized BookType
+    pub label: Option<String>,
+    pub isbn: Option<String>,
+    pub total_pages: Option<i32>,
+    pub total_minutes: Option<i32>,
+    pub created_at: String, // ISO8601 string
+    pub updated_at: String, // ISO8601 string
+}
+
+impl From<Edition> for EditionDto {
+    fn from(edition: Edition) -> Self {
+        EditionDto {
+            id: edition.id,
+            book_id: edition.book_id,
+            format: book_type_to_string(&edition.format),
+            label: edition.label,
+            isbn: edition.isbn,
+            total_pages: edition.total_pages,
+            total_minutes: edition.total_minutes,
+            created_at: edition.created_at.to_rfc3339(),
+            updated_at: edition.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Where the reader is in one edition, derived from the shared position
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditionProgressDto {
+    pub edition_id: Option<i64>, // None = the book's own format
+    pub format: String,
+    pub label: Option<String>,
+    pub total_pages: Option<i32>,
+    pub total_minutes: Option<i32>,
+    pub current_position: i32, // Page, or minute for audiobooks
+}
+
+/// Progress of a book unified across all of its editions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkProgressDto {
+    pub book_id: i64,
+    pub progress_percentage: f64,
+    pub editions: Vec<EditionProgressDto>,
+}
+
+/// Command for adding an edition to a book
+#[derive(Debug, Deserialize)]
+pub struct CreateEditionCommand {
+    pub book_id: i64,
+    pub format: String,
+    pub label: Option<String>,
+    pub isbn: Option<String>,
+    pub total_pages: Option<i32>,
+    pub total_minutes: Option<i32>,
+}
+
+/// Command for updating an edition
+#[derive(Debug, Deserialize)]
+pub struct UpdateEditionCommand {
+    pub id: i64,
+    pub format: Option<String>,
+    pub label: Option<String>,
+    pub isbn: Option<String>,
+    pub total_pages: Option<i32>,
+    pub total_minutes: Option<i32>,
+}
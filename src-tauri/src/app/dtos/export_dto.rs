@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use crate::app::dtos::{
+    ListAgendaBlocksFilters, ListBooksFilters, ListGoalsFilters, ListJournalEntriesFilters,
+    ListNotesFilters, ListSessionsFilters,
+};
+use crate::core::domains::citation::CitationFormat;
+use crate::core::domains::library_export::ExportFormat;
+use crate::core::domains::tabular_export::TableFormat;
+
+/// An exported file, ready for the frontend to save
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportFileDto {
+    pub format: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub content: String,
+}
+
+/// Command to export notes and journal as Markdown (Obsidian vault layout)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportMarkdownCommand {
+    pub book_id: Option<i64>,       // Export a single book; None = everything
+    pub target_dir: Option<String>, // Folder to write into; None = only return the files
+}
+
+/// One file of a Markdown export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedFileDto {
+    pub path: String, // Relative to the export folder, e.g. "Books/Dune.md"
+    pub content: String,
+}
+
+/// Result of a Markdown export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownExportDto {
+    pub files: Vec<ExportedFileDto>,
+    pub written_to: Option<String>,
+    pub book_count: usize,
+    pub note_count: usize,
+    pub journal_entry_count: usize,
+}
+
+/// Command to export one dataset as a table.
+/// Only the filters matching the dataset are used; they behave as in the list commands.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportTableCommand {
+    pub dataset: String, // "books", "sessions", "notes", "goals", "journal_entries", "agenda_blocks"
+    pub format: String,  // "csv" or "jsonl"
+    pub fields: Option<Vec<String>>, // Columns in order (DTO field names); None = all
+    pub book_filters: Option<ListBooksFilters>,
+    pub session_filters: Option<ListSessionsFilters>,
+    pub note_filters: Option<ListNotesFilters>,
+    pub goal_filters: Option<ListGoalsFilters>,
+    pub journal_filters: Option<ListJournalEntriesFilters>,
+    pub agenda_filters: Option<ListAgendaBlocksFilters>,
+}
+
+/// Command to export citations of one book, or of the books matching the list filters
+/// (use `collection_id` in the filters to export a collection)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExportCitationsCommand {
+    pub format: String, // "bibtex", "ris" or "csl_json"
+    pub book_id: Option<i64>,
+    pub filters: Option<ListBooksFilters>,
+}
+
+pub fn string_to_citation_format(s: &str) -> Result<CitationFormat, String> {
+    match s {
+        "bibtex" => Ok(CitationFormat::BibTex),
+        "ris" => Ok(CitationFormat::Ris),
+        "csl_json" => Ok(CitationFormat::CslJson),
+        _ => Err(format!("Invalid citation format: {}", s)),
+    }
+}
+
+pub fn string_to_table_format(s: &str) -> Result<TableFormat, String> {
+    match s {
+        "csv" => Ok(TableFormat::Csv),
+        "jsonl" => Ok(TableFormat::Jsonl),
+        _ => Err(format!("Invalid table format: {}", s)),
+    }
+}
+
+pub fn string_to_export_format(s: &str) -> Result<ExportFormat, String> {
+    match s {
+        "storygraph_csv" => Ok(ExportFormat::StoryGraphCsv),
+        _ => Err(format!("Invalid export format: {}", s)),
+    }
+}
+
+pub fn export_format_to_string(format: &ExportFormat) -> String {
+    match format {
+        ExportFormat::StoryGraphCsv => "storygraph_csv".to_string(),
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::core::domains::goal::{Goal, GoalType};
+use crate::app::dtos::statistics_dto::{TodayStatistics, MonthStatistics, ReadingSpeedStatistics};
 
 /// Goal Data Transfer Object for API communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,13 +71,17 @@ pub struct UpdateGoalCommand {
 
 /// Statistics Data Transfer Object
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StatisticsDto {
+pub struct GoalStatisticsDto {
+    pub today: TodayStatistics, // Split by local day, so overnight sessions count towards both days
+    pub this_month: MonthStatistics, // Listening time apart from the audio minutes covered
     pub pages_read_this_month: i32,
     pub total_pages_read: i32,
     pub books_completed: i32,
     pub sessions_this_month: i32,
     pub average_pages_per_session: f64,
     pub pages_per_month: Vec<MonthlyPagesDto>,
+    pub ratings_by_year: Vec<YearlyRatingsDto>,
+    pub reading_speed: ReadingSpeedStatistics,
 }
 
 /// Filters for listing goals
@@ -92,3 +97,16 @@ pub struct MonthlyPagesDto {
     pub pages: i32,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct YearlyRatingsDto {
+    pub year: i32,
+    pub readings_rated: i32,
+    pub average_rating: f64,
+    pub distribution: Vec<RatingCountDto>, // Only ratings that occur, lowest first
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingCountDto {
+    pub rating: f64,
+    pub count: i32,
+}
@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::library_import::ImportSource;
+
+/// Command to preview or run a library import from an external service export
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportLibraryCommand {
+    pub source: String,                     // "goodreads", "storygraph", "librarything"
+    pub content: String,                    // Raw CSV/TSV content of the export file
+    pub default_total_pages: Option<i32>,   // Used for rows without a page count
+    pub default_total_minutes: Option<i32>, // Used for audiobooks (exports carry no duration)
+    pub skip_duplicates: Option<bool>,      // Defaults to true
+}
+
+/// Command to import references from a BibTeX or RIS file as Article/Pdf books
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportCitationsCommand {
+    pub content: String,                  // Raw BibTeX or RIS content
+    pub default_total_pages: Option<i32>, // Used for entries without a page range
+    pub skip_duplicates: Option<bool>,    // Defaults to true
+}
+
+/// One row of an import preview
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRowDto {
+    pub row_number: usize,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub isbn: Option<String>,
+    pub book_type: String,
+    pub total_pages: Option<i32>,
+    pub status: String,
+    pub is_wishlist: bool,
+    pub tags: Vec<String>,
+    pub rating: Option<f64>,
+    pub date_read: Option<String>, // YYYY-MM-DD
+    pub read_count: i32,
+    pub duplicate_of_book_id: Option<i64>, // Existing book this row matches
+    pub duplicate_of_row: Option<usize>,   // Earlier row in the same file this row repeats
+    pub error: Option<String>,             // Why this row cannot be imported
+}
+
+/// Preview of an import, shown before anything is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportPreviewDto {
+    pub source: String,
+    pub total_rows: usize,
+    pub importable_count: usize,
+    pub duplicate_count: usize,
+    pub error_count: usize,
+    pub rows: Vec<ImportRowDto>,
+}
+
+/// A row that failed during import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRowErrorDto {
+    pub row_number: usize,
+    pub title: Option<String>,
+    pub error: String,
+}
+
+/// Outcome of a committed import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportResultDto {
+    pub imported_count: usize,
+    pub skipped_duplicates: usize,
+    pub created_book_ids: Vec<i64>,
+    pub errors: Vec<ImportRowErrorDto>,
+}
+
+/// Command to import highlights and notes from a Kindle "My Clippings.txt" file
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportKindleClippingsCommand {
+    pub content: String,                  // Raw content of My Clippings.txt
+    pub default_total_pages: Option<i32>, // Length for ebooks created for unmatched titles
+}
+
+/// Import outcome for one Kindle title
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KindleBookImportDto {
+    pub kindle_title: String,
+    pub kindle_author: Option<String>,
+    pub book_id: i64,
+    pub book_title: String,
+    pub created_book: bool, // No matching book existed, so an ebook was created
+    pub highlights_imported: usize,
+    pub notes_imported: usize,
+    pub already_imported: usize, // Clippings skipped because an identical note exists
+}
+
+/// Outcome of a Kindle clippings import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KindleImportResultDto {
+    pub books: Vec<KindleBookImportDto>,
+    pub total_imported: usize,
+    pub total_skipped: usize,
+    pub errors: Vec<ImportRowErrorDto>, // Row number is the entry's position in the file
+}
+
+/// A KOReader sidecar file (metadata.<ext>.lua) picked by the user
+#[derive(Debug, Clone, Deserialize)]
+pub struct KoreaderFileInput {
+    pub file_name: String,
+    pub content: String,
+}
+
+/// Command to import KOReader highlights
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportKoreaderCommand {
+    pub files: Vec<KoreaderFileInput>,
+    pub dry_run: Option<bool>,            // Only report matches, write nothing
+    pub default_total_pages: Option<i32>, // Length for ebooks created for unmatched documents
+}
+
+/// Command to import books from a Calibre library
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportCalibreCommand {
+    pub library_path: String, // Calibre library folder or its metadata.db
+    pub dry_run: Option<bool>,
+    pub default_total_pages: Option<i32>,   // Calibre stores no page counts
+    pub default_total_minutes: Option<i32>, // Length for audiobooks
+}
+
+/// How one external book was (or would be) matched and imported
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalBookMatchDto {
+    pub source: String, // Sidecar file name or "calibre:<id>"
+    pub title: String,
+    pub authors: Vec<String>,
+    pub book_id: Option<i64>,          // Matched (or created) library book
+    pub matched_title: Option<String>, // Title of the matched library book
+    pub action: String,                // "create", "update", "unchanged" or "error"
+    pub changes: Vec<String>,          // Fields filled in on a matched book
+    pub new_notes: usize,
+    pub existing_notes: usize,
+    pub error: Option<String>,
+}
+
+/// Report of a KOReader or Calibre import; with dry_run nothing was written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalImportReportDto {
+    pub dry_run: bool,
+    pub books: Vec<ExternalBookMatchDto>,
+    pub created_books: usize,
+    pub updated_books: usize,
+    pub imported_notes: usize,
+}
+
+pub fn string_to_import_source(s: &str) -> Result<ImportSource, String> {
+    match s {
+        "goodreads" => Ok(ImportSource::Goodreads),
+        "storygraph" => Ok(ImportSource::StoryGraph),
+        "librarything" => Ok(ImportSource::LibraryThing),
+        _ => Err(format!("Invalid import source: {}", s)),
+    }
+}
+
+pub fn import_source_to_string(source: &ImportSource) -> String {
+    match source {
+        ImportSource::Goodreads => "goodreads".to_string(),
+        ImportSource::StoryGraph => "storygraph".to_string(),
+        ImportSource::LibraryThing => "librarything".to_string(),
+    }
+}
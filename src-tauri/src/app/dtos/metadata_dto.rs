@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::author::Author;
+use crate::core::domains::book_metadata::BookMetadata;
+use crate::core::domains::isbn::isbn13_to_10;
+
+/// Metadata for an ISBN; field names match CreateBookCommand so the form can be prefilled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookMetadataDto {
+    pub isbn: String,           // ISBN-13
+    pub isbn10: Option<String>, // Only for 978-prefixed ISBNs
+    pub title: String,
+    pub author: Option<String>, // Authors joined with ", "
+    pub authors: Vec<String>,
+    pub genre: Option<String>,
+    pub publication_year: Option<i32>,
+    pub total_pages: Option<i32>,
+    pub cover_url: Option<String>,
+    pub source: String,
+    pub fetched_at: String, // RFC3339
+    pub from_cache: bool,
+}
+
+/// Both forms of a validated ISBN
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IsbnDto {
+    pub isbn13: String,
+    pub isbn10: Option<String>,
+}
+
+impl BookMetadataDto {
+    pub fn from_metadata(metadata: BookMetadata, from_cache: bool) -> Self {
+        BookMetadataDto {
+            isbn10: isbn13_to_10(&metadata.isbn),
+            author: Author::join_names(&metadata.authors),
+            isbn: metadata.isbn,
+            title: metadata.title,
+            authors: metadata.authors,
+            genre: metadata.genre,
+            publication_year: metadata.publication_year,
+            total_pages: metadata.total_pages,
+            cover_url: metadata.cover_url,
+            source: metadata.source,
+            fetched_at: metadata.fetched_at.to_rfc3339(),
+            from_cache,
+        }
+    }
+}
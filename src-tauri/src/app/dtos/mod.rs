@@ -1,25 +1,43 @@
 pub mod agenda_block_dto;
+pub mod author_dto;
 pub mod book_dto;
 pub mod book_summary_dto;
+pub mod chapter_dto;
 pub mod collection_dto;
+pub mod cover_dto;
+pub mod edition_dto;
+pub mod export_dto;
 pub mod goal_dto;
+pub mod import_dto;
 pub mod journal_entry_dto;
+pub mod metadata_dto;
 pub mod note_dto;
 pub mod reading_dto;
+pub mod series_dto;
 pub mod session_dto;
+pub mod session_photo_dto;
 pub mod setting_dto;
 pub mod statistics_dto;
 pub mod tag_dto;
 
 pub use agenda_block_dto::*;
+pub use author_dto::*;
 pub use book_dto::*;
 pub use book_summary_dto::*;
+pub use chapter_dto::*;
 pub use collection_dto::*;
+pub use cover_dto::*;
+pub use edition_dto::*;
+pub use export_dto::*;
 pub use goal_dto::*;
+pub use import_dto::*;
 pub use journal_entry_dto::*;
+pub use metadata_dto::*;
 pub use note_dto::*;
 pub use reading_dto::*;
+pub use series_dto::*;
 pub use session_dto::*;
+pub use session_photo_dto::*;
 pub use setting_dto::*;
 pub use statistics_dto::*;
 pub use tag_dto::*;
@@ -24,6 +24,8 @@ pub struct ReadingDto {
     pub completed_at: Option<String>, // RFC3339 format
     pub status: ReadingStatusDto,
     pub created_at: String, // RFC3339 format
+    pub rating: Option<f64>, // 0.5 - 5.0 in half-star steps
+    pub review: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +33,15 @@ pub struct CreateReadingCommand {
     pub book_id: i64,
 }
 
+/// Command for rating a reading; without reading_id the latest reading of the book is rated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateReadingCommand {
+    pub book_id: i64,
+    pub reading_id: Option<i64>,
+    pub rating: Option<f64>, // None clears the rating
+    pub review: Option<String>,
+}
+
 impl From<Reading> for ReadingDto {
     fn from(reading: Reading) -> Self {
         ReadingDto {
@@ -47,6 +58,8 @@ impl From<Reading> for ReadingDto {
                 crate::core::domains::reading::ReadingStatus::Completed => ReadingStatusDto::Completed,
             },
             created_at: reading.created_at.to_rfc3339(),
+            rating: reading.rating,
+            review: reading.review,
         }
     }
 }
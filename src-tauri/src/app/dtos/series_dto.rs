@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use crate::app::dtos::book_dto::{deserialize_nullable, BookDto};
+use crate::core::domains::series::Series;
+
+/// Series Data Transfer Object for API communication
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesDto {
+    pub id: Option<i64>,
+    pub name: String,
+    pub author: Option<String>,
+    pub expected_volumes: Option<i32>,
+    pub created_at: String, // ISO8601 string
+    pub updated_at: String, // ISO8601 string
+}
+
+impl From<Series> for SeriesDto {
+    fn from(series: Series) -> Self {
+        SeriesDto {
+            id: series.id,
+            name: series.name,
+            author: series.author,
+            expected_volumes: series.expected_volumes,
+            created_at: series.created_at.to_rfc3339(),
+            updated_at: series.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Series with its volumes in order, gaps, and the next volume to read
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesDetailDto {
+    pub series: SeriesDto,
+    pub books: Vec<BookDto>, // Ordered by series_index
+    pub missing_volumes: Vec<i32>,
+    pub completed_volumes: i32,
+    pub next_unread: Option<BookDto>,
+}
+
+/// Command for creating a series
+#[derive(Debug, Deserialize)]
+pub struct CreateSeriesCommand {
+    pub name: String,
+    pub author: Option<String>,
+    pub expected_volumes: Option<i32>,
+}
+
+/// Command for updating a series
+#[derive(Debug, Deserialize)]
+pub struct UpdateSeriesCommand {
+    pub id: i64,
+    pub name: Option<String>,
+    pub author: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub expected_volumes: Option<Option<i32>>, // None = not provided, Some(None) = length unknown
+}
+
+/// Filters for listing series
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListSeriesFilters {
+    // Currently no filters, but structure is ready for future additions
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
+use crate::app::dtos::import_dto::ImportRowErrorDto;
+use crate::core::domains::active_session::ActiveSession;
+use crate::core::domains::progress_correction::{ProgressCorrection, SessionChange};
 use crate::core::domains::session::ReadingSession;
+use crate::core::domains::session_validation::SessionIssue;
 
 /// Session Data Transfer Object for API communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,15 +11,43 @@ pub struct SessionDto {
     pub id: Option<i64>,
     pub book_id: i64,
     pub reading_id: Option<i64>,
+    pub edition_id: Option<i64>,
     pub session_date: String, // ISO date format (YYYY-MM-DD)
     pub start_time: Option<String>, // HH:MM:SS format
     pub end_time: Option<String>, // HH:MM:SS format
+    pub started_at: Option<String>, // RFC3339 with the local offset
+    pub ended_at: Option<String>, // RFC3339 with the local offset
+    pub timezone: Option<String>, // IANA zone name
     pub start_page: Option<i32>,
     pub end_page: Option<i32>,
     pub pages_read: Option<i32>,
-    pub minutes_read: Option<i32>,
+    #[serde(default)]
+    pub position_unit: Option<String>, // percentage or location, when positions were recorded
+    #[serde(default)]
+    pub start_position: Option<f64>,
+    #[serde(default)]
+    pub end_position: Option<f64>,
+    pub minutes_read: Option<i32>, // Audiobooks: minutes of the recording covered
     pub duration_seconds: Option<i32>,
     pub photo_path: Option<String>,
+    #[serde(default)]
+    pub playback_speed: Option<f64>, // Audiobooks, e.g. 1.5
+    #[serde(default)]
+    pub listening_seconds: Option<i32>, // Audiobooks: time spent listening at the playback speed
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub device: Option<String>, // paper, ereader, phone, tablet, computer, audio or other
+    #[serde(default)]
+    pub mood_before: Option<i32>, // 1-5
+    #[serde(default)]
+    pub mood_after: Option<i32>,
+    #[serde(default)]
+    pub energy_before: Option<i32>, // 1-5
+    #[serde(default)]
+    pub energy_after: Option<i32>,
+    #[serde(default)]
+    pub interruptions: Option<i32>,
     pub created_at: String, // ISO8601 string
     pub updated_at: String, // ISO8601 string
     pub duration_formatted: String, // Formatted duration (e.g., "1h 30m")
@@ -29,19 +61,37 @@ impl From<ReadingSession> for SessionDto {
             String::new()
         };
 
+        let listening_seconds = session.listening_seconds();
+
         SessionDto {
             id: session.id,
             book_id: session.book_id,
             reading_id: session.reading_id,
+            edition_id: session.edition_id,
             session_date: session.session_date.format("%Y-%m-%d").to_string(),
             start_time: session.start_time.map(|t| t.format("%H:%M:%S").to_string()),
             end_time: session.end_time.map(|t| t.format("%H:%M:%S").to_string()),
+            started_at: session.started_at.map(|d| d.to_rfc3339()),
+            ended_at: session.ended_at.map(|d| d.to_rfc3339()),
+            timezone: session.timezone,
             start_page: session.start_page,
             end_page: session.end_page,
             pages_read: session.pages_read,
+            position_unit: session.position_unit.map(|u| u.as_str().to_string()),
+            start_position: session.start_position,
+            end_position: session.end_position,
             minutes_read: session.minutes_read,
             duration_seconds: session.duration_seconds,
             photo_path: session.photo_path,
+            playback_speed: session.playback_speed,
+            listening_seconds,
+            location: session.context.location,
+            device: session.context.device.map(|d| d.as_str().to_string()),
+            mood_before: session.context.mood_before,
+            mood_after: session.context.mood_after,
+            energy_before: session.context.energy_before,
+            energy_after: session.context.energy_after,
+            interruptions: session.context.interruptions,
             created_at: session.created_at.to_rfc3339(),
             updated_at: session.updated_at.to_rfc3339(),
             duration_formatted,
@@ -49,7 +99,7 @@ impl From<ReadingSession> for SessionDto {
     }
 }
 
-fn format_duration(seconds: i32) -> String {
+pub(crate) fn format_duration(seconds: i32) -> String {
     let hours = seconds / 3600;
     let minutes = (seconds % 3600) / 60;
     
@@ -69,24 +119,51 @@ fn format_duration(seconds: i32) -> String {
 pub struct CreateSessionCommand {
     pub book_id: i64,
     pub reading_id: Option<i64>,
+    pub edition_id: Option<i64>, // Edition read in this session (None = the book's own format)
     pub session_date: String, // YYYY-MM-DD
     pub start_time: Option<String>, // HH:MM:SS
-    pub end_time: Option<String>, // HH:MM:SS
+    pub end_time: Option<String>, // HH:MM:SS; before start_time means after midnight
+    pub timezone: Option<String>, // IANA zone; defaults to the timezone setting
     pub start_page: Option<i32>,
     pub end_page: Option<i32>,
-    pub minutes_read: Option<i32>, // For audiobooks
+    pub start_position: Option<f64>, // Percentage or location books, instead of pages
+    pub end_position: Option<f64>,
+    pub minutes_read: Option<i32>, // For audiobooks; defaults to the timed minutes at the playback speed
+    pub playback_speed: Option<f64>, // For audiobooks, 0.5 to 4.0
+    pub confirm_warnings: Option<bool>, // Save despite overlap/page-order warnings
+    pub photo_paths: Option<Vec<String>>, // Page scans to copy into the app and attach
+    pub location: Option<String>,
+    pub device: Option<String>, // paper, ereader (or kindle), phone, tablet, computer, audio or other
+    pub mood_before: Option<i32>, // 1-5
+    pub mood_after: Option<i32>,
+    pub energy_before: Option<i32>, // 1-5
+    pub energy_after: Option<i32>,
+    pub interruptions: Option<i32>,
 }
 
 /// Command for updating a session
 #[derive(Debug, Deserialize)]
 pub struct UpdateSessionCommand {
     pub id: i64,
+    pub edition_id: Option<i64>,
     pub session_date: Option<String>,
     pub start_time: Option<String>,
     pub end_time: Option<String>,
+    pub timezone: Option<String>,
     pub start_page: Option<i32>,
     pub end_page: Option<i32>,
+    pub start_position: Option<f64>, // Replaces the pages; pages given instead clear recorded positions
+    pub end_position: Option<f64>,
     pub minutes_read: Option<i32>,
+    pub playback_speed: Option<f64>,
+    pub confirm_warnings: Option<bool>,
+    pub location: Option<String>, // An empty label clears it
+    pub device: Option<String>,   // An empty value clears it
+    pub mood_before: Option<i32>,
+    pub mood_after: Option<i32>,
+    pub energy_before: Option<i32>,
+    pub energy_after: Option<i32>,
+    pub interruptions: Option<i32>,
 }
 
 /// Filters for listing sessions
@@ -97,3 +174,213 @@ pub struct ListSessionsFilters {
     pub end_date: Option<String>,   // ISO date format: YYYY-MM-DD
 }
 
+/// Running session timer, as shown while the user is reading
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveSessionDto {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub reading_id: Option<i64>,
+    pub edition_id: Option<i64>,
+    pub start_page: Option<i32>,
+    pub started_at: String, // ISO8601 string
+    pub is_paused: bool,
+    pub paused_seconds: i64, // Including a pause in progress
+    pub elapsed_seconds: i64, // Reading time, excluding pauses
+    pub elapsed_formatted: String,
+}
+
+impl ActiveSessionDto {
+    pub fn from_session(session: &ActiveSession, now: chrono::DateTime<chrono::Utc>) -> Self {
+        let elapsed_seconds = session.elapsed_seconds(now);
+
+        ActiveSessionDto {
+            id: session.id,
+            book_id: session.book_id,
+            reading_id: session.reading_id,
+            edition_id: session.edition_id,
+            start_page: session.start_page,
+            started_at: session.started_at.to_rfc3339(),
+            is_paused: session.is_paused(),
+            paused_seconds: session.total_paused_seconds(now),
+            elapsed_seconds,
+            elapsed_formatted: format_duration(elapsed_seconds as i32),
+        }
+    }
+}
+
+/// Command for starting the session timer
+#[derive(Debug, Deserialize)]
+pub struct StartActiveSessionCommand {
+    pub book_id: i64,
+    pub reading_id: Option<i64>,
+    pub edition_id: Option<i64>,
+    pub start_page: Option<i32>, // Defaults to the book's current page (text books)
+}
+
+/// Command for finishing the session timer into a reading session
+#[derive(Debug, Deserialize)]
+pub struct FinishActiveSessionCommand {
+    pub end_page: Option<i32>,
+    pub end_position: Option<f64>, // Percentage or location books, instead of an end page
+    pub minutes_read: Option<i32>, // For audiobooks; defaults to the timed minutes at the playback speed
+    pub playback_speed: Option<f64>,
+    pub confirm_warnings: Option<bool>,
+}
+
+/// A consistency problem found in a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionIssueDto {
+    pub code: String, // "invalid_time", "page_out_of_range", "overlap", "page_regression"
+    pub severity: String, // "error" (rejected) or "warning" (needs confirmation)
+    pub message: String,
+    pub conflicting_session_id: Option<i64>,
+}
+
+impl From<SessionIssue> for SessionIssueDto {
+    fn from(issue: SessionIssue) -> Self {
+        SessionIssueDto {
+            code: issue.kind.as_str().to_string(),
+            severity: if issue.is_error() { "error" } else { "warning" }.to_string(),
+            message: issue.message,
+            conflicting_session_id: issue.conflicting_session_id,
+        }
+    }
+}
+
+/// Result of checking a session before saving it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionValidationDto {
+    pub is_valid: bool, // No errors
+    pub needs_confirmation: bool, // Warnings to confirm with confirm_warnings
+    pub issues: Vec<SessionIssueDto>,
+}
+
+impl SessionValidationDto {
+    pub fn from_issues(issues: Vec<SessionIssue>) -> Self {
+        let is_valid = !issues.iter().any(|i| i.is_error());
+        let needs_confirmation = issues.iter().any(|i| !i.is_error());
+
+        SessionValidationDto {
+            is_valid,
+            needs_confirmation,
+            issues: issues.into_iter().map(SessionIssueDto::from).collect(),
+        }
+    }
+}
+
+/// Command for creating many sessions at once (e.g. from a paper reading log)
+/// All sessions are saved in one transaction, or none if any is invalid
+#[derive(Debug, Deserialize)]
+pub struct CreateSessionsBulkCommand {
+    pub sessions: Vec<CreateSessionCommand>,
+    pub confirm_warnings: Option<bool>, // Applies to the whole batch
+}
+
+/// Command for previewing or importing sessions from CSV
+/// Columns: title or isbn, date, and optionally start_time, end_time, start_page, end_page, minutes
+#[derive(Debug, Deserialize)]
+pub struct ImportSessionsCsvCommand {
+    pub content: String,
+    pub timezone: Option<String>, // Zone the times were written in; defaults to the timezone setting
+    pub confirm_warnings: Option<bool>,
+}
+
+/// One row of a session CSV preview
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionCsvRowDto {
+    pub row_number: usize,
+    pub title: Option<String>,
+    pub isbn: Option<String>,
+    pub book_id: Option<i64>, // Matched book
+    pub book_title: Option<String>,
+    pub session_date: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub start_page: Option<i32>,
+    pub end_page: Option<i32>,
+    pub minutes_read: Option<i32>,
+    pub warnings: Vec<SessionIssueDto>,
+    pub error: Option<String>, // Why this row will not be imported
+}
+
+/// Preview of a session CSV import, shown before anything is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionCsvPreviewDto {
+    pub total_rows: usize,
+    pub importable_count: usize,
+    pub warning_count: usize, // Importable rows with warnings to confirm
+    pub error_count: usize,
+    pub rows: Vec<SessionCsvRowDto>,
+}
+
+/// Outcome of a bulk create or CSV import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkSessionsResultDto {
+    pub created_count: usize,
+    pub sessions: Vec<SessionDto>,
+    pub recalculated_book_ids: Vec<i64>,
+    pub errors: Vec<ImportRowErrorDto>, // CSV rows that were skipped
+}
+
+/// Command for correcting a book's current position by rewriting its session history
+#[derive(Debug, Deserialize)]
+pub struct CorrectProgressCommand {
+    pub book_id: i64,
+    pub mode: String, // "adjustment" (add a session) or "rebase" (scale later sessions)
+    pub current_page: Option<i32>, // Corrected page, for text books
+    pub current_minutes: Option<i32>, // Corrected minute, for audiobooks
+    pub from_session_id: Option<i64>, // Rebase: first session to scale (default: all sessions)
+    pub note: Option<String>,
+}
+
+/// One session field changed by a progress correction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionChangeDto {
+    pub session_id: Option<i64>,
+    pub field: String,
+    pub old_value: Option<i32>,
+    pub new_value: Option<i32>,
+}
+
+impl From<SessionChange> for SessionChangeDto {
+    fn from(change: SessionChange) -> Self {
+        SessionChangeDto {
+            session_id: change.session_id,
+            field: change.field,
+            old_value: change.old_value,
+            new_value: change.new_value,
+        }
+    }
+}
+
+/// Audit entry of a progress correction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressCorrectionDto {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub mode: String,
+    pub unit: String, // "pages" or "minutes"
+    pub previous_value: i32,
+    pub corrected_value: i32,
+    pub adjustment_session_id: Option<i64>,
+    pub changes: Vec<SessionChangeDto>,
+    pub note: Option<String>,
+    pub created_at: String,
+}
+
+impl From<ProgressCorrection> for ProgressCorrectionDto {
+    fn from(correction: ProgressCorrection) -> Self {
+        ProgressCorrectionDto {
+            id: correction.id,
+            book_id: correction.book_id,
+            mode: correction.mode.as_str().to_string(),
+            unit: correction.unit.as_str().to_string(),
+            previous_value: correction.previous_value,
+            corrected_value: correction.corrected_value,
+            adjustment_session_id: correction.adjustment_session_id,
+            changes: correction.changes.into_iter().map(SessionChangeDto::from).collect(),
+            note: correction.note,
+            created_at: correction.created_at.to_rfc3339(),
+        }
+    }
+}
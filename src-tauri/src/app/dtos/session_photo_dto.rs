@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::session_photo::SessionPhoto;
+
+/// Command to attach a photo (e.g. a page scan) to a session
+#[derive(Debug, Clone, Deserialize)]
+pub struct AttachSessionPhotoCommand {
+    pub session_id: i64,
+    pub source: String, // Local file path
+}
+
+/// Photo linked to a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPhotoDto {
+    pub id: Option<i64>,
+    pub session_id: i64,
+    pub hash: String,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+    pub source: Option<String>,
+    pub created_at: String, // RFC3339
+}
+
+/// Photo content, ready to use as an <img> source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPhotoImageDto {
+    pub photo_id: i64,
+    pub mime_type: String,
+    pub data_url: String, // data:<mime>;base64,...
+}
+
+/// Photo file carried inside a full backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPhotoBackupDto {
+    pub session_id: i64,
+    pub file_name: String,
+    pub source: Option<String>,
+    pub data_base64: String,
+}
+
+impl From<SessionPhoto> for SessionPhotoDto {
+    fn from(photo: SessionPhoto) -> Self {
+        SessionPhotoDto {
+            mime_type: photo.mime_type().to_string(),
+            id: photo.id,
+            session_id: photo.session_id,
+            hash: photo.hash,
+            width: photo.width,
+            height: photo.height,
+            source: photo.source,
+            created_at: photo.created_at.to_rfc3339(),
+        }
+    }
+}
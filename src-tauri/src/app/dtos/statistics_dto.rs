@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::core::domains::session_context::ContextBucket;
 
 /// Statistics Data Transfer Object for API communication
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,12 +7,15 @@ pub struct StatisticsDto {
     pub today: TodayStatistics,
     pub this_month: MonthStatistics,
     pub current_book: Option<CurrentBookStatistics>,
+    pub reading_speed: ReadingSpeedStatistics,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TodayStatistics {
+    pub date: String, // Local date in the user's time zone (YYYY-MM-DD)
     pub pages_read: i32,
-    pub minutes_read: i32,
+    pub minutes_read: i32, // Audiobook minutes covered
+    pub listening_minutes: i32, // Time spent listening, at the playback speeds used
     pub sessions_count: i32,
     pub duration_seconds: i32,
 }
@@ -19,11 +23,22 @@ pub struct TodayStatistics {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonthStatistics {
     pub pages_read: i32,
-    pub minutes_read: i32,
+    pub minutes_read: i32, // Audiobook minutes covered
+    pub listening_minutes: i32, // Time spent listening, at the playback speeds used
+    pub average_playback_speed: Option<f64>, // Minutes covered per minute listened
     pub sessions_count: i32,
     pub books_completed: i32,
 }
 
+/// Library-wide speeds from timed sessions, with outlier sessions left out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingSpeedStatistics {
+    pub pages_per_hour: Option<f64>,
+    pub listening_speed: Option<f64>, // Audio minutes per minute listened
+    pub page_sessions_used: i32,
+    pub audio_sessions_used: i32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentBookStatistics {
     pub book_id: i64,
@@ -36,3 +51,48 @@ pub struct CurrentBookStatistics {
     pub status: String,
 }
 
+/// Filters for reading-context statistics; all sessions when empty
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ContextStatisticsFilters {
+    pub book_id: Option<i64>,
+    pub start_date: Option<String>, // YYYY-MM-DD
+    pub end_date: Option<String>,   // YYYY-MM-DD
+}
+
+/// Reading totals and pace for one location, device or time of day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextBucketDto {
+    pub key: String, // Location label, device name, or morning/afternoon/evening/night
+    pub session_count: i32,
+    pub pages_read: i32,
+    pub minutes_listened: i32,
+    pub reading_seconds: i64,
+    pub pages_per_hour: Option<f64>,
+    pub average_mood_change: Option<f64>, // Positive: felt better after reading
+    pub average_energy_change: Option<f64>,
+    pub average_interruptions: Option<f64>,
+}
+
+/// Reading habits split by where, on what and when sessions took place
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextStatisticsDto {
+    pub by_location: Vec<ContextBucketDto>,
+    pub by_device: Vec<ContextBucketDto>,
+    pub by_time_of_day: Vec<ContextBucketDto>,
+}
+
+impl From<ContextBucket> for ContextBucketDto {
+    fn from(bucket: ContextBucket) -> Self {
+        ContextBucketDto {
+            key: bucket.key,
+            session_count: bucket.session_count as i32,
+            pages_read: bucket.pages_read,
+            minutes_listened: bucket.minutes_listened,
+            reading_seconds: bucket.reading_seconds,
+            pages_per_hour: bucket.pages_per_hour,
+            average_mood_change: bucket.average_mood_change,
+            average_energy_change: bucket.average_energy_change,
+            average_interruptions: bucket.average_interruptions,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+/// A reading session whose timer is running (or paused) and not yet saved as a ReadingSession.
+/// Stored in the database so it survives closing the app.
+#[derive(Debug, Clone)]
+pub struct ActiveSession {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub reading_id: Option<i64>,
+    pub edition_id: Option<i64>,
+    pub start_page: Option<i32>,
+    pub started_at: DateTime<Utc>,
+    pub paused_at: Option<DateTime<Utc>>, // Set while paused
+    pub paused_seconds: i64,              // Completed pauses; the current one is added on resume
+}
+
+impl ActiveSession {
+    pub fn new(book_id: i64, start_page: Option<i32>, now: DateTime<Utc>) -> Result<Self, String> {
+        if start_page.map(|p| p < 0).unwrap_or(false) {
+            return Err("Page numbers cannot be negative".to_string());
+        }
+
+        Ok(ActiveSession {
+            id: None,
+            book_id,
+            reading_id: None,
+            edition_id: None,
+            start_page,
+            started_at: now,
+            paused_at: None,
+            paused_seconds: 0,
+        })
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    pub fn pause(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        if self.is_paused() {
+            return Err("Session is already paused".to_string());
+        }
+        self.paused_at = Some(now.max(self.started_at));
+        Ok(())
+    }
+
+    pub fn resume(&mut self, now: DateTime<Utc>) -> Result<(), String> {
+        let paused_at = self.paused_at.ok_or("Session is not paused".to_string())?;
+        self.paused_seconds += (now - paused_at).num_seconds().max(0);
+        self.paused_at = None;
+        Ok(())
+    }
+
+    /// Seconds paused so far, including a pause still in progress
+    pub fn total_paused_seconds(&self, now: DateTime<Utc>) -> i64 {
+        let current = self.paused_at.map(|p| (now - p).num_seconds().max(0)).unwrap_or(0);
+        self.paused_seconds + current
+    }
+
+    /// Reading time so far, excluding pauses
+    pub fn elapsed_seconds(&self, now: DateTime<Utc>) -> i64 {
+        ((now - self.started_at).num_seconds() - self.total_paused_seconds(now)).max(0)
+    }
+
+    /// When the reading stopped: the start of a pause in progress, otherwise now
+    pub fn ended_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        self.paused_at.unwrap_or(now)
+    }
+}
@@ -0,0 +1,159 @@
+/// A chapter of an audiobook, starting at a timestamp into the recording
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudiobookChapter {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub position: i32, // 0-based order within the book
+    pub title: String,
+    pub start_seconds: i32,
+}
+
+impl AudiobookChapter {
+    /// Creates a new chapter with validation
+    pub fn new(book_id: i64, title: String, start_seconds: i32) -> Result<Self, String> {
+        let title = title.trim().to_string();
+        if title.is_empty() {
+            return Err("Chapter title cannot be empty".to_string());
+        }
+        if start_seconds < 0 {
+            return Err("Chapter start cannot be negative".to_string());
+        }
+
+        Ok(AudiobookChapter {
+            id: None,
+            book_id,
+            position: 0,
+            title,
+            start_seconds,
+        })
+    }
+
+    /// Sorts chapters by start, numbers them and checks they fit in the recording
+    pub fn arrange(mut chapters: Vec<AudiobookChapter>, total_minutes: Option<i32>) -> Result<Vec<AudiobookChapter>, String> {
+        chapters.sort_by_key(|c| c.start_seconds);
+
+        for pair in chapters.windows(2) {
+            if pair[0].start_seconds == pair[1].start_seconds {
+                return Err(format!(
+                    "Chapters \"{}\" and \"{}\" both start at {}",
+                    pair[0].title,
+                    pair[1].title,
+                    format_timestamp(pair[0].start_seconds)
+                ));
+            }
+        }
+
+        if let (Some(last), Some(total)) = (chapters.last(), total_minutes) {
+            if last.start_seconds >= total * 60 {
+                return Err(format!(
+                    "Chapter \"{}\" starts at {}, after the end of the recording ({})",
+                    last.title,
+                    format_timestamp(last.start_seconds),
+                    format_timestamp(total * 60)
+                ));
+            }
+        }
+
+        for (position, chapter) in chapters.iter_mut().enumerate() {
+            chapter.position = position as i32;
+        }
+
+        Ok(chapters)
+    }
+}
+
+/// Parses a timestamp as H:MM:SS, M:SS or plain seconds
+pub fn parse_timestamp(value: &str) -> Result<i32, String> {
+    let invalid = || format!("Invalid timestamp: {} (expected H:MM:SS, M:SS or seconds)", value);
+
+    let parts = value
+        .trim()
+        .split(':')
+        .map(|part| part.parse::<u32>().map_err(|_| invalid()))
+        .collect::<Result<Vec<u32>, String>>()?;
+
+    let seconds = match parts.as_slice() {
+        [seconds] => *seconds,
+        [minutes, seconds] if *seconds < 60 => minutes * 60 + seconds,
+        [hours, minutes, seconds] if *minutes < 60 && *seconds < 60 => hours * 3600 + minutes * 60 + seconds,
+        _ => return Err(invalid()),
+    };
+
+    i32::try_from(seconds).map_err(|_| invalid())
+}
+
+/// Formats seconds as H:MM:SS
+pub fn format_timestamp(seconds: i32) -> String {
+    format!("{}:{:02}:{:02}", seconds / 3600, (seconds % 3600) / 60, seconds % 60)
+}
+
+/// Whether a chapter has been listened to, relative to the current position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChapterStatus {
+    Finished,
+    Current,
+    Upcoming,
+}
+
+impl ChapterStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChapterStatus::Finished => "finished",
+            ChapterStatus::Current => "current",
+            ChapterStatus::Upcoming => "upcoming",
+        }
+    }
+}
+
+/// Where the listening position falls in one chapter
+#[derive(Debug, Clone)]
+pub struct ChapterProgress {
+    pub chapter: AudiobookChapter,
+    pub end_seconds: Option<i32>, // Next chapter's start, or the end of the recording
+    pub listened_seconds: i32,
+    pub status: ChapterStatus,
+}
+
+impl ChapterProgress {
+    /// Places the position (seconds into the recording) among ordered chapters.
+    /// The position's chapter is current until the recording is listened to the end.
+    pub fn compute(chapters: &[AudiobookChapter], position_seconds: i32, total_seconds: Option<i32>) -> Vec<ChapterProgress> {
+        let finished_all = total_seconds.map(|total| position_seconds >= total).unwrap_or(false);
+
+        chapters
+            .iter()
+            .enumerate()
+            .map(|(i, chapter)| {
+                let end_seconds = chapters.get(i + 1).map(|next| next.start_seconds).or(total_seconds);
+                let status = if finished_all || end_seconds.map(|end| position_seconds >= end).unwrap_or(false) {
+                    ChapterStatus::Finished
+                } else if position_seconds >= chapter.start_seconds {
+                    ChapterStatus::Current
+                } else {
+                    ChapterStatus::Upcoming
+                };
+                let listened_seconds = match (status, end_seconds) {
+                    (ChapterStatus::Finished, Some(end)) => end - chapter.start_seconds,
+                    (ChapterStatus::Upcoming, _) => 0,
+                    _ => position_seconds - chapter.start_seconds,
+                };
+
+                ChapterProgress {
+                    chapter: chapter.clone(),
+                    end_seconds,
+                    listened_seconds: listened_seconds.max(0),
+                    status,
+                }
+            })
+            .collect()
+    }
+
+    /// Share of the chapter listened to (0-100), when its length is known
+    pub fn percentage(&self) -> Option<f64> {
+        let length = self.end_seconds? - self.chapter.start_seconds;
+        if length <= 0 {
+            return None;
+        }
+        Some(((self.listened_seconds as f64 / length as f64 * 100.0).min(100.0) * 10.0).round() / 10.0)
+    }
+}
@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::book::BookType;
+
+/// Role a person plays in a book
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ContributorRole {
+    Author,
+    Translator,
+    Editor,
+    Narrator, // Audiobooks only
+}
+
+impl ContributorRole {
+    /// Returns true if the role makes sense for the given book type
+    pub fn is_valid_for(&self, book_type: &BookType) -> bool {
+        match self {
+            ContributorRole::Narrator => *book_type == BookType::Audiobook,
+            _ => true,
+        }
+    }
+}
+
+/// Author entity shared between books (also used for translators, editors and narrators)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Author {
+    pub id: Option<i64>,
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// An author linked to a book with a role
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BookContributor {
+    pub book_id: i64,
+    pub author: Author,
+    pub role: ContributorRole,
+    pub position: i32, // Display order within the book
+}
+
+impl Author {
+    /// Creates a new Author with validation
+    pub fn new(name: String) -> Result<Self, String> {
+        let name = Self::normalize_name(&name);
+        Self::validate_name(&name)?;
+
+        let now = chrono::Utc::now();
+        Ok(Author {
+            id: None,
+            name,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Updates the author name
+    pub fn update_name(&mut self, name: String) -> Result<(), String> {
+        let name = Self::normalize_name(&name);
+        Self::validate_name(&name)?;
+
+        self.name = name;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    /// Splits a free-text author field ("Neil Gaiman & Terry Pratchett", "A; B and C")
+    /// into names in display order
+    /// Commas are kept, since they belong to names such as "King, Stephen" or "Martin Luther King, Jr."
+    pub fn split_names(authors: &str) -> Vec<String> {
+        authors
+            .split([';', '&'])
+            .flat_map(|part| {
+                let words: Vec<&str> = part.split_whitespace().collect();
+                words
+                    .split(|word| word.eq_ignore_ascii_case("and"))
+                    .map(|name| name.join(" "))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Joins names into a free-text author field that `split_names` reads back
+    pub fn join_names(names: &[String]) -> Option<String> {
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join(" & "))
+        }
+    }
+
+    /// Trims and collapses inner whitespace so "Ursula  K. Le Guin " matches "Ursula K. Le Guin"
+    /// Case is compared by the database (authors.name is COLLATE NOCASE)
+    pub fn normalize_name(name: &str) -> String {
+        name.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn validate_name(name: &str) -> Result<(), String> {
+        if name.is_empty() {
+            return Err("Author name cannot be empty".to_string());
+        }
+
+        if name.len() > 200 {
+            return Err("Author name cannot exceed 200 characters".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_ampersands_semicolons_and_the_word_and() {
+        assert_eq!(Author::split_names("Neil Gaiman & Terry Pratchett"), vec!["Neil Gaiman", "Terry Pratchett"]);
+        assert_eq!(Author::split_names("A. Smith; B. Jones and C. Brown"), vec!["A. Smith", "B. Jones", "C. Brown"]);
+        assert_eq!(Author::split_names("Ann AND Bob"), vec!["Ann", "Bob"]);
+    }
+
+    #[test]
+    fn keeps_commas_inside_names() {
+        assert_eq!(Author::split_names("King, Stephen"), vec!["King, Stephen"]);
+        assert_eq!(Author::split_names("Martin Luther King, Jr."), vec!["Martin Luther King, Jr."]);
+    }
+
+    #[test]
+    fn does_not_split_names_containing_and() {
+        assert_eq!(Author::split_names("Alexander Andersen"), vec!["Alexander Andersen"]);
+    }
+
+    #[test]
+    fn skips_empty_parts() {
+        assert_eq!(Author::split_names(" & Ann ;; "), vec!["Ann"]);
+        assert!(Author::split_names("").is_empty());
+    }
+
+    #[test]
+    fn joined_names_split_back_to_the_same_names() {
+        let names = vec!["King, Stephen".to_string(), "Peter Straub".to_string()];
+        let joined = Author::join_names(&names).unwrap();
+        assert_eq!(Author::split_names(&joined), names);
+        assert_eq!(Author::join_names(&[]), None);
+    }
+
+    #[test]
+    fn normalizes_whitespace() {
+        assert_eq!(Author::normalize_name("  Ursula  K. Le Guin "), "Ursula K. Le Guin");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::domains::progress_unit::ProgressUnit;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -33,6 +34,8 @@ pub struct Book {
     pub total_minutes: Option<i32>, // For audiobooks
     pub current_page_text: i32,
     pub current_minutes_audio: i32,
+    pub progress_unit: ProgressUnit, // Unit positions are recorded in (text books)
+    pub total_locations: Option<i32>, // Kindle locations, for the location unit
     pub status: BookStatus,
     pub is_archived: bool,
     pub is_wishlist: bool,
@@ -41,6 +44,12 @@ pub struct Book {
     pub added_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
     pub status_changed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub series_id: Option<i64>,
+    pub series_index: Option<f64>, // Volume number within the series (e.g., 3.0, 2.5)
+    // Rating aggregates derived from book_readings (read-only, not persisted on books)
+    pub average_rating: Option<f64>,
+    pub latest_rating: Option<f64>,
+    pub ratings_count: i32,
 }
 
 impl Book {
@@ -81,6 +90,8 @@ impl Book {
             total_minutes,
             current_page_text: 0,
             current_minutes_audio: 0,
+            progress_unit: ProgressUnit::Page,
+            total_locations: None,
             status: BookStatus::NotStarted,
             is_archived: false,
             is_wishlist: false,
@@ -89,6 +100,11 @@ impl Book {
             added_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
             status_changed_at: None,
+            series_id: None,
+            series_index: None,
+            average_rating: None,
+            latest_rating: None,
+            ratings_count: 0,
         })
     }
 
@@ -133,6 +149,24 @@ impl Book {
         }
     }
 
+    /// Sets the unit reading positions are recorded in; locations need the book's total locations
+    pub fn update_progress_unit(&mut self, unit: ProgressUnit, total_locations: Option<i32>) -> Result<(), String> {
+        if total_locations.map(|total| total <= 0).unwrap_or(false) {
+            return Err("Total locations must be greater than 0".to_string());
+        }
+        if unit != ProgressUnit::Page && self.book_type == BookType::Audiobook {
+            return Err("Audiobook progress is tracked in minutes".to_string());
+        }
+        if unit == ProgressUnit::Location && total_locations.is_none() {
+            return Err("Tracking by location needs the book's total number of locations".to_string());
+        }
+
+        self.progress_unit = unit;
+        self.total_locations = total_locations;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
     /// Marks book as reading
     pub fn mark_as_reading(&mut self) {
         if self.status != BookStatus::Reading {
@@ -149,6 +183,19 @@ impl Book {
         self.updated_at = chrono::Utc::now();
     }
 
+    /// Assigns the book to a series with an optional volume number
+    pub fn update_series(&mut self, series_id: Option<i64>, series_index: Option<f64>) -> Result<(), String> {
+        if let Some(index) = series_index {
+            if index <= 0.0 {
+                return Err("Series index must be greater than 0".to_string());
+            }
+        }
+        self.series_id = series_id;
+        self.series_index = series_index;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
     /// Updates current page with validation
     pub fn update_current_page(&mut self, page: i32) -> Result<(), String> {
         if let Some(total) = self.total_pages {
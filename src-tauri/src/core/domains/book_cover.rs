@@ -0,0 +1,66 @@
+/// A cover image stored in the app's covers folder, linked to a book.
+/// Files are named by the SHA-256 of their content, so identical covers are stored once.
+#[derive(Debug, Clone)]
+pub struct BookCover {
+    pub book_id: i64,
+    pub hash: String,      // SHA-256 of the original image, hex
+    pub extension: String, // "jpg", "png" or "webp"
+    pub width: u32,
+    pub height: u32,
+    pub source: Option<String>, // File path or URL the cover was imported from
+    pub imported_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Image stored by the cover storage, before it is linked to a book
+#[derive(Debug, Clone)]
+pub struct StoredImage {
+    pub hash: String,
+    pub extension: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl BookCover {
+    pub fn new(book_id: i64, image: StoredImage, source: Option<String>) -> Self {
+        BookCover {
+            book_id,
+            hash: image.hash,
+            extension: image.extension,
+            width: image.width,
+            height: image.height,
+            source,
+            imported_at: chrono::Utc::now(),
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{}.{}", self.hash, self.extension)
+    }
+
+    /// Thumbnails are always JPEG
+    pub fn thumbnail_file_name(&self) -> String {
+        thumbnail_file_name(&self.hash)
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        mime_type_for_extension(&self.extension)
+    }
+}
+
+pub fn thumbnail_file_name(hash: &str) -> String {
+    format!("{}_thumb.jpg", hash)
+}
+
+pub fn mime_type_for_extension(extension: &str) -> &'static str {
+    match extension {
+        "png" => "image/png",
+        "webp" => "image/webp",
+        _ => "image/jpeg",
+    }
+}
+
+/// True for covers given as a web address rather than a file path
+pub fn is_remote_source(source: &str) -> bool {
+    let source = source.trim().to_lowercase();
+    source.starts_with("http://") || source.starts_with("https://")
+}
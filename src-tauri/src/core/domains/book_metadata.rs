@@ -0,0 +1,78 @@
+use serde_json::Value;
+
+/// Bibliographic metadata fetched for an ISBN, used to prefill new books
+#[derive(Debug, Clone)]
+pub struct BookMetadata {
+    pub isbn: String, // ISBN-13
+    pub title: String,
+    pub authors: Vec<String>,
+    pub total_pages: Option<i32>,
+    pub publication_year: Option<i32>,
+    pub cover_url: Option<String>,
+    pub genre: Option<String>,
+    pub source: String, // Provider that supplied the data, e.g. "open_library"
+    pub fetched_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl BookMetadata {
+    /// Reads one record of an Open Library `api/books?jscmd=data` response.
+    /// Returns None when the record has no title.
+    pub fn from_open_library(isbn: &str, record: &Value) -> Option<BookMetadata> {
+        let title = record.get("title")?.as_str()?.trim();
+        if title.is_empty() {
+            return None;
+        }
+        let title = match record.get("subtitle").and_then(|s| s.as_str()).map(str::trim) {
+            Some(subtitle) if !subtitle.is_empty() => format!("{}: {}", title, subtitle),
+            _ => title.to_string(),
+        };
+
+        let names = |key: &str| -> Vec<String> {
+            record
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item.get("name").and_then(|n| n.as_str()))
+                        .map(|name| name.trim().to_string())
+                        .filter(|name| !name.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let cover_url = record.get("cover").and_then(|cover| {
+            ["large", "medium", "small"]
+                .iter()
+                .find_map(|size| cover.get(*size).and_then(|u| u.as_str()))
+                .map(|url| url.to_string())
+        });
+
+        Some(BookMetadata {
+            isbn: isbn.to_string(),
+            title,
+            authors: names("authors"),
+            total_pages: record
+                .get("number_of_pages")
+                .and_then(|p| p.as_i64())
+                .and_then(|p| i32::try_from(p).ok())
+                .filter(|p| *p > 0),
+            publication_year: record
+                .get("publish_date")
+                .and_then(|d| d.as_str())
+                .and_then(publication_year),
+            cover_url,
+            genre: names("subjects").into_iter().next(),
+            source: "open_library".to_string(),
+            fetched_at: chrono::Utc::now(),
+        })
+    }
+}
+
+/// Year from free-form publish dates like "March 2005", "2005-03-01" or "c1998"
+fn publication_year(date: &str) -> Option<i32> {
+    date.split(|c: char| !c.is_ascii_digit())
+        .find(|part| part.len() == 4)
+        .and_then(|part| part.parse().ok())
+}
@@ -0,0 +1,65 @@
+use chrono::NaiveDate;
+use crate::core::domains::book::{BookStatus, BookType};
+use crate::core::domains::library_import::{normalize_isbn, ImportedBook};
+
+/// A book as stored in a Calibre library's metadata.db
+#[derive(Debug, Clone)]
+pub struct CalibreBook {
+    pub calibre_id: i64,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub series: Option<String>,
+    pub series_index: Option<f64>,
+    pub tags: Vec<String>,
+    pub identifiers: Vec<(String, String)>, // (type, value), e.g. ("isbn", "9780441013593")
+    pub formats: Vec<String>,               // File formats in the library, e.g. "EPUB", "PDF"
+    pub publication_year: Option<i32>,
+    pub added_on: Option<NaiveDate>,
+}
+
+impl CalibreBook {
+    pub fn isbn(&self) -> Option<String> {
+        self.identifiers
+            .iter()
+            .find(|(kind, value)| kind.eq_ignore_ascii_case("isbn") && normalize_isbn(value).is_some())
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Book type from the stored formats: audio files win, then ebooks, then PDF
+    pub fn book_type(&self) -> BookType {
+        let has = |names: &[&str]| self.formats.iter().any(|f| names.contains(&f.to_uppercase().as_str()));
+        if has(&["M4B", "MP3", "M4A", "AAC", "OGG", "FLAC"]) {
+            BookType::Audiobook
+        } else if has(&["EPUB", "KEPUB", "MOBI", "AZW", "AZW3", "KFX", "FB2", "LIT"]) {
+            BookType::Ebook
+        } else if has(&["CBZ", "CBR", "CB7"]) {
+            BookType::Comic
+        } else if has(&["PDF"]) {
+            BookType::Pdf
+        } else {
+            BookType::PhysicalBook
+        }
+    }
+
+    /// Converts to the shared import record, so matching and saving follow the CSV imports
+    pub fn to_imported_book(&self, row_number: usize) -> ImportedBook {
+        ImportedBook {
+            row_number,
+            title: self.title.clone(),
+            authors: self.authors.clone(),
+            isbn: self.isbn(),
+            book_type: self.book_type(),
+            total_pages: None, // Calibre has no page count without plugins
+            publication_year: self.publication_year,
+            status: BookStatus::NotStarted,
+            is_wishlist: false,
+            tags: self.tags.clone(),
+            rating: None,
+            review: None,
+            date_started: None,
+            date_read: None,
+            date_added: self.added_on,
+            read_count: 0,
+        }
+    }
+}
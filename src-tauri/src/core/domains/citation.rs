@@ -0,0 +1,506 @@
+use serde_json::{json, Value};
+use crate::core::domains::author::Author;
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::library_import::{normalize_isbn, ImportedBook};
+
+/// Reference manager formats for citation export
+#[derive(Debug, Clone, PartialEq)]
+pub enum CitationFormat {
+    BibTex,
+    Ris,
+    CslJson,
+}
+
+/// Bibliographic data of one work, shared by the export writers and the importers
+#[derive(Debug, Clone)]
+pub struct CitationItem {
+    pub row_number: usize, // Entry position in an imported file (1-based); 0 for exports
+    pub book_type: BookType,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub editors: Vec<String>,
+    pub year: Option<i32>,
+    pub isbn: Option<String>,
+    pub url: Option<String>,
+    pub pages: Option<i32>, // Page count (from a page range on import)
+}
+
+impl CitationItem {
+    /// Builds the citation of a library book; `authors` and `editors` come from its contributors
+    pub fn from_book(book: &Book, authors: Vec<String>, editors: Vec<String>) -> Self {
+        // Books without contributors fall back to the free-text author field
+        let authors = if authors.is_empty() {
+            book.author
+                .as_deref()
+                .map(Author::split_names)
+                .unwrap_or_default()
+        } else {
+            authors
+        };
+
+        CitationItem {
+            row_number: 0,
+            book_type: book.book_type.clone(),
+            title: book.title.clone(),
+            authors,
+            editors,
+            year: book.publication_year,
+            isbn: book.isbn.clone(),
+            url: book.url.clone(),
+            pages: book.total_pages,
+        }
+    }
+
+    /// Converts an imported citation to the shared import record, so saving follows the CSV imports
+    pub fn to_imported_book(&self) -> ImportedBook {
+        ImportedBook {
+            row_number: self.row_number,
+            title: self.title.clone(),
+            authors: self.authors.clone(),
+            isbn: self.isbn.clone(),
+            book_type: self.book_type.clone(),
+            total_pages: self.pages,
+            publication_year: self.year,
+            status: BookStatus::NotStarted,
+            is_wishlist: false,
+            tags: Vec::new(),
+            rating: None,
+            review: None,
+            date_started: None,
+            date_read: None,
+            date_added: None,
+            read_count: 0,
+        }
+    }
+}
+
+/// Writes citations in the given format
+pub fn write_citations(items: &[CitationItem], format: &CitationFormat) -> String {
+    let keys = citation_keys(items);
+    match format {
+        CitationFormat::BibTex => items
+            .iter()
+            .zip(&keys)
+            .map(|(item, key)| bibtex_entry(item, key))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        CitationFormat::Ris => items.iter().map(ris_entry).collect::<Vec<_>>().join("\n"),
+        CitationFormat::CslJson => {
+            let values: Vec<Value> = items.iter().zip(&keys).map(|(item, key)| csl_item(item, key)).collect();
+            serde_json::to_string_pretty(&values).unwrap_or_else(|_| "[]".to_string()) + "\n"
+        }
+    }
+}
+
+/// Entries read from a file plus per-entry errors (entry number, message).
+pub type ParsedCitations = (Vec<CitationItem>, Vec<(usize, String)>);
+
+/// Parses a BibTeX or RIS file (detected from its content).
+/// Returns the entries that could be read plus per-entry errors (entry number, message).
+pub fn parse_citations(content: &str) -> Result<ParsedCitations, String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let is_ris = content.lines().any(|l| l.trim_start().starts_with("TY  -"));
+    if is_ris {
+        Ok(parse_ris(content))
+    } else if content.contains('@') {
+        Ok(parse_bibtex(content))
+    } else {
+        Err("File is neither BibTeX nor RIS".to_string())
+    }
+}
+
+// --- Export writers ---
+
+/// Unique keys like "leguin1969left"; repeats get a letter suffix
+fn citation_keys(items: &[CitationItem]) -> Vec<String> {
+    let mut keys: Vec<String> = Vec::new();
+    for item in items {
+        let family = item.authors.first().map(|a| split_name(a).0).unwrap_or_default();
+        let word = item.title
+            .split_whitespace()
+            .map(key_part)
+            .find(|w| w.len() > 3)
+            .unwrap_or_default();
+        let year = item.year.map(|y| y.to_string()).unwrap_or_default();
+        let mut base = format!("{}{}{}", key_part(&family), year, word);
+        if base.is_empty() {
+            base = "untitled".to_string();
+        }
+
+        let mut key = base.clone();
+        let mut suffix = b'a';
+        while keys.contains(&key) && suffix <= b'z' {
+            key = format!("{}{}", base, suffix as char);
+            suffix += 1;
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+fn key_part(value: &str) -> String {
+    value.chars().filter(|c| c.is_ascii_alphanumeric()).collect::<String>().to_lowercase()
+}
+
+fn bibtex_entry(item: &CitationItem, key: &str) -> String {
+    let entry_type = match item.book_type {
+        BookType::Article => "article",
+        BookType::Pdf => "misc",
+        _ => "book",
+    };
+
+    // Double braces keep the title's capitalization in every style
+    let mut fields = vec![("title", format!("{{{{{}}}}}", bibtex_escape(&item.title)))];
+    if !item.authors.is_empty() {
+        fields.push(("author", format!("{{{}}}", bibtex_names(&item.authors))));
+    }
+    if !item.editors.is_empty() {
+        fields.push(("editor", format!("{{{}}}", bibtex_names(&item.editors))));
+    }
+    if let Some(year) = item.year {
+        fields.push(("year", format!("{{{}}}", year)));
+    }
+    if let Some(isbn) = &item.isbn {
+        fields.push(("isbn", format!("{{{}}}", bibtex_escape(isbn))));
+    }
+    if let Some(url) = &item.url {
+        fields.push(("url", format!("{{{}}}", url)));
+    }
+
+    let body: Vec<String> = fields.iter().map(|(name, value)| format!("  {} = {}", name, value)).collect();
+    format!("@{}{{{},\n{}\n}}\n", entry_type, key, body.join(",\n"))
+}
+
+fn bibtex_names(names: &[String]) -> String {
+    names.iter().map(|n| bibtex_escape(n)).collect::<Vec<_>>().join(" and ")
+}
+
+fn bibtex_escape(value: &str) -> String {
+    let mut out = String::new();
+    for c in value.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn ris_entry(item: &CitationItem) -> String {
+    let entry_type = match item.book_type {
+        BookType::Article => "JOUR",
+        BookType::Pdf => "GEN",
+        BookType::Ebook => "EBOOK",
+        _ => "BOOK",
+    };
+
+    let mut lines = vec![format!("TY  - {}", entry_type), format!("TI  - {}", single_line(&item.title))];
+    for author in &item.authors {
+        lines.push(format!("AU  - {}", single_line(author)));
+    }
+    for editor in &item.editors {
+        lines.push(format!("ED  - {}", single_line(editor)));
+    }
+    if let Some(year) = item.year {
+        lines.push(format!("PY  - {}", year));
+    }
+    if let Some(isbn) = &item.isbn {
+        lines.push(format!("SN  - {}", isbn));
+    }
+    if let Some(url) = &item.url {
+        lines.push(format!("UR  - {}", url));
+    }
+    lines.push("ER  - ".to_string());
+    lines.join("\n") + "\n"
+}
+
+fn csl_item(item: &CitationItem, key: &str) -> Value {
+    let item_type = match item.book_type {
+        BookType::Article => "article-journal",
+        BookType::Pdf => "document",
+        _ => "book",
+    };
+
+    let mut value = json!({ "id": key, "type": item_type, "title": item.title });
+    if !item.authors.is_empty() {
+        value["author"] = Value::Array(item.authors.iter().map(|n| csl_name(n)).collect());
+    }
+    if !item.editors.is_empty() {
+        value["editor"] = Value::Array(item.editors.iter().map(|n| csl_name(n)).collect());
+    }
+    if let Some(year) = item.year {
+        value["issued"] = json!({ "date-parts": [[year]] });
+    }
+    if let Some(isbn) = &item.isbn {
+        value["ISBN"] = json!(isbn);
+    }
+    if let Some(url) = &item.url {
+        value["URL"] = json!(url);
+    }
+    if let Some(pages) = item.pages {
+        value["number-of-pages"] = json!(pages);
+    }
+    value
+}
+
+fn csl_name(name: &str) -> Value {
+    match split_name(name) {
+        (family, given) if given.is_empty() => json!({ "literal": family }),
+        (family, given) => json!({ "family": family, "given": given }),
+    }
+}
+
+/// Splits a name into (family, given): "Le Guin, Ursula K." or "Ursula Le Guin" (last word as family)
+fn split_name(name: &str) -> (String, String) {
+    let name = name.trim();
+    if let Some((family, given)) = name.split_once(',') {
+        return (family.trim().to_string(), given.trim().to_string());
+    }
+    match name.rsplit_once(' ') {
+        Some((given, family)) => (family.trim().to_string(), given.trim().to_string()),
+        None => (name.to_string(), String::new()),
+    }
+}
+
+fn single_line(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// --- Importers ---
+
+/// Reference types imported as articles; everything else becomes a PDF document
+const BIBTEX_ARTICLE_TYPES: [&str; 4] = ["article", "inproceedings", "conference", "incollection"];
+const RIS_ARTICLE_TYPES: [&str; 8] = ["JOUR", "JFULL", "EJOUR", "MGZN", "NEWS", "CPAPER", "CONF", "CHAP"];
+
+fn parse_bibtex(content: &str) -> (Vec<CitationItem>, Vec<(usize, String)>) {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    let mut entry_number = 0;
+    let mut rest = content;
+
+    while let Some(at) = rest.find('@') {
+        rest = &rest[at + 1..];
+        let open = match rest.find(['{', '(']) {
+            Some(index) => index,
+            None => break,
+        };
+        let entry_type = rest[..open].trim().to_lowercase();
+        let body_length = match matching_close(&rest[open..]) {
+            Some(length) => length,
+            None => {
+                entry_number += 1;
+                errors.push((entry_number, "Unterminated BibTeX entry".to_string()));
+                break;
+            }
+        };
+        let body = &rest[open + 1..open + body_length];
+        rest = &rest[open + body_length..];
+
+        if matches!(entry_type.as_str(), "comment" | "string" | "preamble") || entry_type.is_empty() {
+            continue;
+        }
+        entry_number += 1;
+
+        let fields = bibtex_fields(body);
+        let field = |name: &str| fields.iter().find(|(n, _)| n == name).map(|(_, v)| v.clone());
+        let title = match field("title").filter(|t| !t.is_empty()) {
+            Some(title) => title,
+            None => {
+                errors.push((entry_number, "Entry has no title".to_string()));
+                continue;
+            }
+        };
+
+        items.push(CitationItem {
+            row_number: entry_number,
+            book_type: if BIBTEX_ARTICLE_TYPES.contains(&entry_type.as_str()) { BookType::Article } else { BookType::Pdf },
+            title,
+            authors: field("author").map(|a| split_bibtex_names(&a)).unwrap_or_default(),
+            editors: field("editor").map(|a| split_bibtex_names(&a)).unwrap_or_default(),
+            year: field("year").or_else(|| field("date")).and_then(|y| parse_year(&y)),
+            isbn: field("isbn").filter(|i| normalize_isbn(i).is_some()),
+            url: field("url").or_else(|| field("doi").map(|doi| format!("https://doi.org/{}", doi))),
+            pages: field("pages").and_then(|p| page_count(&p)).or_else(|| field("pagetotal").and_then(|p| p.parse().ok())),
+        });
+    }
+
+    (items, errors)
+}
+
+/// Length up to and including the bracket closing the one `text` starts with
+fn matching_close(text: &str) -> Option<usize> {
+    let (open, close) = if text.starts_with('(') { ('(', ')') } else { ('{', '}') };
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+/// Reads `name = value` pairs after the citation key; values are braced, quoted or bare
+fn bibtex_fields(body: &str) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let mut rest = match body.find(',') {
+        Some(index) => &body[index + 1..],
+        None => return fields,
+    };
+
+    while let Some(eq) = rest.find('=') {
+        let name = rest[..eq].trim().trim_start_matches(',').trim().to_lowercase();
+        let after = rest[eq + 1..].trim_start();
+        let (value, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            match matching_close(after) {
+                Some(end) => (&after[1..end], end + 1),
+                None => (braced, after.len()),
+            }
+        } else if let Some(quoted) = after.strip_prefix('"') {
+            match quoted.find('"') {
+                Some(end) => (&quoted[..end], end + 2),
+                None => (quoted, after.len()),
+            }
+        } else {
+            let end = after.find(',').unwrap_or(after.len());
+            (after[..end].trim(), end)
+        };
+
+        fields.push((name, clean_bibtex_value(value)));
+        rest = &after[consumed..];
+        rest = rest.trim_start().trim_start_matches(',');
+    }
+
+    fields
+}
+
+/// Removes braces and LaTeX escapes, and collapses whitespace
+fn clean_bibtex_value(value: &str) -> String {
+    let mut out = String::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' => {}
+            '\\' => {
+                if let Some(next) = chars.peek().copied() {
+                    if "&%$#_{}".contains(next) {
+                        out.push(next);
+                        chars.next();
+                    }
+                }
+            }
+            '~' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    single_line(&out)
+}
+
+fn split_bibtex_names(value: &str) -> Vec<String> {
+    value
+        .split(" and ")
+        .map(display_name)
+        .filter(|name| !name.is_empty() && name != "others")
+        .collect()
+}
+
+/// "Last, First" becomes "First Last", the way the library stores names
+fn display_name(name: &str) -> String {
+    match name.split_once(',') {
+        Some((family, given)) if !given.trim().is_empty() => format!("{} {}", given.trim(), family.trim()),
+        _ => name.trim().to_string(),
+    }
+}
+
+fn parse_ris(content: &str) -> (Vec<CitationItem>, Vec<(usize, String)>) {
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    let mut entry_number = 0;
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim_end();
+        if line.get(2..5) != Some("  -") {
+            continue;
+        }
+        let tag = line[..2].to_uppercase();
+        let value = line[5..].trim().to_string();
+
+        match tag.as_str() {
+            "TY" => {
+                fields.clear();
+                fields.push((tag, value));
+            }
+            "ER" => {
+                entry_number += 1;
+                match ris_item(entry_number, &fields) {
+                    Ok(item) => items.push(item),
+                    Err(error) => errors.push((entry_number, error)),
+                }
+                fields.clear();
+            }
+            _ => fields.push((tag, value)),
+        }
+    }
+
+    (items, errors)
+}
+
+fn ris_item(entry_number: usize, fields: &[(String, String)]) -> Result<CitationItem, String> {
+    let values = |tags: &[&str]| -> Vec<String> {
+        fields
+            .iter()
+            .filter(|(tag, value)| tags.contains(&tag.as_str()) && !value.is_empty())
+            .map(|(_, value)| value.clone())
+            .collect()
+    };
+    let first = |tags: &[&str]| values(tags).into_iter().next();
+
+    let entry_type = first(&["TY"]).unwrap_or_default().to_uppercase();
+    let title = first(&["TI", "T1", "CT", "BT"]).ok_or("Entry has no title".to_string())?;
+    let pages = match (first(&["SP"]), first(&["EP"])) {
+        (Some(start), Some(end)) => page_count(&format!("{}-{}", start, end)),
+        (Some(range), None) => page_count(&range),
+        _ => None,
+    };
+
+    Ok(CitationItem {
+        row_number: entry_number,
+        book_type: if RIS_ARTICLE_TYPES.contains(&entry_type.as_str()) { BookType::Article } else { BookType::Pdf },
+        title,
+        authors: values(&["AU", "A1"]).iter().map(|n| display_name(n)).collect(),
+        editors: values(&["ED", "A2"]).iter().map(|n| display_name(n)).collect(),
+        year: first(&["PY", "Y1", "DA"]).and_then(|y| parse_year(&y)),
+        isbn: first(&["SN"]).filter(|i| normalize_isbn(i).is_some()),
+        url: first(&["UR"]).or_else(|| first(&["DO"]).map(|doi| format!("https://doi.org/{}", doi))),
+        pages,
+    })
+}
+
+/// First four-digit number, e.g. "2019/05/01" or "May 2019" -> 2019
+fn parse_year(value: &str) -> Option<i32> {
+    value
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|part| part.len() == 4)
+        .and_then(|part| part.parse().ok())
+}
+
+/// Number of pages in a range like "12--34" or "12-34"
+fn page_count(range: &str) -> Option<i32> {
+    let numbers: Vec<i32> = range
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|part| part.parse().ok())
+        .collect();
+    match numbers.as_slice() {
+        [start, end, ..] if end >= start => Some(end - start + 1),
+        _ => None,
+    }
+}
@@ -0,0 +1,186 @@
+/// Minimal RFC 4180 CSV reader/writer used by library imports and exports
+pub struct CsvFormat;
+
+impl CsvFormat {
+    /// Parses CSV content into rows of fields
+    pub fn parse(content: &str) -> Result<Vec<Vec<String>>, String> {
+        Self::parse_delimited(content, ',')
+    }
+
+    /// Parses delimited content (comma or tab) into rows of fields.
+    /// Handles quoted fields, escaped quotes (""), embedded newlines, CRLF and a leading BOM.
+    pub fn parse_delimited(content: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        let mut row: Vec<String> = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut line = 1;
+        let mut chars = content.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                match c {
+                    '"' => {
+                        if chars.peek() == Some(&'"') {
+                            field.push('"');
+                            chars.next();
+                        } else {
+                            in_quotes = false;
+                        }
+                    }
+                    '\n' => {
+                        line += 1;
+                        field.push(c);
+                    }
+                    _ => field.push(c),
+                }
+                continue;
+            }
+
+            match c {
+                '"' if field.is_empty() => in_quotes = true,
+                c if c == delimiter => row.push(std::mem::take(&mut field)),
+                '\r' => {
+                    // CRLF: the '\n' ends the row
+                    if chars.peek() != Some(&'\n') {
+                        field.push(c);
+                    }
+                }
+                '\n' => {
+                    line += 1;
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                _ => field.push(c),
+            }
+        }
+
+        if in_quotes {
+            return Err(format!("Unterminated quoted field starting before line {}", line));
+        }
+
+        if !field.is_empty() || !row.is_empty() {
+            row.push(field);
+            rows.push(row);
+        }
+
+        // Drop blank lines
+        rows.retain(|r| !(r.len() == 1 && r[0].trim().is_empty()));
+
+        Ok(rows)
+    }
+
+    /// Parses content with a header row into records keyed by column name.
+    /// The delimiter is detected from the header line (tab-separated exports use '\t').
+    pub fn parse_with_headers(content: &str) -> Result<(Vec<String>, Vec<CsvRecord>), String> {
+        let header_line = content.lines().next().unwrap_or("");
+        let delimiter = if header_line.matches('\t').count() > header_line.matches(',').count() { '\t' } else { ',' };
+        let mut rows = Self::parse_delimited(content, delimiter)?.into_iter();
+        let headers: Vec<String> = match rows.next() {
+            Some(header) => header.into_iter().map(|h| h.trim().to_string()).collect(),
+            None => return Err("CSV file is empty".to_string()),
+        };
+
+        let records = rows
+            .enumerate()
+            .map(|(index, fields)| CsvRecord {
+                row_number: index + 2, // 1-based, after the header
+                headers: headers.clone(),
+                fields,
+            })
+            .collect();
+
+        Ok((headers, records))
+    }
+
+    /// Escapes a single field, quoting it when needed
+    pub fn escape(field: &str) -> String {
+        if field.contains(',') || field.contains('"') || field.contains('\n') || field.contains('\r') {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    /// Formats one CSV line (without trailing newline)
+    pub fn write_row<S: AsRef<str>>(fields: &[S]) -> String {
+        fields
+            .iter()
+            .map(|f| Self::escape(f.as_ref()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// One data row of a CSV file with access by column name
+#[derive(Debug, Clone)]
+pub struct CsvRecord {
+    pub row_number: usize,
+    headers: Vec<String>,
+    fields: Vec<String>,
+}
+
+impl CsvRecord {
+    /// Returns the trimmed value of a column, or None if missing/empty
+    pub fn get(&self, column: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case(column))
+            .and_then(|index| self.fields.get(index))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_fields_with_delimiters_quotes_and_newlines() {
+        let rows = CsvFormat::parse("title,notes\n\"Dune, Book 1\",\"He said \"\"fear\"\"\nand left\"\n").unwrap();
+        assert_eq!(rows, vec![
+            vec!["title".to_string(), "notes".to_string()],
+            vec!["Dune, Book 1".to_string(), "He said \"fear\"\nand left".to_string()],
+        ]);
+    }
+
+    #[test]
+    fn strips_bom_handles_crlf_and_drops_blank_lines() {
+        let rows = CsvFormat::parse("\u{feff}a,b\r\n\r\n1,2\r\n").unwrap();
+        assert_eq!(rows, vec![vec!["a", "b"], vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn keeps_a_last_row_without_newline_and_empty_fields() {
+        let rows = CsvFormat::parse("a,,c").unwrap();
+        assert_eq!(rows, vec![vec!["a", "", "c"]]);
+    }
+
+    #[test]
+    fn parses_tab_delimited_content() {
+        let rows = CsvFormat::parse_delimited("a\tb, c\n1\t2", '\t').unwrap();
+        assert_eq!(rows, vec![vec!["a", "b, c"], vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn rejects_an_unterminated_quoted_field() {
+        assert!(CsvFormat::parse("a,\"b\nc").is_err());
+    }
+
+    #[test]
+    fn detects_tabs_from_the_header() {
+        let (headers, records) = CsvFormat::parse_with_headers("Title\tAuthors\nDune\tFrank Herbert").unwrap();
+        assert_eq!(headers, vec!["Title", "Authors"]);
+        assert_eq!(records[0].get("Authors"), Some("Frank Herbert"));
+    }
+
+    #[test]
+    fn escaped_fields_read_back_unchanged() {
+        let fields = ["plain", "with, comma", "with \"quote\"", "two\nlines"];
+        let rows = CsvFormat::parse(&CsvFormat::write_row(&fields)).unwrap();
+        assert_eq!(rows, vec![fields.to_vec()]);
+    }
+}
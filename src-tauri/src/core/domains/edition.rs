@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::book::BookType;
+
+/// Additional format of a book (the "work"), e.g. the audiobook of a paperback
+/// The book row itself is the primary edition; editions hold the other formats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Edition {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub format: BookType,
+    pub label: Option<String>, // e.g., "Folio paperback", "Audible, narrated by ..."
+    pub isbn: Option<String>,
+    pub total_pages: Option<i32>,
+    pub total_minutes: Option<i32>, // For audiobooks
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Edition {
+    /// Creates a new Edition with validation
+    pub fn new(
+        book_id: i64,
+        format: BookType,
+        total_pages: Option<i32>,
+        total_minutes: Option<i32>,
+    ) -> Result<Self, String> {
+        Self::validate_length(&format, total_pages, total_minutes)?;
+
+        let now = chrono::Utc::now();
+        Ok(Edition {
+            id: None,
+            book_id,
+            format,
+            label: None,
+            isbn: None,
+            total_pages,
+            total_minutes,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Updates format and length together so they stay consistent
+    pub fn update_format(
+        &mut self,
+        format: BookType,
+        total_pages: Option<i32>,
+        total_minutes: Option<i32>,
+    ) -> Result<(), String> {
+        Self::validate_length(&format, total_pages, total_minutes)?;
+
+        self.format = format;
+        self.total_pages = total_pages;
+        self.total_minutes = total_minutes;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    /// Length of the edition in its own unit (pages, or minutes for audiobooks)
+    pub fn length(&self) -> i32 {
+        match self.format {
+            BookType::Audiobook => self.total_minutes.unwrap_or(0),
+            _ => self.total_pages.unwrap_or(0),
+        }
+    }
+
+    /// Converts a format-independent position (0.0 - 1.0) into this edition's unit
+    pub fn position_at_fraction(&self, fraction: f64) -> i32 {
+        (fraction.clamp(0.0, 1.0) * self.length() as f64).round() as i32
+    }
+
+    fn validate_length(
+        format: &BookType,
+        total_pages: Option<i32>,
+        total_minutes: Option<i32>,
+    ) -> Result<(), String> {
+        // Same rule as Book::new: audiobooks are measured in minutes, everything else in pages
+        match format {
+            BookType::Audiobook => {
+                if total_minutes.unwrap_or(0) <= 0 {
+                    return Err("Audiobook edition must have total_minutes > 0".to_string());
+                }
+            }
+            _ => {
+                if total_pages.unwrap_or(0) <= 0 {
+                    return Err("Edition must have total_pages > 0".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
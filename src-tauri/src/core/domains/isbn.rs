@@ -0,0 +1,71 @@
+/// Validates an ISBN-10 or ISBN-13 (hyphens and spaces allowed) and returns its ISBN-13 form
+pub fn parse_isbn(value: &str) -> Result<String, String> {
+    let compact: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+
+    match compact.len() {
+        10 => {
+            let valid_chars = compact[..9].chars().all(|c| c.is_ascii_digit())
+                && compact[9..].chars().all(|c| c.is_ascii_digit() || c == 'X');
+            if !valid_chars {
+                return Err(format!("Invalid ISBN-10: {}", value.trim()));
+            }
+            if isbn10_check_digit(&compact[..9]) != compact.chars().last().unwrap_or(' ') {
+                return Err(format!("Invalid ISBN-10 check digit: {}", value.trim()));
+            }
+            Ok(isbn10_to_13(&compact))
+        }
+        13 => {
+            if !compact.chars().all(|c| c.is_ascii_digit()) || !(compact.starts_with("978") || compact.starts_with("979")) {
+                return Err(format!("Invalid ISBN-13: {}", value.trim()));
+            }
+            if isbn13_check_digit(&compact[..12]) != compact.chars().last().unwrap_or(' ') {
+                return Err(format!("Invalid ISBN-13 check digit: {}", value.trim()));
+            }
+            Ok(compact)
+        }
+        _ => Err(format!("ISBN must have 10 or 13 digits: {}", value.trim())),
+    }
+}
+
+/// Converts a compact ISBN-10 to ISBN-13 (978 prefix, recomputed check digit)
+pub fn isbn10_to_13(isbn10: &str) -> String {
+    let core = format!("978{}", &isbn10[..9.min(isbn10.len())]);
+    let check = isbn13_check_digit(&core);
+    format!("{}{}", core, check)
+}
+
+/// Converts a compact ISBN-13 to ISBN-10; only 978-prefixed numbers have one
+pub fn isbn13_to_10(isbn13: &str) -> Option<String> {
+    if isbn13.len() != 13 || !isbn13.starts_with("978") {
+        return None;
+    }
+    let core = &isbn13[3..12];
+    Some(format!("{}{}", core, isbn10_check_digit(core)))
+}
+
+/// Check digit for the first 9 digits of an ISBN-10 ('X' stands for 10)
+fn isbn10_check_digit(core: &str) -> char {
+    let sum: u32 = core
+        .chars()
+        .enumerate()
+        .map(|(i, c)| c.to_digit(10).unwrap_or(0) * (10 - i as u32))
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        digit => char::from_digit(digit, 10).unwrap_or('0'),
+    }
+}
+
+/// Check digit for the first 12 digits of an ISBN-13
+fn isbn13_check_digit(core: &str) -> char {
+    let sum: u32 = core
+        .chars()
+        .enumerate()
+        .map(|(i, c)| c.to_digit(10).unwrap_or(0) * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10).unwrap_or('0')
+}
@@ -0,0 +1,359 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::core::domains::book::Book;
+use crate::core::domains::library_import::normalize_title;
+use crate::core::domains::timezone::UserTimezone;
+
+/// Kindle locations per printed page, used when a clipping only has a location
+const LOCATIONS_PER_PAGE: i32 = 15;
+
+const CLIPPING_SEPARATOR: &str = "==========";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClippingKind {
+    Highlight,
+    Note,
+}
+
+/// One entry of a Kindle "My Clippings.txt" file
+#[derive(Debug, Clone)]
+pub struct KindleClipping {
+    pub entry_number: usize, // Position of the entry in the file, from 1
+    pub title: String,
+    pub author: Option<String>,
+    pub kind: ClippingKind,
+    pub page: Option<i32>,
+    pub location: Option<i32>, // Start of the location range
+    pub added_at: Option<DateTime<Utc>>,
+    pub content: String,
+}
+
+impl KindleClipping {
+    /// Page of the clipping, estimated from the location when the device gave none
+    pub fn estimated_page(&self) -> Option<i32> {
+        self.page.or_else(|| {
+            self.location
+                .map(|location| (location + LOCATIONS_PER_PAGE - 1) / LOCATIONS_PER_PAGE)
+                .filter(|page| *page > 0)
+        })
+    }
+}
+
+/// Clippings of one book, in file order
+#[derive(Debug, Clone)]
+pub struct ClippingGroup {
+    pub title: String,
+    pub author: Option<String>,
+    pub clippings: Vec<KindleClipping>,
+}
+
+/// Parses the content of "My Clippings.txt"; "Added on" times are read in the given time zone.
+/// Bookmarks and empty entries are skipped, as are exact repeats (Kindle appends a new
+/// entry every time a highlight is adjusted).
+/// Returns the clippings plus entries that could not be read (entry number, message).
+pub fn parse_clippings(content: &str, timezone: &UserTimezone) -> (Vec<KindleClipping>, Vec<(usize, String)>) {
+    let mut clippings: Vec<KindleClipping> = Vec::new();
+    let mut errors: Vec<(usize, String)> = Vec::new();
+
+    for (index, entry) in content.split(CLIPPING_SEPARATOR).enumerate() {
+        let entry_number = index + 1;
+        let mut lines = entry.lines().map(|l| l.trim_start_matches('\u{feff}').trim());
+        let header = match lines.by_ref().find(|l| !l.is_empty()) {
+            Some(line) => line,
+            None => continue,
+        };
+        let meta = match lines.next() {
+            Some(line) if line.starts_with('-') => line,
+            _ => {
+                errors.push((entry_number, format!("Entry \"{}\" has no clipping details", header)));
+                continue;
+            }
+        };
+
+        // Only the English device layout is understood
+        let kind = if meta.contains("Highlight") || meta.contains("highlight") {
+            ClippingKind::Highlight
+        } else if meta.contains("Note") || meta.contains("note") {
+            ClippingKind::Note
+        } else if meta.contains("Bookmark") || meta.contains("bookmark") {
+            continue;
+        } else {
+            errors.push((entry_number, format!("Unrecognised clipping \"{}\"; only clippings from a Kindle set to English can be read", meta)));
+            continue;
+        };
+
+        let text = lines.collect::<Vec<_>>().join("\n").trim().to_string();
+        if text.is_empty() {
+            continue;
+        }
+
+        let (title, author) = split_title_author(header);
+        let clipping = KindleClipping {
+            entry_number,
+            title,
+            author,
+            kind,
+            page: number_after(meta, "page").or_else(|| number_after(meta, "Page")),
+            location: number_after(meta, "Location")
+                .or_else(|| number_after(meta, "location"))
+                .or_else(|| number_after(meta, "Loc.")),
+            added_at: meta.split("Added on").nth(1).and_then(|d| parse_added_on(d.trim(), timezone)),
+            content: text,
+        };
+
+        let repeated = clippings.iter().any(|c| {
+            c.title == clipping.title && c.kind == clipping.kind && c.content == clipping.content
+        });
+        if !repeated {
+            clippings.push(clipping);
+        }
+    }
+
+    (clippings, errors)
+}
+
+/// Groups clippings by book title and author, keeping first-seen order
+pub fn group_by_book(clippings: Vec<KindleClipping>) -> Vec<ClippingGroup> {
+    let mut groups: Vec<ClippingGroup> = Vec::new();
+
+    for clipping in clippings {
+        match groups
+            .iter_mut()
+            .find(|g| g.title == clipping.title && g.author == clipping.author)
+        {
+            Some(group) => group.clippings.push(clipping),
+            None => groups.push(ClippingGroup {
+                title: clipping.title.clone(),
+                author: clipping.author.clone(),
+                clippings: vec![clipping],
+            }),
+        }
+    }
+
+    groups
+}
+
+/// Shortest main title (in characters) that may match a longer title by prefix
+const MIN_PREFIX_MATCH_LEN: usize = 8;
+
+/// Finds the library book a Kindle title refers to.
+/// Kindle titles often carry subtitles or edition notes, so main titles match when they
+/// are equal or one starts with all the words of the other (if that one is long enough).
+/// When the clipping has an author, books by someone else are never matched.
+pub fn find_matching_book<'b>(title: &str, author: Option<&str>, books: &'b [Book]) -> Option<&'b Book> {
+    let wanted = main_title(title);
+    if wanted.is_empty() {
+        return None;
+    }
+    let author = author.map(normalize_title).unwrap_or_default();
+
+    books
+        .iter()
+        .filter_map(|book| {
+            let candidate = main_title(&book.title);
+            let exact = candidate == wanted;
+            if !exact && !is_word_prefix(&candidate, &wanted) && !is_word_prefix(&wanted, &candidate) {
+                return None;
+            }
+
+            let book_author = book.author.as_deref().map(normalize_title).unwrap_or_default();
+            let same_author = !author.is_empty() && !book_author.is_empty() && author_matches(&book_author, &author);
+            if !author.is_empty() && !book_author.is_empty() && !same_author {
+                return None;
+            }
+
+            // Best: same author, then exact title
+            Some(((same_author, exact), book))
+        })
+        .fold(None, |best: Option<((bool, bool), &Book)>, (rank, book)| match best {
+            Some((best_rank, _)) if best_rank >= rank => best,
+            _ => Some((rank, book)),
+        })
+        .map(|(_, book)| book)
+}
+
+/// Whether `longer` starts with every word of `shorter`, which must be long enough
+/// that short titles ("It", "Dune") don't match everything that begins with them
+fn is_word_prefix(shorter: &str, longer: &str) -> bool {
+    shorter.len() >= MIN_PREFIX_MATCH_LEN
+        && longer.len() > shorter.len()
+        && longer.starts_with(shorter)
+        && longer[shorter.len()..].starts_with(' ')
+}
+
+/// Title without subtitle or bracketed edition notes, normalized
+fn main_title(title: &str) -> String {
+    let main = title
+        .split([':', '(', '['])
+        .next()
+        .unwrap_or(title);
+    normalize_title(main)
+}
+
+/// Kindle writes authors as "First Last" or "Last, First"; compare by shared name parts.
+/// Either side may list co-authors the other leaves out.
+fn author_matches(book_author: &str, kindle_author: &str) -> bool {
+    let contains_all = |names: &str, parts: &str| {
+        let parts: Vec<&str> = parts.split_whitespace().collect();
+        !parts.is_empty() && parts.iter().all(|part| names.split_whitespace().any(|p| p == *part))
+    };
+    contains_all(book_author, kindle_author) || contains_all(kindle_author, book_author)
+}
+
+/// Splits "Title (Author)" on the last parenthesised group
+fn split_title_author(header: &str) -> (String, Option<String>) {
+    if header.ends_with(')') {
+        if let Some(open) = header.rfind('(') {
+            let title = header[..open].trim();
+            let author = header[open + 1..header.len() - 1].trim();
+            if !title.is_empty() && !author.is_empty() {
+                return (title.to_string(), Some(author.replace(';', " & ")));
+            }
+        }
+    }
+    (header.to_string(), None)
+}
+
+/// Reads the first number after a keyword, e.g. "Location 170-172" -> 170
+fn number_after(meta: &str, keyword: &str) -> Option<i32> {
+    let rest = &meta[meta.find(keyword)? + keyword.len()..];
+    let digits: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse::<i32>().ok()
+}
+
+/// Parses the "Added on" timestamp (device local time) in its US and UK layouts
+fn parse_added_on(value: &str, timezone: &UserTimezone) -> Option<DateTime<Utc>> {
+    const FORMATS: [&str; 4] = [
+        "%A, %B %d, %Y %I:%M:%S %p", // Sunday, March 1, 2020 10:14:33 PM
+        "%A, %d %B %Y %H:%M:%S",     // Sunday, 1 March 2020 22:14:33
+        "%A, %B %d, %Y, %I:%M %p",
+        "%A, %d %B %Y, %H:%M",
+    ];
+
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .and_then(|naive| timezone.resolve(naive.date(), naive.time()).ok())
+        .map(|local| local.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domains::book::BookType;
+
+    const CLIPPINGS: &str = "\u{feff}Dune: Deluxe Edition (Herbert, Frank)
+- Your Highlight on page 12 | Location 170-172 | Added on Sunday, March 1, 2020 10:14:33 PM
+
+I must not fear.
+==========
+Dune: Deluxe Edition (Herbert, Frank)
+- Your Highlight on page 12 | Location 170-172 | Added on Sunday, March 1, 2020 10:15:00 PM
+
+I must not fear.
+==========
+Good Omens (Gaiman, Neil;Pratchett, Terry)
+- Your Note on Location 301 | Added on Monday, 2 March 2020 08:05:00
+
+Crowley's car
+==========
+Good Omens (Gaiman, Neil;Pratchett, Terry)
+- Your Bookmark on Location 400 | Added on Monday, 2 March 2020 08:06:00
+
+
+==========
+";
+
+    fn berlin() -> UserTimezone {
+        UserTimezone::parse("Europe/Berlin").unwrap()
+    }
+
+    fn book(title: &str, author: Option<&str>) -> Book {
+        let mut book = Book::new(title.to_string(), BookType::Ebook, Some(300), None).unwrap();
+        book.author = author.map(|a| a.to_string());
+        book
+    }
+
+    #[test]
+    fn parses_highlights_and_notes_and_skips_bookmarks_and_repeats() {
+        let (clippings, errors) = parse_clippings(CLIPPINGS, &berlin());
+        assert!(errors.is_empty());
+        assert_eq!(clippings.len(), 2);
+
+        let highlight = &clippings[0];
+        assert_eq!(highlight.entry_number, 1);
+        assert_eq!(highlight.title, "Dune: Deluxe Edition");
+        assert_eq!(highlight.author.as_deref(), Some("Herbert, Frank"));
+        assert_eq!(highlight.kind, ClippingKind::Highlight);
+        assert_eq!((highlight.page, highlight.location), (Some(12), Some(170)));
+        assert_eq!(highlight.content, "I must not fear.");
+
+        let note = &clippings[1];
+        assert_eq!(note.entry_number, 3);
+        assert_eq!(note.kind, ClippingKind::Note);
+        assert_eq!(note.author.as_deref(), Some("Gaiman, Neil & Pratchett, Terry"));
+        assert_eq!(note.estimated_page(), Some(21));
+    }
+
+    #[test]
+    fn reads_added_on_times_in_the_users_zone() {
+        let (clippings, _) = parse_clippings(CLIPPINGS, &berlin());
+        assert_eq!(clippings[0].added_at.unwrap().to_rfc3339(), "2020-03-01T21:14:33+00:00");
+        assert_eq!(clippings[1].added_at.unwrap().to_rfc3339(), "2020-03-02T07:05:00+00:00");
+    }
+
+    #[test]
+    fn reports_entries_it_cannot_read() {
+        let content = "Dune (Frank Herbert)
+- Ihre Markierung auf Seite 12 | bei Position 170-172 | Hinzugefügt am Sonntag, 1. März 2020 22:14:33
+
+Ich darf keine Angst haben.
+==========
+Just a stray line
+==========
+";
+        let (clippings, errors) = parse_clippings(content, &berlin());
+        assert!(clippings.is_empty());
+        assert_eq!(errors.iter().map(|(entry, _)| *entry).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn groups_clippings_by_title_and_author() {
+        let (clippings, _) = parse_clippings(CLIPPINGS, &berlin());
+        let groups = group_by_book(clippings);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].title, "Good Omens");
+    }
+
+    #[test]
+    fn matches_titles_with_subtitles_by_whole_words() {
+        let books = vec![book("Dune", Some("Frank Herbert")), book("The Left Hand of Darkness", None)];
+        let found = find_matching_book("Dune: Deluxe Edition", Some("Herbert, Frank"), &books).unwrap();
+        assert_eq!(found.title, "Dune");
+        let found = find_matching_book("The Left Hand of Darkness - Ace Science Fiction", None, &books).unwrap();
+        assert_eq!(found.title, "The Left Hand of Darkness");
+    }
+
+    #[test]
+    fn does_not_match_short_titles_by_prefix() {
+        let books = vec![book("Dune Messiah", None), book("The Witches", None)];
+        assert!(find_matching_book("Dune", None, &books).is_none());
+        assert!(find_matching_book("It", None, &books).is_none());
+    }
+
+    #[test]
+    fn does_not_match_a_book_by_another_author() {
+        let books = vec![book("Dune", Some("Brian Herbert"))];
+        assert!(find_matching_book("Dune", Some("Frank Herbert"), &books).is_none());
+    }
+
+    #[test]
+    fn prefers_the_book_by_the_same_author() {
+        let books = vec![book("Emma", None), book("Emma", Some("Jane Austen"))];
+        let found = find_matching_book("Emma", Some("Austen, Jane"), &books).unwrap();
+        assert_eq!(found.author.as_deref(), Some("Jane Austen"));
+    }
+}
@@ -0,0 +1,402 @@
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+
+/// Value of the Lua table literal KOReader writes to its `metadata.*.lua` sidecar files
+#[derive(Debug, Clone, PartialEq)]
+pub enum LuaValue {
+    Nil,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Table(Vec<(LuaValue, LuaValue)>),
+}
+
+impl LuaValue {
+    /// Looks up a string key in a table
+    pub fn get(&self, key: &str) -> Option<&LuaValue> {
+        match self {
+            LuaValue::Table(entries) => entries
+                .iter()
+                .find(|(k, _)| matches!(k, LuaValue::Str(s) if s == key))
+                .map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            LuaValue::Str(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            LuaValue::Number(n) => Some(*n as i32),
+            _ => None,
+        }
+    }
+
+    /// Values of a table in key order (numeric keys first, as Lua arrays are 1-based)
+    pub fn values(&self) -> Vec<&LuaValue> {
+        match self {
+            LuaValue::Table(entries) => {
+                let mut sorted: Vec<&(LuaValue, LuaValue)> = entries.iter().collect();
+                sorted.sort_by(|(a, _), (b, _)| match (a, b) {
+                    (LuaValue::Number(x), LuaValue::Number(y)) => x.partial_cmp(y).unwrap_or(std::cmp::Ordering::Equal),
+                    (LuaValue::Number(_), _) => std::cmp::Ordering::Less,
+                    (_, LuaValue::Number(_)) => std::cmp::Ordering::Greater,
+                    _ => std::cmp::Ordering::Equal,
+                });
+                sorted.into_iter().map(|(_, v)| v).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Parses a sidecar file: an optional comment header followed by `return { ... }`
+pub fn parse_lua_table(content: &str) -> Result<LuaValue, String> {
+    let mut parser = LuaParser { bytes: content.as_bytes(), pos: 0 };
+    parser.skip_whitespace();
+    if parser.bytes[parser.pos..].starts_with(b"return") {
+        parser.pos += "return".len();
+    }
+    parser.parse_value()
+}
+
+struct LuaParser<'s> {
+    bytes: &'s [u8],
+    pos: usize,
+}
+
+impl<'s> LuaParser<'s> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("Invalid KOReader metadata at byte {}: {}", self.pos, message)
+    }
+
+    /// Skips whitespace and `--` line comments
+    fn skip_whitespace(&mut self) {
+        loop {
+            while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+                self.pos += 1;
+            }
+            if self.bytes[self.pos..].starts_with(b"--") {
+                while !matches!(self.peek(), None | Some(b'\n')) {
+                    self.pos += 1;
+                }
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<LuaValue, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{') => self.parse_table(),
+            Some(b'"') | Some(b'\'') => self.parse_string().map(LuaValue::Str),
+            Some(b) if b == b'-' || b.is_ascii_digit() => self.parse_number(),
+            Some(_) => match self.parse_identifier().as_str() {
+                "true" => Ok(LuaValue::Bool(true)),
+                "false" => Ok(LuaValue::Bool(false)),
+                "nil" => Ok(LuaValue::Nil),
+                other => Err(self.error(&format!("unexpected '{}'", other))),
+            },
+            None => Err(self.error("unexpected end of file")),
+        }
+    }
+
+    fn parse_table(&mut self) -> Result<LuaValue, String> {
+        self.pos += 1; // '{'
+        let mut entries = Vec::new();
+        let mut next_index = 1.0;
+
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(LuaValue::Table(entries));
+                }
+                Some(b',') | Some(b';') => {
+                    self.pos += 1;
+                    continue;
+                }
+                None => return Err(self.error("unterminated table")),
+                _ => {}
+            }
+
+            // Entry forms: [key] = value, name = value, or a positional value
+            let key = if self.peek() == Some(b'[') {
+                self.pos += 1;
+                let key = self.parse_value()?;
+                self.skip_whitespace();
+                if self.peek() != Some(b']') {
+                    return Err(self.error("expected ']'"));
+                }
+                self.pos += 1;
+                self.expect_equals()?;
+                key
+            } else if matches!(self.peek(), Some(b) if b.is_ascii_alphabetic() || b == b'_')
+                && !self.at_keyword_value()
+            {
+                let name = self.parse_identifier();
+                self.expect_equals()?;
+                LuaValue::Str(name)
+            } else {
+                let key = LuaValue::Number(next_index);
+                next_index += 1.0;
+                key
+            };
+
+            let value = self.parse_value()?;
+            entries.push((key, value));
+        }
+    }
+
+    /// true/false/nil used as positional values rather than as field names
+    fn at_keyword_value(&self) -> bool {
+        let rest = &self.bytes[self.pos..];
+        [&b"true"[..], b"false", b"nil"].iter().any(|kw| {
+            rest.starts_with(kw)
+                && !matches!(rest.get(kw.len()), Some(b) if b.is_ascii_alphanumeric() || *b == b'_')
+        })
+    }
+
+    fn expect_equals(&mut self) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(b'=') {
+            return Err(self.error("expected '='"));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b) if b.is_ascii_alphanumeric() || b == b'_') {
+            self.pos += 1;
+        }
+        String::from_utf8_lossy(&self.bytes[start..self.pos]).to_string()
+    }
+
+    fn parse_number(&mut self) -> Result<LuaValue, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b) if b.is_ascii_alphanumeric() || b == b'.' || b == b'-' || b == b'+') {
+            self.pos += 1;
+        }
+        let text = String::from_utf8_lossy(&self.bytes[start..self.pos]).to_string();
+        text.parse::<f64>()
+            .map(LuaValue::Number)
+            .map_err(|_| self.error(&format!("invalid number '{}'", text)))
+    }
+
+    /// Quoted string as produced by Lua's %q: escapes include \n, \", \\, \ddd
+    /// and a backslash followed by a literal newline
+    fn parse_string(&mut self) -> Result<String, String> {
+        let quote = self.bytes[self.pos];
+        self.pos += 1;
+        let mut out: Vec<u8> = Vec::new();
+
+        loop {
+            let b = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+            self.pos += 1;
+
+            if b == quote {
+                return Ok(String::from_utf8_lossy(&out).to_string());
+            }
+            if b != b'\\' {
+                out.push(b);
+                continue;
+            }
+
+            let escaped = self.peek().ok_or_else(|| self.error("unterminated escape"))?;
+            self.pos += 1;
+            match escaped {
+                b'n' | b'\n' => out.push(b'\n'),
+                b'r' => out.push(b'\r'),
+                b't' => out.push(b'\t'),
+                b'0'..=b'9' => {
+                    let mut code = (escaped - b'0') as u32;
+                    for _ in 0..2 {
+                        match self.peek() {
+                            Some(d) if d.is_ascii_digit() => {
+                                code = code * 10 + (d - b'0') as u32;
+                                self.pos += 1;
+                            }
+                            _ => break,
+                        }
+                    }
+                    out.push(code.min(255) as u8);
+                }
+                other => out.push(other),
+            }
+        }
+    }
+}
+
+/// One highlight (with its optional note) from a KOReader sidecar
+#[derive(Debug, Clone)]
+pub struct KoreaderAnnotation {
+    pub text: String,
+    pub note: Option<String>,
+    pub page: Option<i32>,
+    pub chapter: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl KoreaderAnnotation {
+    /// Note content: the highlighted text, followed by the reader's note when there is one
+    pub fn note_content(&self) -> String {
+        match &self.note {
+            Some(note) if !self.text.is_empty() => format!("{}\n\n{}", self.text, note),
+            Some(note) => note.clone(),
+            None => self.text.clone(),
+        }
+    }
+}
+
+/// Book information and annotations read from a KOReader sidecar
+#[derive(Debug, Clone)]
+pub struct KoreaderDocument {
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    pub total_pages: Option<i32>,
+    pub annotations: Vec<KoreaderAnnotation>,
+}
+
+impl KoreaderDocument {
+    /// Reads a sidecar; the title falls back to the document file name when doc_props has none
+    pub fn parse(content: &str, file_name: &str) -> Result<Self, String> {
+        let root = parse_lua_table(content)?;
+
+        let props = root.get("doc_props");
+        let title = props
+            .and_then(|p| p.get("title"))
+            .and_then(|t| t.as_str())
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .or_else(|| root.get("doc_path").and_then(|p| p.as_str()).map(file_stem))
+            .or_else(|| sidecar_folder_title(file_name));
+
+        // KOReader separates multiple authors with newlines
+        let authors = props
+            .and_then(|p| p.get("authors"))
+            .and_then(|a| a.as_str())
+            .map(|a| {
+                a.split('\n')
+                    .map(|name| name.trim().to_string())
+                    .filter(|name| !name.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let total_pages = root.get("doc_pages")
+            .or_else(|| root.get("stats").and_then(|s| s.get("pages")))
+            .and_then(|p| p.as_i32())
+            .filter(|p| *p > 0);
+
+        Ok(KoreaderDocument {
+            title,
+            authors,
+            total_pages,
+            annotations: read_annotations(&root),
+        })
+    }
+}
+
+/// Reads highlights from the current "annotations" list, or from the older
+/// "highlight" table (keyed by page) with notes kept in "bookmarks"
+fn read_annotations(root: &LuaValue) -> Vec<KoreaderAnnotation> {
+    let text_of = |entry: &LuaValue, key: &str| {
+        entry.get(key)
+            .and_then(|v| v.as_str())
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let created_of = |entry: &LuaValue| {
+        entry.get("datetime")
+            .and_then(|d| d.as_str())
+            .and_then(parse_koreader_datetime)
+    };
+
+    if let Some(annotations) = root.get("annotations") {
+        return annotations
+            .values()
+            .into_iter()
+            .filter_map(|entry| {
+                let text = text_of(entry, "text").unwrap_or_default();
+                let note = text_of(entry, "note");
+                if text.is_empty() && note.is_none() {
+                    return None; // Plain bookmark
+                }
+                Some(KoreaderAnnotation {
+                    text,
+                    note,
+                    page: entry.get("pageno").or_else(|| entry.get("page")).and_then(|p| p.as_i32()),
+                    chapter: text_of(entry, "chapter"),
+                    created_at: created_of(entry),
+                })
+            })
+            .collect();
+    }
+
+    let bookmarks: Vec<&LuaValue> = root.get("bookmarks").map(|b| b.values()).unwrap_or_default();
+    let mut annotations = Vec::new();
+
+    if let Some(LuaValue::Table(pages)) = root.get("highlight") {
+        for (page_key, highlights) in pages {
+            for entry in highlights.values() {
+                let text = match text_of(entry, "text") {
+                    Some(text) => text,
+                    None => continue,
+                };
+                let datetime = entry.get("datetime").and_then(|d| d.as_str());
+                let note = bookmarks
+                    .iter()
+                    .find(|b| datetime.is_some() && b.get("datetime").and_then(|d| d.as_str()) == datetime)
+                    .and_then(|b| text_of(b, "text"))
+                    .filter(|note| *note != text && !note.starts_with("Page "));
+
+                annotations.push(KoreaderAnnotation {
+                    text,
+                    note,
+                    page: page_key.as_i32(),
+                    chapter: text_of(entry, "chapter"),
+                    created_at: created_of(entry),
+                });
+            }
+        }
+    }
+
+    annotations.sort_by_key(|a| (a.page, a.created_at));
+    annotations
+}
+
+/// Sidecars live in "<book file name>.sdr/", so the folder names the book
+fn sidecar_folder_title(path: &str) -> Option<String> {
+    path.rsplit(['/', '\\'])
+        .find_map(|part| part.strip_suffix(".sdr"))
+        .map(file_stem)
+        .filter(|title| !title.is_empty())
+}
+
+fn file_stem(path: &str) -> String {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => name[..dot].to_string(),
+        _ => name.to_string(),
+    }
+}
+
+/// KOReader stores "YYYY-MM-DD HH:MM:SS" in device local time
+fn parse_koreader_datetime(value: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .map(|local| local.with_timezone(&Utc))
+}
@@ -0,0 +1,117 @@
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::csv_format::CsvFormat;
+use crate::core::domains::journal_entry::JournalEntry;
+use crate::core::domains::note::Note;
+use crate::core::domains::reading::{Reading, ReadingStatus};
+use crate::core::domains::session::ReadingSession;
+
+/// File formats the library can be exported to
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExportFormat {
+    StoryGraphCsv,
+}
+
+/// A book with the related data every export format draws from
+#[derive(Debug, Clone)]
+pub struct ExportEntry {
+    pub book: Book,
+    pub readings: Vec<Reading>, // Ordered by reading number
+    pub tags: Vec<String>,
+    pub notes: Vec<Note>,
+    pub journal_entries: Vec<JournalEntry>,
+    pub sessions: Vec<ReadingSession>, // Ordered by date
+}
+
+/// One file of a multi-file export, with a path relative to the export folder
+#[derive(Debug, Clone)]
+pub struct ExportedFile {
+    pub path: String,
+    pub content: String,
+}
+
+impl ExportEntry {
+    /// Latest rated reading's rating and review
+    pub fn latest_review(&self) -> (Option<f64>, Option<String>) {
+        self.readings
+            .iter()
+            .rev()
+            .find(|r| r.rating.is_some() || r.review.is_some())
+            .map(|r| (r.rating, r.review.clone()))
+            .unwrap_or((None, None))
+    }
+
+    fn completed_readings(&self) -> Vec<&Reading> {
+        self.readings
+            .iter()
+            .filter(|r| r.status == ReadingStatus::Completed)
+            .collect()
+    }
+}
+
+const STORYGRAPH_HEADERS: [&str; 23] = [
+    "Title", "Authors", "Contributors", "ISBN/UID", "Format", "Read Status",
+    "Date Added", "Last Date Read", "Dates Read", "Read Count", "Moods", "Pace",
+    "Character- or Plot-Driven?", "Strong Character Development?", "Loveable Characters?",
+    "Diverse Characters?", "Flawed Characters?", "Star Rating", "Review",
+    "Content Warnings", "Content Warning Description", "Tags", "Owned?",
+];
+
+/// Writes the library in the column layout of a StoryGraph export, which StoryGraph can import
+pub fn storygraph_csv(entries: &[ExportEntry]) -> String {
+    let mut lines = vec![CsvFormat::write_row(&STORYGRAPH_HEADERS)];
+
+    for entry in entries {
+        let book = &entry.book;
+        let completed = entry.completed_readings();
+        let (rating, review) = entry.latest_review();
+        let date = |d: chrono::DateTime<chrono::Utc>| d.format("%Y/%m/%d").to_string();
+
+        let read_status = match book.status {
+            BookStatus::Completed => "read",
+            BookStatus::Reading | BookStatus::Rereading => "currently-reading",
+            BookStatus::Paused => "paused",
+            BookStatus::Abandoned => "did-not-finish",
+            BookStatus::NotStarted => "to-read",
+        };
+        let format = match book.book_type {
+            BookType::Audiobook => "audio",
+            BookType::Ebook | BookType::Pdf | BookType::Article => "digital",
+            BookType::PhysicalBook | BookType::Comic => "paperback",
+        };
+
+        let dates_read = completed
+            .iter()
+            .filter_map(|r| match (r.started_at, r.completed_at) {
+                (Some(start), Some(end)) => Some(format!("{}-{}", date(start), date(end))),
+                (None, Some(end)) => Some(date(end)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let last_date_read = completed
+            .iter()
+            .filter_map(|r| r.completed_at)
+            .max()
+            .map(date)
+            .unwrap_or_default();
+
+        let mut row = vec![String::new(); STORYGRAPH_HEADERS.len()];
+        row[0] = book.title.clone();
+        row[1] = book.author.clone().unwrap_or_default();
+        row[3] = book.isbn.clone().unwrap_or_default();
+        row[4] = format.to_string();
+        row[5] = read_status.to_string();
+        row[6] = date(book.added_at);
+        row[7] = last_date_read;
+        row[8] = dates_read;
+        row[9] = completed.len().to_string();
+        row[17] = rating.map(|r| r.to_string()).unwrap_or_default();
+        row[18] = review.unwrap_or_default();
+        row[21] = entry.tags.join(", ");
+        row[22] = if book.is_wishlist { "No" } else { "Yes" }.to_string();
+
+        lines.push(CsvFormat::write_row(&row));
+    }
+
+    lines.join("\n") + "\n"
+}
@@ -0,0 +1,517 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use crate::core::domains::author::Author;
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::csv_format::{CsvFormat, CsvRecord};
+use crate::core::domains::isbn::isbn10_to_13;
+use crate::core::domains::reading::{Reading, ReadingStatus};
+
+/// External services a library can be imported from
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportSource {
+    Goodreads,
+    StoryGraph,
+    LibraryThing,
+}
+
+/// A book read from an external library export, before it is saved
+#[derive(Debug, Clone)]
+pub struct ImportedBook {
+    pub row_number: usize,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub isbn: Option<String>,
+    pub book_type: BookType,
+    pub total_pages: Option<i32>,
+    pub publication_year: Option<i32>,
+    pub status: BookStatus,
+    pub is_wishlist: bool,
+    pub tags: Vec<String>,
+    pub rating: Option<f64>,
+    pub review: Option<String>,
+    pub date_started: Option<NaiveDate>, // Start of the latest reading, when the export has it
+    pub date_read: Option<NaiveDate>,
+    pub date_added: Option<NaiveDate>,
+    pub read_count: i32,
+}
+
+impl ImportedBook {
+    /// Builds the Book entity, falling back to the given length when the export has none
+    pub fn to_book(&self, default_total_pages: Option<i32>, default_total_minutes: Option<i32>) -> Result<Book, String> {
+        let total_pages = self.total_pages.or(default_total_pages);
+        let total_minutes = match self.book_type {
+            BookType::Audiobook => default_total_minutes,
+            _ => None,
+        };
+
+        match self.book_type {
+            BookType::Audiobook if total_minutes.is_none() => {
+                return Err("No duration in export; set a default length for audiobooks".to_string());
+            }
+            BookType::Audiobook => {}
+            _ if total_pages.is_none() => {
+                return Err("No page count in export; set a default page count".to_string());
+            }
+            _ => {}
+        }
+
+        let mut book = Book::new(self.title.clone(), self.book_type.clone(), total_pages, total_minutes)?;
+        book.author = Author::join_names(&self.authors);
+        book.isbn = self.isbn.clone();
+        book.publication_year = self.publication_year;
+        book.status = self.status.clone();
+        book.is_wishlist = self.is_wishlist;
+
+        if let Some(added) = self.date_added {
+            book.added_at = to_utc(added);
+        }
+        book.status_changed_at = match self.status {
+            BookStatus::Completed => self.date_read.or(self.date_added).map(to_utc),
+            BookStatus::NotStarted => None,
+            _ => self.date_added.map(to_utc),
+        };
+
+        if self.status == BookStatus::Completed {
+            book.current_page_text = book.total_pages.unwrap_or(0);
+            book.current_minutes_audio = book.total_minutes.unwrap_or(0);
+        }
+
+        Ok(book)
+    }
+
+    /// Builds the reading history implied by the export: one reading per recorded read,
+    /// with the date, rating and review attached to the latest one
+    pub fn to_readings(&self, book_id: i64) -> Result<Vec<Reading>, String> {
+        let status = match self.status {
+            BookStatus::NotStarted => return Ok(Vec::new()),
+            BookStatus::Reading | BookStatus::Rereading => ReadingStatus::Reading,
+            BookStatus::Paused => ReadingStatus::Paused,
+            BookStatus::Abandoned => ReadingStatus::Abandoned,
+            BookStatus::Completed => ReadingStatus::Completed,
+        };
+
+        let completed_reads = if status == ReadingStatus::Completed { self.read_count.max(1) } else { 0 };
+        let mut readings = Vec::new();
+
+        for number in 1..=completed_reads {
+            let mut reading = Reading::new(book_id, number)?;
+            reading.status = ReadingStatus::Completed;
+            readings.push(reading);
+        }
+
+        // A book in progress gets its ongoing reading after the completed ones
+        if status != ReadingStatus::Completed {
+            let mut reading = Reading::new(book_id, self.read_count.max(0) + 1)?;
+            reading.status = status;
+            reading.started_at = self.date_added.map(to_utc);
+            readings.push(reading);
+        }
+
+        if let Some(latest) = readings.last_mut() {
+            if latest.status == ReadingStatus::Completed {
+                latest.completed_at = self.date_read.map(to_utc);
+            }
+            latest.started_at = self.date_started.map(to_utc).or(latest.started_at);
+            latest.rate(self.rating, self.review.clone())?;
+        }
+
+        Ok(readings)
+    }
+
+    /// Finds an existing book that matches this one by ISBN, or by title and first author
+    pub fn find_duplicate<'b>(&self, books: &'b [Book]) -> Option<&'b Book> {
+        if let Some(isbn) = self.isbn.as_deref().and_then(normalize_isbn) {
+            let by_isbn = books.iter().find(|book| {
+                book.isbn.as_deref().and_then(normalize_isbn).as_deref() == Some(isbn.as_str())
+            });
+            if by_isbn.is_some() {
+                return by_isbn;
+            }
+        }
+
+        let title = normalize_title(&self.title);
+        let author = self.authors.first().map(|a| normalize_title(a)).unwrap_or_default();
+        books.iter().find(|book| {
+            let book_author = book.author
+                .as_deref()
+                .and_then(|a| Author::split_names(a).into_iter().next())
+                .map(|a| normalize_title(&a))
+                .unwrap_or_default();
+            normalize_title(&book.title) == title && book_author == author
+        })
+    }
+}
+
+/// Books read from an export plus per-row errors (row number, message).
+pub type ParsedLibraryExport = (Vec<ImportedBook>, Vec<(usize, String)>);
+
+/// Parses a library export into imported books.
+/// Returns the books that could be read plus per-row errors (row number, message).
+pub fn parse_library_export(
+    source: &ImportSource,
+    content: &str,
+) -> Result<ParsedLibraryExport, String> {
+    let (headers, records) = CsvFormat::parse_with_headers(content)?;
+
+    let (required_column, source_name) = match source {
+        ImportSource::Goodreads => ("Exclusive Shelf", "Goodreads"),
+        ImportSource::StoryGraph => ("Read Status", "StoryGraph"),
+        ImportSource::LibraryThing => ("Primary Author", "LibraryThing"),
+    };
+    if !headers.iter().any(|h| h.eq_ignore_ascii_case(required_column)) {
+        return Err(format!(
+            "Not a {} export: missing '{}' column",
+            source_name, required_column
+        ));
+    }
+
+    let mut books = Vec::new();
+    let mut errors = Vec::new();
+    for record in records {
+        let parsed = match source {
+            ImportSource::Goodreads => parse_goodreads_record(&record),
+            ImportSource::StoryGraph => parse_storygraph_record(&record),
+            ImportSource::LibraryThing => parse_librarything_record(&record),
+        };
+        match parsed {
+            Ok(book) => books.push(book),
+            Err(e) => errors.push((record.row_number, e)),
+        }
+    }
+
+    Ok((books, errors))
+}
+
+/// Maps one row of a Goodreads "library_export.csv"
+fn parse_goodreads_record(record: &CsvRecord) -> Result<ImportedBook, String> {
+    let title = record.get("Title").ok_or("Missing title")?.to_string();
+
+    let mut authors: Vec<String> = Vec::new();
+    if let Some(author) = record.get("Author") {
+        authors.push(author.to_string());
+    }
+    if let Some(additional) = record.get("Additional Authors") {
+        authors.extend(additional.split(',').map(|a| a.trim().to_string()).filter(|a| !a.is_empty()));
+    }
+
+    // Goodreads wraps ISBNs as ="0451526538" to stop spreadsheets mangling them
+    let isbn = record.get("ISBN13")
+        .and_then(clean_goodreads_isbn)
+        .or_else(|| record.get("ISBN").and_then(clean_goodreads_isbn));
+
+    let book_type = book_type_from_format(record.get("Binding"));
+
+    let exclusive_shelf = record.get("Exclusive Shelf").unwrap_or("read").to_lowercase();
+    let (status, is_wishlist) = match exclusive_shelf.as_str() {
+        "read" => (BookStatus::Completed, false),
+        "currently-reading" => (BookStatus::Reading, false),
+        "to-read" => (BookStatus::NotStarted, true),
+        "did-not-finish" | "dnf" | "abandoned" => (BookStatus::Abandoned, false),
+        "paused" | "on-hold" => (BookStatus::Paused, false),
+        _ => (BookStatus::NotStarted, false),
+    };
+
+    // Every shelf other than the exclusive one becomes a tag
+    let tags: Vec<String> = record.get("Bookshelves")
+        .map(|shelves| {
+            shelves
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty() && s.to_lowercase() != exclusive_shelf)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let rating = record.get("My Rating")
+        .and_then(|r| r.parse::<f64>().ok())
+        .and_then(round_rating);
+
+    let read_count = record.get("Read Count")
+        .and_then(|c| c.parse::<i32>().ok())
+        .unwrap_or(if status == BookStatus::Completed { 1 } else { 0 });
+
+    Ok(ImportedBook {
+        row_number: record.row_number,
+        title,
+        authors,
+        isbn,
+        book_type,
+        total_pages: record.get("Number of Pages").and_then(|p| p.parse::<i32>().ok()).filter(|p| *p > 0),
+        publication_year: record.get("Original Publication Year")
+            .or_else(|| record.get("Year Published"))
+            .and_then(|y| y.parse::<i32>().ok()),
+        status,
+        is_wishlist,
+        tags,
+        rating,
+        review: record.get("My Review").map(|r| r.to_string()),
+        date_started: None,
+        date_read: record.get("Date Read").and_then(parse_import_date),
+        date_added: record.get("Date Added").and_then(parse_import_date),
+        read_count,
+    })
+}
+
+/// Maps one row of a StoryGraph export
+fn parse_storygraph_record(record: &CsvRecord) -> Result<ImportedBook, String> {
+    let title = record.get("Title").ok_or("Missing title")?.to_string();
+
+    let read_status = record.get("Read Status").unwrap_or("to-read").to_lowercase();
+    let (status, is_wishlist) = match read_status.as_str() {
+        "read" => (BookStatus::Completed, false),
+        "currently-reading" => (BookStatus::Reading, false),
+        "paused" => (BookStatus::Paused, false),
+        "did-not-finish" => (BookStatus::Abandoned, false),
+        "to-read" => (BookStatus::NotStarted, true),
+        _ => (BookStatus::NotStarted, false),
+    };
+
+    // "Dates Read" lists every read as "start-end", latest last; a bare date is an end date
+    let (date_started, date_read) = match record.get("Dates Read").and_then(|d| d.split(',').next_back()) {
+        Some(range) => match range.trim().split_once('-') {
+            Some((start, end)) => (parse_import_date(start.trim()), parse_import_date(end.trim())),
+            None => (None, parse_import_date(range.trim())),
+        },
+        None => (None, None),
+    };
+
+    let read_count = record.get("Read Count")
+        .and_then(|c| c.parse::<i32>().ok())
+        .unwrap_or(if status == BookStatus::Completed { 1 } else { 0 });
+
+    Ok(ImportedBook {
+        row_number: record.row_number,
+        title,
+        authors: split_list(record.get("Authors")),
+        isbn: record.get("ISBN/UID")
+            .filter(|id| normalize_isbn(id).is_some())
+            .map(|id| id.to_string()),
+        book_type: book_type_from_format(record.get("Format")),
+        total_pages: None, // StoryGraph exports carry no page count
+        publication_year: None,
+        status,
+        is_wishlist,
+        tags: split_list(record.get("Tags")),
+        rating: record.get("Star Rating").and_then(|r| r.parse::<f64>().ok()).and_then(round_rating),
+        review: record.get("Review").map(|r| r.to_string()),
+        date_started,
+        date_read: date_read.or_else(|| record.get("Last Date Read").and_then(parse_import_date)),
+        date_added: record.get("Date Added").and_then(parse_import_date),
+        read_count,
+    })
+}
+
+/// Maps one row of a LibraryThing export (CSV or tab-separated)
+fn parse_librarything_record(record: &CsvRecord) -> Result<ImportedBook, String> {
+    let title = record.get("Title").ok_or("Missing title")?.to_string();
+
+    // Authors are exported as "Last, First"
+    let mut authors: Vec<String> = Vec::new();
+    for column in ["Primary Author", "Secondary Author"] {
+        if let Some(name) = record.get(column) {
+            authors.push(match name.split_once(',') {
+                Some((last, first)) => format!("{} {}", first.trim(), last.trim()),
+                None => name.to_string(),
+            });
+        }
+    }
+
+    // ISBN is exported as "[0441013597]"
+    let isbn = record.get("ISBN")
+        .map(|i| i.trim_matches(|c| c == '[' || c == ']').trim().to_string())
+        .filter(|i| !i.is_empty());
+
+    let collections: Vec<String> = split_list(record.get("Collections"))
+        .into_iter()
+        .map(|c| c.to_lowercase())
+        .collect();
+    let date_read = record.get("Date Read").and_then(parse_import_date);
+    let has = |name: &str| collections.iter().any(|c| c == name);
+
+    let (status, is_wishlist) = if has("currently reading") {
+        (BookStatus::Reading, false)
+    } else if date_read.is_some() || has("read but unowned") {
+        (BookStatus::Completed, false)
+    } else if has("wishlist") {
+        (BookStatus::NotStarted, true)
+    } else {
+        (BookStatus::NotStarted, false)
+    };
+
+    Ok(ImportedBook {
+        row_number: record.row_number,
+        title,
+        authors,
+        isbn,
+        book_type: book_type_from_format(record.get("Media")),
+        total_pages: record.get("Page Count").and_then(|p| p.parse::<i32>().ok()).filter(|p| *p > 0),
+        publication_year: record.get("Date")
+            .and_then(|d| d.get(..4))
+            .and_then(|y| y.parse::<i32>().ok()),
+        status: status.clone(),
+        is_wishlist,
+        tags: split_list(record.get("Tags")),
+        rating: record.get("Rating").and_then(|r| r.parse::<f64>().ok()).and_then(round_rating),
+        review: record.get("Review").map(|r| r.to_string()),
+        date_started: record.get("Date Started").and_then(parse_import_date),
+        date_read,
+        date_added: record.get("Entry Date")
+            .or_else(|| record.get("Acquired"))
+            .and_then(parse_import_date),
+        read_count: if status == BookStatus::Completed { 1 } else { 0 },
+    })
+}
+
+/// Maps a free-text format/media value ("audio", "Ebook", "Paper Book", "hardcover") to a book type
+fn book_type_from_format(format: Option<&str>) -> BookType {
+    match format.map(|f| f.to_lowercase()) {
+        Some(f) if f.contains("audio") => BookType::Audiobook,
+        Some(f) if f.contains("digital") || f.contains("ebook") || f.contains("e-book") || f.contains("kindle") => BookType::Ebook,
+        Some(f) if f.contains("pdf") => BookType::Pdf,
+        Some(f) if f.contains("comic") || f.contains("graphic") => BookType::Comic,
+        _ => BookType::PhysicalBook,
+    }
+}
+
+/// Splits a comma-separated list column
+fn split_list(value: Option<&str>) -> Vec<String> {
+    value
+        .map(|v| {
+            v.split(',')
+                .map(|item| item.trim().to_string())
+                .filter(|item| !item.is_empty())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Rounds fractional ratings (StoryGraph uses quarter stars) to our half-star scale; 0 means unrated
+fn round_rating(rating: f64) -> Option<f64> {
+    if rating <= 0.0 {
+        return None;
+    }
+    Some(((rating * 2.0).round() / 2.0).clamp(0.5, 5.0))
+}
+
+fn clean_goodreads_isbn(value: &str) -> Option<String> {
+    let cleaned = value.trim_start_matches('=').trim_matches('"').trim();
+    if cleaned.is_empty() {
+        None
+    } else {
+        Some(cleaned.to_string())
+    }
+}
+
+/// Parses the date formats used by library exports (YYYY/MM/DD, YYYY-MM-DD)
+pub fn parse_import_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y/%m/%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+        .ok()
+}
+
+fn to_utc(date: NaiveDate) -> chrono::DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap_or_default())
+}
+
+/// Normalizes an ISBN-10 or ISBN-13 to its 13-digit form for comparison
+pub fn normalize_isbn(isbn: &str) -> Option<String> {
+    let digits: String = isbn
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == 'X' || *c == 'x')
+        .collect::<String>()
+        .to_uppercase();
+
+    match digits.len() {
+        13 => Some(digits),
+        10 => Some(isbn10_to_13(&digits)),
+        _ => None,
+    }
+}
+
+/// Lowercases and strips punctuation so titles differing only in case or spacing compare equal
+pub fn normalize_title(value: &str) -> String {
+    value
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_ratings_to_half_stars_within_range() {
+        assert_eq!(round_rating(4.0), Some(4.0));
+        assert_eq!(round_rating(3.74), Some(3.5));
+        assert_eq!(round_rating(3.75), Some(4.0));
+        assert_eq!(round_rating(0.1), Some(0.5));
+        assert_eq!(round_rating(7.0), Some(5.0));
+    }
+
+    #[test]
+    fn treats_a_zero_rating_as_unrated() {
+        assert_eq!(round_rating(0.0), None);
+        assert_eq!(round_rating(-1.0), None);
+    }
+
+    #[test]
+    fn normalizes_isbns_to_thirteen_digits() {
+        assert_eq!(normalize_isbn("978-0-441-01359-3").as_deref(), Some("9780441013593"));
+        assert_eq!(normalize_isbn("0-441-01359-7").as_deref(), Some("9780441013593"));
+        assert_eq!(normalize_isbn("080442957x").as_deref(), Some("9780804429573"));
+    }
+
+    #[test]
+    fn rejects_isbns_of_the_wrong_length() {
+        assert_eq!(normalize_isbn(""), None);
+        assert_eq!(normalize_isbn("12345"), None);
+        assert_eq!(normalize_isbn("=\"\""), None);
+    }
+
+    #[test]
+    fn parses_a_goodreads_row() {
+        let content = "Title,Author,Additional Authors,ISBN,ISBN13,My Rating,Exclusive Shelf,Bookshelves,Number of Pages,Date Read\n\
+            Good Omens,Neil Gaiman,Terry Pratchett,\"=\"\"0060853980\"\"\",\"=\"\"\"\"\",4,read,\"read, favourites\",412,2020/03/01\n\
+            ,Nobody,,,,,read,,,\n";
+        let (books, errors) = parse_library_export(&ImportSource::Goodreads, content).unwrap();
+
+        assert_eq!(books.len(), 1);
+        let book = &books[0];
+        assert_eq!(book.authors, vec!["Neil Gaiman", "Terry Pratchett"]);
+        assert_eq!(book.isbn.as_deref(), Some("0060853980"));
+        assert_eq!(book.status, BookStatus::Completed);
+        assert_eq!(book.tags, vec!["favourites"]);
+        assert_eq!(book.rating, Some(4.0));
+        assert_eq!(book.date_read, NaiveDate::from_ymd_opt(2020, 3, 1));
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].1, "Missing title");
+    }
+
+    #[test]
+    fn rejects_an_export_from_another_service() {
+        assert!(parse_library_export(&ImportSource::Goodreads, "Title,Read Status\nDune,read\n").is_err());
+    }
+
+    #[test]
+    fn finds_duplicates_by_isbn_or_title_and_first_author() {
+        let content = "Title,Author,ISBN13,Exclusive Shelf\nGood Omens,Neil Gaiman,9780060853983,read\n";
+        let (books, _) = parse_library_export(&ImportSource::Goodreads, content).unwrap();
+        let imported = &books[0];
+
+        let mut by_isbn = Book::new("Good Omens (Paperback)".to_string(), BookType::PhysicalBook, Some(412), None).unwrap();
+        by_isbn.isbn = Some("978-0-06-085398-3".to_string());
+        assert!(imported.find_duplicate(&[by_isbn]).is_some());
+
+        let mut by_title = Book::new("good omens".to_string(), BookType::PhysicalBook, Some(412), None).unwrap();
+        by_title.author = Some("Neil Gaiman & Terry Pratchett".to_string());
+        assert!(imported.find_duplicate(&[by_title.clone()]).is_some());
+
+        by_title.author = Some("Terry Pratchett".to_string());
+        assert!(imported.find_duplicate(&[by_title]).is_none());
+    }
+}
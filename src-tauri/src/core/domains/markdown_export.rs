@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, HashMap};
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::journal_entry::JournalEntry;
+use crate::core::domains::library_export::{ExportEntry, ExportedFile};
+use crate::core::domains::reading::ReadingStatus;
+
+const BOOKS_FOLDER: &str = "Books";
+const JOURNAL_FOLDER: &str = "Journal";
+
+/// Builds an Obsidian-compatible vault: one note per book in "Books/" and one note per
+/// day in "Journal/", cross-linked with [[wiki links]].
+/// `unlinked_journal` holds journal entries that belong to no book.
+pub fn obsidian_vault(entries: &[ExportEntry], unlinked_journal: &[JournalEntry]) -> Vec<ExportedFile> {
+    let names = note_names(entries);
+    let mut files = Vec::new();
+
+    for entry in entries {
+        let name = note_name(&names, &entry.book);
+        files.push(ExportedFile {
+            path: format!("{}/{}.md", BOOKS_FOLDER, name),
+            content: book_markdown(entry),
+        });
+    }
+
+    // Daily journal notes, each entry linked to its book
+    let mut days: BTreeMap<String, Vec<(Option<String>, &JournalEntry)>> = BTreeMap::new();
+    for entry in entries {
+        for journal in &entry.journal_entries {
+            days.entry(journal.entry_date.format("%Y-%m-%d").to_string())
+                .or_default()
+                .push((Some(note_name(&names, &entry.book)), journal));
+        }
+    }
+    for journal in unlinked_journal {
+        days.entry(journal.entry_date.format("%Y-%m-%d").to_string())
+            .or_default()
+            .push((None, journal));
+    }
+
+    for (day, mut day_entries) in days {
+        day_entries.sort_by_key(|(_, journal)| journal.created_at);
+        let mut lines = vec![
+            "---".to_string(),
+            format!("date: {}", day),
+            "tags: [journal]".to_string(),
+            "---".to_string(),
+            String::new(),
+            format!("# {}", day),
+        ];
+        for (book_name, journal) in day_entries {
+            lines.push(String::new());
+            lines.push(match book_name {
+                Some(name) => format!("## [[{}]]", name),
+                None => "## Journal".to_string(),
+            });
+            lines.push(String::new());
+            lines.push(journal.content.trim().to_string());
+        }
+
+        files.push(ExportedFile {
+            path: format!("{}/{}.md", JOURNAL_FOLDER, day),
+            content: lines.join("\n") + "\n",
+        });
+    }
+
+    files
+}
+
+/// Markdown note for one book: YAML frontmatter, notes by page, linked journal days
+pub fn book_markdown(entry: &ExportEntry) -> String {
+    let book = &entry.book;
+    let date = |d: chrono::DateTime<chrono::Utc>| d.format("%Y-%m-%d").to_string();
+    let started = entry.readings.iter().filter_map(|r| r.started_at).min();
+    let finished = entry.readings
+        .iter()
+        .filter(|r| r.status == ReadingStatus::Completed)
+        .filter_map(|r| r.completed_at)
+        .max();
+    let (rating, _) = entry.latest_review();
+
+    let mut lines = vec!["---".to_string(), format!("title: {}", yaml_string(&book.title))];
+    if let Some(author) = &book.author {
+        lines.push(format!("author: {}", yaml_string(author)));
+    }
+    lines.push(format!("status: {}", status_name(&book.status)));
+    lines.push(format!("type: {}", type_name(&book.book_type)));
+    if let Some(isbn) = &book.isbn {
+        lines.push(format!("isbn: {}", yaml_string(isbn)));
+    }
+    if let Some(year) = book.publication_year {
+        lines.push(format!("published: {}", year));
+    }
+    lines.push(format!("added: {}", date(book.added_at)));
+    if let Some(started) = started {
+        lines.push(format!("started: {}", date(started)));
+    }
+    if let Some(finished) = finished {
+        lines.push(format!("finished: {}", date(finished)));
+    }
+    if let Some(rating) = rating {
+        lines.push(format!("rating: {}", rating));
+    }
+    let tags: Vec<String> = entry.tags.iter().map(|t| yaml_string(&obsidian_tag(t))).collect();
+    lines.push(format!("tags: [{}]", tags.join(", ")));
+    lines.push("---".to_string());
+    lines.push(String::new());
+    lines.push(format!("# {}", book.title));
+
+    // Reviews, latest reading first
+    for reading in entry.readings.iter().rev() {
+        if let Some(review) = &reading.review {
+            lines.push(String::new());
+            lines.push(format!("## Review (reading {})", reading.reading_number));
+            lines.push(String::new());
+            lines.push(review.trim().to_string());
+        }
+    }
+
+    if !entry.notes.is_empty() {
+        let mut notes: Vec<_> = entry.notes.iter().collect();
+        // By page, notes without a page last, then in the order they were written
+        notes.sort_by_key(|n| (n.page.is_none(), n.page, n.created_at));
+
+        lines.push(String::new());
+        lines.push("## Notes".to_string());
+        for note in notes {
+            lines.push(String::new());
+            lines.push(match note.page {
+                Some(page) => format!("### Page {}", page),
+                None => "### Note".to_string(),
+            });
+            lines.push(String::new());
+            lines.push(note.content.trim().to_string());
+            lines.push(String::new());
+            lines.push(format!("*{}*", date(note.created_at)));
+        }
+    }
+
+    if !entry.journal_entries.is_empty() {
+        let mut journal: Vec<_> = entry.journal_entries.iter().collect();
+        journal.sort_by_key(|j| (j.entry_date, j.created_at));
+
+        lines.push(String::new());
+        lines.push("## Journal".to_string());
+        lines.push(String::new());
+        for item in journal {
+            let first_line = item.content.trim().lines().next().unwrap_or("").to_string();
+            lines.push(format!("- [[{}]] {}", item.entry_date.format("%Y-%m-%d"), first_line));
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Unique, file-system safe note names; repeated titles get the author or id appended
+fn note_names(entries: &[ExportEntry]) -> HashMap<i64, String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for entry in entries {
+        *counts.entry(safe_file_name(&entry.book.title).to_lowercase()).or_default() += 1;
+    }
+
+    let mut names = HashMap::new();
+    let mut used: Vec<String> = Vec::new();
+    for entry in entries {
+        let base = safe_file_name(&entry.book.title);
+        let mut name = if counts.get(&base.to_lowercase()).copied().unwrap_or(0) > 1 {
+            match &entry.book.author {
+                Some(author) => format!("{} ({})", base, safe_file_name(author)),
+                None => base.clone(),
+            }
+        } else {
+            base.clone()
+        };
+        if used.contains(&name.to_lowercase()) {
+            name = format!("{} ({})", base, entry.book.id.unwrap_or(0));
+        }
+        used.push(name.to_lowercase());
+        if let Some(id) = entry.book.id {
+            names.insert(id, name);
+        }
+    }
+    names
+}
+
+fn note_name(names: &HashMap<i64, String>, book: &Book) -> String {
+    book.id
+        .and_then(|id| names.get(&id).cloned())
+        .unwrap_or_else(|| safe_file_name(&book.title))
+}
+
+/// Removes characters Obsidian does not allow in note names (and links)
+fn safe_file_name(value: &str) -> String {
+    let cleaned: String = value
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' | '#' | '^' | '[' | ']' => ' ',
+            c if c.is_control() => ' ',
+            c => c,
+        })
+        .collect();
+    let name = cleaned.split_whitespace().collect::<Vec<_>>().join(" ");
+    let name = name.trim_matches('.').trim().to_string();
+    if name.is_empty() { "Untitled".to_string() } else { name }
+}
+
+/// Obsidian tags cannot contain spaces
+fn obsidian_tag(tag: &str) -> String {
+    tag.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+fn yaml_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', " "))
+}
+
+fn status_name(status: &BookStatus) -> &'static str {
+    match status {
+        BookStatus::NotStarted => "not_started",
+        BookStatus::Reading => "reading",
+        BookStatus::Paused => "paused",
+        BookStatus::Abandoned => "abandoned",
+        BookStatus::Completed => "completed",
+        BookStatus::Rereading => "rereading",
+    }
+}
+
+fn type_name(book_type: &BookType) -> &'static str {
+    match book_type {
+        BookType::PhysicalBook => "physical_book",
+        BookType::Ebook => "ebook",
+        BookType::Audiobook => "audiobook",
+        BookType::Article => "article",
+        BookType::Pdf => "pdf",
+        BookType::Comic => "comic",
+    }
+}
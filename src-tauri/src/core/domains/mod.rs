@@ -1,13 +1,39 @@
 // Domain entities and services
+pub mod active_session;
 pub mod agenda_block;
+pub mod audiobook_chapter;
+pub mod author;
 pub mod book;
+pub mod book_cover;
+pub mod book_metadata;
+pub mod calibre_library;
+pub mod citation;
 pub mod collection;
+pub mod csv_format;
+pub mod edition;
 pub mod goal;
+pub mod isbn;
 pub mod journal_entry;
+pub mod kindle_clippings;
+pub mod koreader_metadata;
+pub mod library_export;
+pub mod library_import;
+pub mod markdown_export;
 pub mod note;
+pub mod pdf_document;
+pub mod progress_correction;
+pub mod progress_unit;
 pub mod reading;
+pub mod reading_speed;
+pub mod series;
 pub mod session;
+pub mod session_context;
+pub mod session_csv;
+pub mod session_photo;
+pub mod session_validation;
 pub mod setting;
+pub mod tabular_export;
 pub mod tag;
+pub mod timezone;
 pub mod progress_calculator;
 pub mod statistics_calculator;
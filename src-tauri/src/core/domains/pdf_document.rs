@@ -0,0 +1,299 @@
+/// Minimal text-only PDF writer (PDF 1.4, built-in Helvetica fonts, A4 pages).
+/// Text is encoded as WinAnsi with octal escapes, so the output is plain ASCII.
+pub struct PdfDocument {
+    title: String,
+    blocks: Vec<Block>,
+}
+
+enum Block {
+    Title(String),
+    Heading(String),
+    Paragraph(String),
+    Meta(String), // Small italic line (dates, page references)
+    Bullet(String),
+    Spacer,
+}
+
+#[derive(Clone, Copy)]
+enum Font {
+    Regular,
+    Bold,
+    Italic,
+}
+
+impl Font {
+    fn resource(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+            Font::Italic => "F3",
+        }
+    }
+}
+
+/// One positioned line of text
+struct PlacedLine {
+    font: Font,
+    size: f64,
+    x: f64,
+    y: f64,
+    text: String,
+}
+
+const PAGE_WIDTH: f64 = 595.0;
+const PAGE_HEIGHT: f64 = 842.0;
+const MARGIN: f64 = 56.0;
+const BULLET_INDENT: f64 = 14.0;
+
+impl PdfDocument {
+    pub fn new(title: &str) -> Self {
+        PdfDocument {
+            title: title.to_string(),
+            blocks: Vec::new(),
+        }
+    }
+
+    pub fn title(&mut self, text: &str) {
+        self.blocks.push(Block::Title(text.to_string()));
+    }
+
+    pub fn heading(&mut self, text: &str) {
+        self.blocks.push(Block::Heading(text.to_string()));
+    }
+
+    pub fn paragraph(&mut self, text: &str) {
+        self.blocks.push(Block::Paragraph(text.to_string()));
+    }
+
+    pub fn meta(&mut self, text: &str) {
+        self.blocks.push(Block::Meta(text.to_string()));
+    }
+
+    pub fn bullet(&mut self, text: &str) {
+        self.blocks.push(Block::Bullet(text.to_string()));
+    }
+
+    pub fn spacer(&mut self) {
+        self.blocks.push(Block::Spacer);
+    }
+
+    /// Lays out the blocks into pages and serializes the PDF file
+    pub fn render(&self) -> String {
+        let pages = self.layout();
+        let page_count = pages.len();
+
+        let mut objects: Vec<String> = Vec::new();
+        // 1: catalog, 2: page tree, 3-5: fonts, 6: info; then a page and its content per page
+        let page_ids: Vec<usize> = (0..page_count).map(|i| 7 + i * 2).collect();
+
+        objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+        objects.push(format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids.iter().map(|id| format!("{} 0 R", id)).collect::<Vec<_>>().join(" "),
+            page_count
+        ));
+        for font in ["Helvetica", "Helvetica-Bold", "Helvetica-Oblique"] {
+            objects.push(format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                font
+            ));
+        }
+        objects.push(format!(
+            "<< /Title ({}) /Producer (Library) /CreationDate (D:{}) >>",
+            encode_text(&self.title),
+            chrono::Utc::now().format("%Y%m%d%H%M%SZ")
+        ));
+
+        for (index, lines) in pages.iter().enumerate() {
+            let mut stream = String::new();
+            for line in lines {
+                stream.push_str(&format!(
+                    "BT /{} {} Tf {:.2} {:.2} Td ({}) Tj ET\n",
+                    line.font.resource(),
+                    line.size,
+                    line.x,
+                    line.y,
+                    encode_text(&line.text)
+                ));
+            }
+            // Footer with page number
+            let footer = format!("{} / {}", index + 1, page_count);
+            stream.push_str(&format!(
+                "BT /F1 8 Tf {:.2} {:.2} Td ({}) Tj ET\n",
+                (PAGE_WIDTH - text_width(&footer, 8.0)) / 2.0,
+                MARGIN / 2.0,
+                encode_text(&footer)
+            ));
+
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R /F3 5 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                page_ids[index] + 1
+            ));
+            objects.push(format!("<< /Length {} >>\nstream\n{}endstream", stream.len(), stream));
+        }
+
+        let mut out = String::from("%PDF-1.4\n");
+        let mut offsets = Vec::new();
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+        }
+
+        let xref_offset = out.len();
+        out.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+        for offset in offsets {
+            out.push_str(&format!("{:010} 00000 n \n", offset));
+        }
+        out.push_str(&format!(
+            "trailer\n<< /Size {} /Root 1 0 R /Info 6 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref_offset
+        ));
+        out
+    }
+
+    /// Wraps every block to the text width and distributes the lines over pages
+    fn layout(&self) -> Vec<Vec<PlacedLine>> {
+        let text_width_max = PAGE_WIDTH - 2.0 * MARGIN;
+        let mut pages: Vec<Vec<PlacedLine>> = vec![Vec::new()];
+        let mut y = PAGE_HEIGHT - MARGIN;
+
+        for block in &self.blocks {
+            let (font, size, indent, space_before, text) = match block {
+                Block::Title(t) => (Font::Bold, 20.0, 0.0, 0.0, t.as_str()),
+                Block::Heading(t) => (Font::Bold, 14.0, 0.0, 14.0, t.as_str()),
+                Block::Paragraph(t) => (Font::Regular, 11.0, 0.0, 4.0, t.as_str()),
+                Block::Meta(t) => (Font::Italic, 9.0, 0.0, 2.0, t.as_str()),
+                Block::Bullet(t) => (Font::Regular, 11.0, BULLET_INDENT, 2.0, t.as_str()),
+                Block::Spacer => {
+                    y -= 10.0;
+                    continue;
+                }
+            };
+            let leading = size * 1.35;
+            let lines = wrap(text, size, text_width_max - indent);
+            y -= space_before;
+
+            for (line_index, line) in lines.into_iter().enumerate() {
+                if y - leading < MARGIN {
+                    pages.push(Vec::new());
+                    y = PAGE_HEIGHT - MARGIN;
+                }
+                y -= leading;
+
+                let page = pages.last_mut().expect("at least one page");
+                if line_index == 0 {
+                    if let Block::Bullet(_) = block {
+                        page.push(PlacedLine { font, size, x: MARGIN, y, text: "\u{2022}".to_string() });
+                    }
+                }
+                page.push(PlacedLine { font, size, x: MARGIN + indent, y, text: line });
+            }
+        }
+
+        pages
+    }
+}
+
+/// Splits text into lines no wider than `max_width`, keeping explicit line breaks
+fn wrap(text: &str, size: f64, max_width: f64) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.split('\n') {
+        let mut current = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+            if text_width(&candidate, size) <= max_width {
+                current = candidate;
+                continue;
+            }
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            // Break words longer than a whole line
+            for c in word.chars() {
+                if text_width(&format!("{}{}", current, c), size) > max_width && !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                }
+                current.push(c);
+            }
+        }
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Width of a string in points, from the Helvetica glyph metrics
+fn text_width(text: &str, size: f64) -> f64 {
+    text.chars().map(|c| glyph_width(c) as f64).sum::<f64>() * size / 1000.0
+}
+
+fn glyph_width(c: char) -> u16 {
+    const ASCII_WIDTHS: [u16; 95] = [
+        278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, // space - /
+        556, 556, 556, 556, 556, 556, 556, 556, 556, 556, // 0 - 9
+        278, 278, 584, 584, 584, 556, 1015, // : - @
+        667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, // A - M
+        722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, // N - Z
+        278, 278, 278, 469, 556, 333, // [ - `
+        556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, // a - m
+        556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, // n - z
+        334, 260, 334, 584, // { - ~
+    ];
+    match c as u32 {
+        code @ 32..=126 => ASCII_WIDTHS[(code - 32) as usize],
+        _ => 556,
+    }
+}
+
+/// Encodes text as a PDF string body in WinAnsiEncoding, escaping non-ASCII bytes in octal
+fn encode_text(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        let byte: u8 = match c {
+            '\t' => b' ',
+            c if (c as u32) < 32 => continue,
+            c if (c as u32) < 127 => c as u8,
+            c if (0xA0..=0xFF).contains(&(c as u32)) => c as u32 as u8,
+            '\u{20AC}' => 0x80,
+            '\u{201A}' => 0x82,
+            '\u{201E}' => 0x84,
+            '\u{2026}' => 0x85,
+            '\u{2020}' => 0x86,
+            '\u{2021}' => 0x87,
+            '\u{2030}' => 0x89,
+            '\u{0160}' => 0x8A,
+            '\u{2039}' => 0x8B,
+            '\u{0152}' => 0x8C,
+            '\u{017D}' => 0x8E,
+            '\u{2018}' => 0x91,
+            '\u{2019}' => 0x92,
+            '\u{201C}' => 0x93,
+            '\u{201D}' => 0x94,
+            '\u{2022}' => 0x95,
+            '\u{2013}' => 0x96,
+            '\u{2014}' => 0x97,
+            '\u{02DC}' => 0x98,
+            '\u{2122}' => 0x99,
+            '\u{0161}' => 0x9A,
+            '\u{203A}' => 0x9B,
+            '\u{0153}' => 0x9C,
+            '\u{017E}' => 0x9E,
+            '\u{0178}' => 0x9F,
+            _ => b'?', // Outside the standard fonts' character set
+        };
+        match byte {
+            b'(' | b')' | b'\\' => {
+                out.push('\\');
+                out.push(byte as char);
+            }
+            32..=126 => out.push(byte as char),
+            _ => out.push_str(&format!("\\{:03o}", byte)),
+        }
+    }
+    out
+}
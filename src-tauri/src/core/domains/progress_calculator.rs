@@ -1,6 +1,16 @@
-use crate::core::domains::book::Book;
+use crate::core::domains::book::{Book, BookType};
+use crate::core::domains::edition::Edition;
 use crate::core::domains::session::ReadingSession;
 
+/// Format-independent position change recorded by a session
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionPosition {
+    /// Session ended at a known fraction of the edition (text: end_page / total_pages)
+    At(f64),
+    /// Session moved forward by a fraction of the edition (audio: minutes_read / total_minutes)
+    Advanced(f64),
+}
+
 /// Domain service for calculating reading progress
 pub struct ProgressCalculator;
 
@@ -31,26 +41,79 @@ impl ProgressCalculator {
         ((current as f64 / total as f64) * 100.0).min(100.0)
     }
 
-    /// Calculates hybrid progress (text + audio)
-    pub fn calculate_hybrid_progress(
-        current_page_text: i32,
-        total_pages: Option<i32>,
-        current_minutes_audio: i32,
-        total_minutes: Option<i32>,
+    /// Calculates hybrid progress (text + audio) as a percentage
+    /// Positions must be in chronological order; each one moves a single shared
+    /// position, so alternating between a paperback and an audiobook continues
+    /// from wherever the other format left off
+    pub fn calculate_hybrid_progress(positions: &[SessionPosition]) -> f64 {
+        let mut fraction: f64 = 0.0;
+
+        for position in positions {
+            fraction = match position {
+                SessionPosition::At(at) => *at,
+                SessionPosition::Advanced(delta) => fraction + delta,
+            }
+            .clamp(0.0, 1.0);
+        }
+
+        fraction * 100.0
+    }
+
+    /// Shared position (0.0 - 1.0) of a book across all of its editions
+    pub fn unified_fraction(
+        book: &Book,
+        editions: &[Edition],
+        sessions: &[ReadingSession],
     ) -> f64 {
-        let text_progress = if let Some(total) = total_pages {
-            Self::calculate_percentage(current_page_text, total) * 0.5
-        } else {
-            0.0
-        };
-
-        let audio_progress = if let Some(total) = total_minutes {
-            Self::calculate_percentage(current_minutes_audio, total) * 0.5
-        } else {
-            0.0
-        };
-
-        text_progress + audio_progress
+        let positions = Self::session_positions(book, editions, sessions);
+        Self::calculate_hybrid_progress(&positions) / 100.0
     }
-}
 
+    /// Converts sessions into format-independent positions
+    /// Sessions without an edition were recorded against the book's own format
+    pub fn session_positions(
+        book: &Book,
+        editions: &[Edition],
+        sessions: &[ReadingSession],
+    ) -> Vec<SessionPosition> {
+        let mut ordered: Vec<&ReadingSession> = sessions.iter().collect();
+        ordered.sort_by(|a, b| {
+            a.session_date
+                .cmp(&b.session_date)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+
+        let mut positions = Vec::new();
+        for session in ordered {
+            let edition = session
+                .edition_id
+                .and_then(|id| editions.iter().find(|e| e.id == Some(id)));
+
+            let (format, total_pages, total_minutes) = match edition {
+                Some(edition) => (&edition.format, edition.total_pages, edition.total_minutes),
+                None => (&book.book_type, book.total_pages, book.total_minutes),
+            };
+
+            let position = match format {
+                BookType::Audiobook => match (session.minutes_read, total_minutes) {
+                    (Some(minutes), Some(total)) if total > 0 => {
+                        Some(SessionPosition::Advanced(minutes as f64 / total as f64))
+                    }
+                    _ => None,
+                },
+                _ => match (session.end_page, total_pages) {
+                    (Some(end_page), Some(total)) if total > 0 => {
+                        Some(SessionPosition::At(end_page as f64 / total as f64))
+                    }
+                    _ => None,
+                },
+            };
+
+            if let Some(position) = position {
+                positions.push(position);
+            }
+        }
+
+        positions
+    }
+}
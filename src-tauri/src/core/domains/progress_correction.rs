@@ -0,0 +1,302 @@
+use crate::core::domains::session::ReadingSession;
+use serde::{Deserialize, Serialize};
+
+/// How a corrected position is written into the session history
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CorrectionMode {
+    /// Adds a session that moves the position to the corrected value
+    AdjustmentSession,
+    /// Scales the pages (or minutes) of later sessions so they end at the corrected value
+    Rebase,
+}
+
+impl CorrectionMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "adjustment" => Ok(CorrectionMode::AdjustmentSession),
+            "rebase" => Ok(CorrectionMode::Rebase),
+            other => Err(format!("Invalid correction mode: {} (expected adjustment or rebase)", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorrectionMode::AdjustmentSession => "adjustment",
+            CorrectionMode::Rebase => "rebase",
+        }
+    }
+}
+
+/// Unit of the corrected position: pages for text, minutes for audiobooks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CorrectionUnit {
+    Pages,
+    Minutes,
+}
+
+impl CorrectionUnit {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "pages" => Ok(CorrectionUnit::Pages),
+            "minutes" => Ok(CorrectionUnit::Minutes),
+            other => Err(format!("Invalid correction unit: {}", other)),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CorrectionUnit::Pages => "pages",
+            CorrectionUnit::Minutes => "minutes",
+        }
+    }
+}
+
+/// One field of one session changed by a correction
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionChange {
+    pub session_id: Option<i64>,
+    pub field: String, // start_page, end_page, pages_read or minutes_read
+    pub old_value: Option<i32>, // None for a field of a new adjustment session
+    pub new_value: Option<i32>,
+}
+
+/// Audit entry recording a progress correction and what it changed
+#[derive(Debug, Clone)]
+pub struct ProgressCorrection {
+    pub id: Option<i64>,
+    pub book_id: i64,
+    pub mode: CorrectionMode,
+    pub unit: CorrectionUnit,
+    pub previous_value: i32,
+    pub corrected_value: i32,
+    pub adjustment_session_id: Option<i64>,
+    pub changes: Vec<SessionChange>,
+    pub note: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Sessions to write for a correction
+#[derive(Debug, Clone)]
+pub struct CorrectionPlan {
+    pub adjustment: Option<ReadingSession>,
+    pub updated: Vec<ReadingSession>,
+    pub changes: Vec<SessionChange>,
+}
+
+/// Plans progress corrections against a book's session history
+pub struct ProgressCorrector;
+
+impl ProgressCorrector {
+    /// Orders sessions oldest first, by date then creation, the same order used to derive
+    /// the current page (the last session's end page)
+    pub fn chronological(mut sessions: Vec<ReadingSession>) -> Vec<ReadingSession> {
+        sessions.sort_by(|a, b| {
+            a.session_date
+                .cmp(&b.session_date)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+        sessions
+    }
+
+    /// Plans a session moving the position from `current` to `target`.
+    /// Moving forward records the skipped pages as read; moving back pins the position
+    /// with an empty session. Listened minutes can only be added this way.
+    pub fn plan_adjustment(
+        book_id: i64,
+        sessions: &[ReadingSession],
+        unit: CorrectionUnit,
+        current: i32,
+        target: i32,
+        date: chrono::NaiveDate,
+    ) -> Result<CorrectionPlan, String> {
+        let mut session = match unit {
+            CorrectionUnit::Pages => ReadingSession::new(book_id, date, Some(current.min(target)), Some(target))?,
+            CorrectionUnit::Minutes => {
+                if target < current {
+                    return Err("An adjustment session can't remove listened minutes; re-base the sessions instead".to_string());
+                }
+                let mut session = ReadingSession::new(book_id, date, None, None)?;
+                session.minutes_read = Some(target - current);
+                session
+            }
+        };
+
+        // Keep the adjustment in the same reading cycle as the latest session
+        session.reading_id = sessions.last().and_then(|s| s.reading_id);
+
+        let changes = match unit {
+            CorrectionUnit::Pages => vec![
+                Self::created("start_page", session.start_page),
+                Self::created("end_page", session.end_page),
+                Self::created("pages_read", session.pages_read),
+            ],
+            CorrectionUnit::Minutes => vec![Self::created("minutes_read", session.minutes_read)],
+        };
+
+        Ok(CorrectionPlan {
+            adjustment: Some(session),
+            updated: Vec::new(),
+            changes,
+        })
+    }
+
+    /// Plans re-basing the sessions of the latest reading from `from_session_id` (or all of them)
+    /// so they end at `target`; earlier readings are left alone. Sessions must be in chronological
+    /// order. Pages are scaled proportionally from the page the first re-based session starts at
+    /// and must stay within `total`; minutes are scaled so that all sessions sum to `target`.
+    pub fn plan_rebase(
+        sessions: &[ReadingSession],
+        from_session_id: Option<i64>,
+        unit: CorrectionUnit,
+        target: i32,
+        total: Option<i32>,
+    ) -> Result<CorrectionPlan, String> {
+        let latest_reading = sessions
+            .last()
+            .ok_or("There are no sessions to re-base".to_string())?
+            .reading_id;
+        let (cycle, other_readings): (Vec<ReadingSession>, Vec<ReadingSession>) = sessions
+            .iter()
+            .cloned()
+            .partition(|s| s.reading_id == latest_reading);
+
+        let from = match from_session_id {
+            Some(id) => match cycle.iter().position(|s| s.id == Some(id)) {
+                Some(from) => from,
+                None if other_readings.iter().any(|s| s.id == Some(id)) => {
+                    return Err("Only sessions of the latest reading can be re-based".to_string());
+                }
+                None => return Err(format!("Session with id {} not found for this book", id)),
+            },
+            None => 0,
+        };
+
+        let (earlier, later) = cycle.split_at(from);
+        match unit {
+            CorrectionUnit::Pages => Self::rebase_pages(earlier, later, target, total),
+            CorrectionUnit::Minutes => {
+                // Listened minutes add up across readings, so every session not re-based counts
+                let kept_minutes = other_readings
+                    .iter()
+                    .chain(earlier)
+                    .filter_map(|s| s.minutes_read)
+                    .sum();
+                Self::rebase_minutes(kept_minutes, later, target)
+            }
+        }
+    }
+
+    fn rebase_pages(earlier: &[ReadingSession], later: &[ReadingSession], target: i32, total: Option<i32>) -> Result<CorrectionPlan, String> {
+        let base = later[0]
+            .start_page
+            .or_else(|| earlier.iter().rev().find_map(|s| s.end_page))
+            .unwrap_or(0);
+        let old_end = later
+            .iter()
+            .rev()
+            .find_map(|s| s.end_page)
+            .ok_or("The sessions to re-base have no pages recorded".to_string())?;
+
+        if old_end <= base {
+            return Err("The sessions to re-base record no progress; add an adjustment session instead".to_string());
+        }
+        if target < base {
+            return Err(format!(
+                "The corrected page ({}) can't be before page {} where the re-based sessions start",
+                target, base
+            ));
+        }
+
+        let scale = (target - base) as f64 / (old_end - base) as f64;
+        let map = |page: Option<i32>| {
+            page.map(|p| if p <= base { p } else { base + ((p - base) as f64 * scale).round() as i32 })
+        };
+
+        let mut plan = CorrectionPlan { adjustment: None, updated: Vec::new(), changes: Vec::new() };
+        for original in later {
+            let mut session = original.clone();
+            session.start_page = map(original.start_page);
+            session.end_page = map(original.end_page);
+            if let Some(total) = total {
+                if let Some(page) = [session.start_page, session.end_page].into_iter().flatten().find(|p| *p > total) {
+                    return Err(format!(
+                        "Re-basing would move a session on {} to page {}, past the book's {} pages",
+                        original.session_date.format("%Y-%m-%d"),
+                        page,
+                        total
+                    ));
+                }
+            }
+            session.update_pages_read()?;
+
+            let before = plan.changes.len();
+            plan.changes.extend(Self::changed(original.id, "start_page", original.start_page, session.start_page));
+            plan.changes.extend(Self::changed(original.id, "end_page", original.end_page, session.end_page));
+            plan.changes.extend(Self::changed(original.id, "pages_read", original.pages_read, session.pages_read));
+            if plan.changes.len() > before {
+                plan.updated.push(session);
+            }
+        }
+
+        Ok(plan)
+    }
+
+    fn rebase_minutes(earlier_minutes: i32, later: &[ReadingSession], target: i32) -> Result<CorrectionPlan, String> {
+        let later_minutes: i32 = later.iter().filter_map(|s| s.minutes_read).sum();
+
+        if later_minutes <= 0 {
+            return Err("The sessions to re-base record no listening time; add an adjustment session instead".to_string());
+        }
+        if target < earlier_minutes {
+            return Err(format!(
+                "The corrected position ({} min) can't be before the {} min listened in earlier sessions",
+                target, earlier_minutes
+            ));
+        }
+
+        // Round the running total rather than each session, so the minutes add up exactly
+        let scale = (target - earlier_minutes) as f64 / later_minutes as f64;
+        let mut running = 0;
+        let mut plan = CorrectionPlan { adjustment: None, updated: Vec::new(), changes: Vec::new() };
+        for original in later {
+            let minutes = match original.minutes_read {
+                Some(minutes) => minutes,
+                None => continue,
+            };
+            let scaled_before = (running as f64 * scale).round() as i32;
+            running += minutes;
+            let scaled_after = (running as f64 * scale).round() as i32;
+
+            let mut session = original.clone();
+            session.minutes_read = Some(scaled_after - scaled_before);
+
+            if let Some(change) = Self::changed(original.id, "minutes_read", original.minutes_read, session.minutes_read) {
+                plan.changes.push(change);
+                plan.updated.push(session);
+            }
+        }
+
+        Ok(plan)
+    }
+
+    fn created(field: &str, value: Option<i32>) -> SessionChange {
+        SessionChange {
+            session_id: None,
+            field: field.to_string(),
+            old_value: None,
+            new_value: value,
+        }
+    }
+
+    fn changed(session_id: Option<i64>, field: &str, old_value: Option<i32>, new_value: Option<i32>) -> Option<SessionChange> {
+        if old_value == new_value {
+            return None;
+        }
+        Some(SessionChange {
+            session_id,
+            field: field.to_string(),
+            old_value,
+            new_value,
+        })
+    }
+}
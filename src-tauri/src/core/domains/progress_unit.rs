@@ -0,0 +1,108 @@
+use crate::core::domains::book::{Book, BookType};
+use serde::{Deserialize, Serialize};
+
+/// Setting key holding the progress unit new books are tracked in
+pub const PROGRESS_UNIT_SETTING: &str = "defaultProgressUnit";
+
+/// Unit a text book's reading position is recorded in
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ProgressUnit {
+    Page,
+    Percentage,
+    Location, // Kindle location; needs the book's total locations
+}
+
+impl ProgressUnit {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "page" => Ok(ProgressUnit::Page),
+            "percentage" => Ok(ProgressUnit::Percentage),
+            "location" => Ok(ProgressUnit::Location),
+            other => Err(format!("Invalid progress unit: {} (expected page, percentage or location)", other)),
+        }
+    }
+
+    /// Parses the default progress unit setting; locations can only be chosen per book
+    pub fn parse_default(value: &str) -> Result<Self, String> {
+        match Self::parse(value)? {
+            ProgressUnit::Location => Err("Kindle locations can't be the default progress unit; set them per book".to_string()),
+            unit => Ok(unit),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProgressUnit::Page => "page",
+            ProgressUnit::Percentage => "percentage",
+            ProgressUnit::Location => "location",
+        }
+    }
+}
+
+/// Converts positions in a book's progress unit to pages and back
+#[derive(Debug, Clone, Copy)]
+pub struct PositionScale {
+    pub unit: ProgressUnit,
+    total_pages: i32,
+    total_locations: Option<i32>,
+}
+
+impl PositionScale {
+    /// Scale of a text book tracked by percentage or location
+    pub fn for_book(book: &Book) -> Result<Self, String> {
+        if book.book_type == BookType::Audiobook {
+            return Err("Audiobook progress is tracked in minutes".to_string());
+        }
+        if book.progress_unit == ProgressUnit::Page {
+            return Err(format!("\"{}\" is tracked by page; record pages instead of positions", book.title));
+        }
+
+        let total_pages = book
+            .total_pages
+            .filter(|total| *total > 0)
+            .ok_or_else(|| format!("\"{}\" needs a page count to convert positions to pages", book.title))?;
+
+        if book.progress_unit == ProgressUnit::Location && book.total_locations.is_none() {
+            return Err(format!("\"{}\" needs its total number of locations", book.title));
+        }
+
+        Ok(PositionScale {
+            unit: book.progress_unit,
+            total_pages,
+            total_locations: book.total_locations,
+        })
+    }
+
+    /// Largest position in this unit (100% or the last location)
+    pub fn max_position(&self) -> f64 {
+        match self.unit {
+            ProgressUnit::Location => self.total_locations.unwrap_or(0) as f64,
+            ProgressUnit::Percentage => 100.0,
+            ProgressUnit::Page => self.total_pages as f64,
+        }
+    }
+
+    pub fn validate(&self, position: f64) -> Result<(), String> {
+        if !position.is_finite() || position < 0.0 || position > self.max_position() {
+            return Err(match self.unit {
+                ProgressUnit::Percentage => format!("Percentage ({}) must be between 0 and 100", position),
+                _ => format!("Location ({}) must be between 0 and {}", position, self.max_position()),
+            });
+        }
+        Ok(())
+    }
+
+    /// Page at a position, rounded to the nearest page
+    pub fn to_page(&self, position: f64) -> i32 {
+        (position / self.max_position() * self.total_pages as f64).round() as i32
+    }
+
+    /// Position at a page; percentages keep one decimal, locations are whole
+    pub fn from_page(&self, page: i32) -> f64 {
+        let position = page as f64 / self.total_pages as f64 * self.max_position();
+        match self.unit {
+            ProgressUnit::Percentage => (position * 10.0).round() / 10.0,
+            _ => position.round(),
+        }
+    }
+}
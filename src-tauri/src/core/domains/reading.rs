@@ -18,6 +18,8 @@ pub struct Reading {
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub status: ReadingStatus,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    pub rating: Option<f64>, // 0.5 - 5.0 in half-star steps
+    pub review: Option<String>,
 }
 
 impl Reading {
@@ -35,6 +37,8 @@ impl Reading {
             completed_at: None,
             status: ReadingStatus::NotStarted,
             created_at: chrono::Utc::now(),
+            rating: None,
+            review: None,
         })
     }
 
@@ -51,5 +55,35 @@ impl Reading {
         self.status = ReadingStatus::Completed;
         self.completed_at = Some(chrono::Utc::now());
     }
+
+    /// Sets the rating and review for this reading (None clears them)
+    pub fn rate(&mut self, rating: Option<f64>, review: Option<String>) -> Result<(), String> {
+        if self.status == ReadingStatus::NotStarted {
+            return Err("Cannot rate a reading that has not started".to_string());
+        }
+
+        if let Some(value) = rating {
+            validate_rating(value)?;
+        }
+
+        self.rating = rating;
+        self.review = review
+            .map(|r| r.trim().to_string())
+            .filter(|r| !r.is_empty());
+        Ok(())
+    }
+}
+
+/// Validates a star rating: 0.5 to 5.0 in half-star steps
+pub fn validate_rating(rating: f64) -> Result<(), String> {
+    if !(0.5..=5.0).contains(&rating) {
+        return Err("Rating must be between 0.5 and 5".to_string());
+    }
+
+    if (rating * 2.0).fract() != 0.0 {
+        return Err("Rating must use half-star steps (e.g., 3.5)".to_string());
+    }
+
+    Ok(())
 }
 
@@ -0,0 +1,318 @@
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::session::ReadingSession;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+/// Sessions shorter than this are too noisy to measure speed
+const MIN_SESSION_SECONDS: i32 = 60;
+
+/// Below this many samples outliers can't be told apart, so nothing is trimmed
+const MIN_SAMPLES_FOR_TRIMMING: usize = 4;
+
+/// Samples a book needs before its own speed is preferred over the global one
+pub const MIN_BOOK_SAMPLES: usize = 3;
+
+/// Days of recent sessions used to estimate reading cadence
+pub const CADENCE_WINDOW_DAYS: i64 = 28;
+
+/// What a session's progress is measured in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpeedUnit {
+    Pages,        // pages_read
+    AudioMinutes, // minutes_read of audiobooks (at the playback speed used)
+}
+
+impl SpeedUnit {
+    pub fn for_book_type(book_type: &BookType) -> Self {
+        match book_type {
+            BookType::Audiobook => SpeedUnit::AudioMinutes,
+            _ => SpeedUnit::Pages,
+        }
+    }
+
+    /// Progress recorded by a session in this unit
+    pub fn units(&self, session: &ReadingSession) -> Option<i32> {
+        match self {
+            SpeedUnit::Pages => session.pages_read,
+            SpeedUnit::AudioMinutes => session.minutes_read,
+        }
+    }
+}
+
+/// Progress and duration of one timed session, in its book's unit
+#[derive(Debug, Clone)]
+pub struct SpeedSample {
+    pub unit: SpeedUnit,
+    pub units: i32,
+    pub duration_seconds: i32,
+}
+
+/// Reading speed measured from timed sessions
+#[derive(Debug, Clone)]
+pub struct ReadingSpeed {
+    pub unit: SpeedUnit,
+    pub units_per_hour: f64,
+    pub sessions_used: usize,
+    pub sessions_trimmed: usize,
+}
+
+impl ReadingSpeed {
+    /// Measures speed from sessions with both progress and a duration
+    pub fn from_sessions(sessions: &[ReadingSession], unit: SpeedUnit) -> Option<Self> {
+        let samples: Vec<(f64, f64)> = sessions
+            .iter()
+            .filter_map(|s| sample(unit.units(s), s.duration_seconds))
+            .collect();
+
+        Self::measure(&samples, unit)
+    }
+
+    /// Measures speed from the samples recorded in the given unit
+    pub fn from_samples(samples: &[SpeedSample], unit: SpeedUnit) -> Option<Self> {
+        let samples: Vec<(f64, f64)> = samples
+            .iter()
+            .filter(|s| s.unit == unit)
+            .filter_map(|s| sample(Some(s.units), Some(s.duration_seconds)))
+            .collect();
+
+        Self::measure(&samples, unit)
+    }
+
+    /// Sessions far outside the interquartile range are dropped, then the
+    /// speed is total units over total time of the remaining sessions
+    fn measure(samples: &[(f64, f64)], unit: SpeedUnit) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let kept = trim_outliers(samples);
+        let units: f64 = kept.iter().map(|(units, _)| units).sum();
+        let hours: f64 = kept.iter().map(|(_, hours)| hours).sum();
+
+        if hours <= 0.0 {
+            return None;
+        }
+
+        Some(ReadingSpeed {
+            unit,
+            units_per_hour: units / hours,
+            sessions_used: kept.len(),
+            sessions_trimmed: samples.len() - kept.len(),
+        })
+    }
+
+    /// Audio minutes per minute listened (effectively the playback speed)
+    pub fn units_per_minute(&self) -> f64 {
+        self.units_per_hour / 60.0
+    }
+
+    /// Seconds needed to get through the given units at this speed
+    pub fn seconds_for(&self, units: i32) -> Option<i64> {
+        if self.units_per_hour <= 0.0 {
+            return None;
+        }
+        Some((units as f64 / self.units_per_hour * 3600.0).round() as i64)
+    }
+}
+
+/// (units, hours) of a session usable for speed
+fn sample(units: Option<i32>, seconds: Option<i32>) -> Option<(f64, f64)> {
+    let units = units.filter(|u| *u > 0)?;
+    let seconds = seconds.filter(|s| *s >= MIN_SESSION_SECONDS)?;
+    Some((units as f64, seconds as f64 / 3600.0))
+}
+
+/// Keeps samples whose rate lies within 1.5 IQR of the middle half (Tukey's fences)
+fn trim_outliers(samples: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    if samples.len() < MIN_SAMPLES_FOR_TRIMMING {
+        return samples.to_vec();
+    }
+
+    let mut rates: Vec<f64> = samples.iter().map(|(units, hours)| units / hours).collect();
+    rates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let q1 = quantile(&rates, 0.25);
+    let q3 = quantile(&rates, 0.75);
+    let fence = (q3 - q1) * 1.5;
+    let (low, high) = (q1 - fence, q3 + fence);
+
+    samples
+        .iter()
+        .filter(|(units, hours)| {
+            let rate = units / hours;
+            rate >= low && rate <= high
+        })
+        .copied()
+        .collect()
+}
+
+/// Linear-interpolated quantile of sorted values
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = (sorted.len() - 1) as f64 * q;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let weight = position - lower as f64;
+    sorted[lower] * (1.0 - weight) + sorted[upper] * weight
+}
+
+/// Units read per day over the recent window, counted from the first
+/// session in the window up to today (so gaps in reading lower the cadence)
+pub fn recent_cadence(sessions: &[ReadingSession], unit: SpeedUnit, today: NaiveDate) -> Option<f64> {
+    let window_start = today - chrono::Duration::days(CADENCE_WINDOW_DAYS - 1);
+
+    let recent: Vec<&ReadingSession> = sessions
+        .iter()
+        .filter(|s| s.session_date >= window_start && s.session_date <= today)
+        .collect();
+
+    let first_date = recent.iter().map(|s| s.session_date).min()?;
+    let units: i32 = recent.iter().filter_map(|s| unit.units(s)).sum();
+
+    if units <= 0 {
+        return None;
+    }
+
+    let days = (today - first_date).num_days() + 1;
+    Some(units as f64 / days as f64)
+}
+
+/// Time remaining and estimated finish date for a book in progress
+#[derive(Debug, Clone)]
+pub struct FinishPrediction {
+    pub unit: SpeedUnit,
+    pub remaining_units: i32,
+    pub speed: Option<ReadingSpeed>,
+    pub speed_is_global: bool,
+    pub units_per_day: Option<f64>,
+    pub time_remaining_seconds: Option<i64>,
+    pub estimated_finish_date: Option<NaiveDate>,
+}
+
+impl FinishPrediction {
+    /// Predicts from the book's own speed when it has enough samples, else the global speed
+    pub fn new(
+        unit: SpeedUnit,
+        remaining_units: i32,
+        book_speed: Option<ReadingSpeed>,
+        global_speed: Option<ReadingSpeed>,
+        units_per_day: Option<f64>,
+        today: NaiveDate,
+    ) -> Self {
+        let (speed, speed_is_global) = match book_speed {
+            Some(speed) if speed.sessions_used >= MIN_BOOK_SAMPLES => (Some(speed), false),
+            _ => match global_speed {
+                Some(speed) => (Some(speed), true),
+                None => (None, false),
+            },
+        };
+
+        let time_remaining_seconds = speed.as_ref().and_then(|s| s.seconds_for(remaining_units));
+        let estimated_finish_date = units_per_day
+            .filter(|per_day| *per_day > 0.0)
+            .map(|per_day| today + chrono::Duration::days((remaining_units as f64 / per_day).ceil() as i64));
+
+        FinishPrediction {
+            unit,
+            remaining_units,
+            speed,
+            speed_is_global,
+            units_per_day,
+            time_remaining_seconds,
+            estimated_finish_date,
+        }
+    }
+}
+
+/// Library-wide speeds plus the sessions of the books to predict
+/// Sessions logged against another edition are left out, since their progress
+/// is counted in that edition's units
+pub struct SpeedModel {
+    pub global_pages: Option<ReadingSpeed>,
+    pub global_audio: Option<ReadingSpeed>,
+    sessions_by_book: HashMap<i64, Vec<ReadingSession>>,
+}
+
+impl SpeedModel {
+    /// `samples` cover the whole library; `sessions` only need the predicted books'
+    pub fn build(samples: &[SpeedSample], sessions: Vec<ReadingSession>) -> Self {
+        let mut sessions_by_book: HashMap<i64, Vec<ReadingSession>> = HashMap::new();
+        for session in sessions.into_iter().filter(|s| s.edition_id.is_none()) {
+            sessions_by_book.entry(session.book_id).or_default().push(session);
+        }
+
+        SpeedModel {
+            global_pages: ReadingSpeed::from_samples(samples, SpeedUnit::Pages),
+            global_audio: ReadingSpeed::from_samples(samples, SpeedUnit::AudioMinutes),
+            sessions_by_book,
+        }
+    }
+
+    /// Prediction for a book that is not finished and has a known length
+    pub fn predict(&self, book: &Book, today: NaiveDate) -> Option<FinishPrediction> {
+        if matches!(book.status, BookStatus::Completed | BookStatus::Abandoned) {
+            return None;
+        }
+
+        let unit = SpeedUnit::for_book_type(&book.book_type);
+        let remaining_units = match unit {
+            SpeedUnit::Pages => book.total_pages? - book.current_page_text,
+            SpeedUnit::AudioMinutes => book.total_minutes? - book.current_minutes_audio,
+        };
+
+        if remaining_units <= 0 {
+            return None;
+        }
+
+        let sessions = book.id
+            .and_then(|id| self.sessions_by_book.get(&id))
+            .map(|s| s.as_slice())
+            .unwrap_or(&[]);
+
+        let global_speed = match unit {
+            SpeedUnit::Pages => self.global_pages.clone(),
+            SpeedUnit::AudioMinutes => self.global_audio.clone(),
+        };
+
+        Some(FinishPrediction::new(
+            unit,
+            remaining_units,
+            ReadingSpeed::from_sessions(sessions, unit),
+            global_speed,
+            recent_cadence(sessions, unit, today),
+            today,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(units: i32, minutes: i32) -> SpeedSample {
+        SpeedSample { unit: SpeedUnit::Pages, units, duration_seconds: minutes * 60 }
+    }
+
+    #[test]
+    fn measures_units_over_total_time() {
+        let speed = ReadingSpeed::from_samples(&[sample(30, 60), sample(15, 60)], SpeedUnit::Pages).unwrap();
+        assert_eq!(speed.units_per_hour, 22.5);
+        assert_eq!(speed.seconds_for(45), Some(7200));
+    }
+
+    #[test]
+    fn drops_outlier_sessions() {
+        let samples = [sample(30, 60), sample(32, 60), sample(28, 60), sample(31, 60), sample(300, 60)];
+        let speed = ReadingSpeed::from_samples(&samples, SpeedUnit::Pages).unwrap();
+        assert_eq!((speed.sessions_used, speed.sessions_trimmed), (4, 1));
+        assert_eq!(speed.units_per_hour, 30.25);
+    }
+
+    #[test]
+    fn ignores_short_sessions_and_other_units() {
+        let audio = SpeedSample { unit: SpeedUnit::AudioMinutes, units: 90, duration_seconds: 3600 };
+        let short = SpeedSample { unit: SpeedUnit::Pages, units: 5, duration_seconds: 30 };
+        assert!(ReadingSpeed::from_samples(&[audio.clone(), short], SpeedUnit::Pages).is_none());
+        let speed = ReadingSpeed::from_samples(&[audio], SpeedUnit::AudioMinutes).unwrap();
+        assert_eq!(speed.units_per_minute(), 1.5);
+    }
+}
@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+use crate::core::domains::book::{Book, BookStatus};
+
+/// Series entity for grouping numbered volumes (e.g., "The Expanse")
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Series {
+    pub id: Option<i64>,
+    pub name: String,
+    pub author: Option<String>,
+    pub expected_volumes: Option<i32>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl Series {
+    /// Creates a new Series with validation
+    pub fn new(
+        name: String,
+        author: Option<String>,
+        expected_volumes: Option<i32>,
+    ) -> Result<Self, String> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err("Series name cannot be empty".to_string());
+        }
+
+        if name.len() > 200 {
+            return Err("Series name cannot exceed 200 characters".to_string());
+        }
+
+        if let Some(count) = expected_volumes {
+            if count <= 0 {
+                return Err("Expected volume count must be greater than 0".to_string());
+            }
+        }
+
+        let author = author.map(|a| a.trim().to_string()).filter(|a| !a.is_empty());
+
+        let now = chrono::Utc::now();
+        Ok(Series {
+            id: None,
+            name,
+            author,
+            expected_volumes,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Updates the series name
+    pub fn update_name(&mut self, name: String) -> Result<(), String> {
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            return Err("Series name cannot be empty".to_string());
+        }
+
+        if name.len() > 200 {
+            return Err("Series name cannot exceed 200 characters".to_string());
+        }
+
+        self.name = name;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    /// Updates the expected number of volumes
+    pub fn update_expected_volumes(&mut self, expected_volumes: Option<i32>) -> Result<(), String> {
+        if let Some(count) = expected_volumes {
+            if count <= 0 {
+                return Err("Expected volume count must be greater than 0".to_string());
+            }
+        }
+
+        self.expected_volumes = expected_volumes;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    /// Returns whole volume numbers that are not present in the library
+    /// Checks 1..=max(expected_volumes, highest owned volume)
+    pub fn missing_volumes(&self, books: &[Book]) -> Vec<i32> {
+        let owned: Vec<f64> = books.iter().filter_map(|b| b.series_index).collect();
+
+        let highest_owned = owned
+            .iter()
+            .fold(0.0_f64, |acc, &index| acc.max(index))
+            .floor() as i32;
+        let last_volume = self.expected_volumes.unwrap_or(0).max(highest_owned);
+
+        (1..=last_volume)
+            .filter(|volume| !owned.contains(&(*volume as f64)))
+            .collect()
+    }
+
+    /// Suggests what to read after finishing a book: the next unread volume of its series
+    /// `books` are the books of the book's series
+    pub fn continue_after<'b>(book: &Book, books: &'b [Book]) -> Option<&'b Book> {
+        if book.status != BookStatus::Completed {
+            return None;
+        }
+        Self::next_unread(books, book.series_index?)
+    }
+
+    /// Finds the next unread volume after the given position in the series
+    /// Books must belong to this series; completed and abandoned volumes are skipped
+    pub fn next_unread(books: &[Book], after_index: f64) -> Option<&Book> {
+        books
+            .iter()
+            .filter(|b| b.series_index.map(|i| i > after_index).unwrap_or(false))
+            .filter(|b| b.status != BookStatus::Completed && b.status != BookStatus::Abandoned)
+            .min_by(|a, b| {
+                a.series_index
+                    .partial_cmp(&b.series_index)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domains::book::BookType;
+
+    fn volume(index: f64, status: BookStatus) -> Book {
+        let mut book = Book::new(format!("Volume {}", index), BookType::PhysicalBook, Some(300), None).unwrap();
+        book.update_series(Some(1), Some(index)).unwrap();
+        book.status = status;
+        book
+    }
+
+    #[test]
+    fn missing_volumes_run_up_to_the_expected_count() {
+        let series = Series::new("The Expanse".to_string(), None, Some(5)).unwrap();
+        let books = vec![volume(1.0, BookStatus::Completed), volume(3.0, BookStatus::NotStarted)];
+        assert_eq!(series.missing_volumes(&books), vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn missing_volumes_run_up_to_the_highest_owned_without_a_count() {
+        let series = Series::new("The Expanse".to_string(), None, None).unwrap();
+        let books = vec![volume(2.0, BookStatus::NotStarted), volume(4.0, BookStatus::NotStarted)];
+        assert_eq!(series.missing_volumes(&books), vec![1, 3]);
+    }
+
+    #[test]
+    fn novellas_between_volumes_do_not_fill_a_gap() {
+        let series = Series::new("The Expanse".to_string(), None, None).unwrap();
+        let books = vec![volume(1.0, BookStatus::NotStarted), volume(2.5, BookStatus::NotStarted)];
+        assert_eq!(series.missing_volumes(&books), vec![2]);
+    }
+
+    #[test]
+    fn rejects_a_non_positive_expected_count() {
+        assert!(Series::new("The Expanse".to_string(), None, Some(0)).is_err());
+    }
+
+    #[test]
+    fn suggests_the_next_unread_volume_after_a_completed_one() {
+        let finished = volume(1.0, BookStatus::Completed);
+        let books = vec![
+            finished.clone(),
+            volume(2.0, BookStatus::Completed),
+            volume(2.5, BookStatus::Abandoned),
+            volume(4.0, BookStatus::NotStarted),
+            volume(3.0, BookStatus::NotStarted),
+        ];
+        let next = Series::continue_after(&finished, &books).unwrap();
+        assert_eq!(next.series_index, Some(3.0));
+    }
+
+    #[test]
+    fn suggests_nothing_for_a_book_still_being_read() {
+        let reading = volume(1.0, BookStatus::Reading);
+        let books = vec![reading.clone(), volume(2.0, BookStatus::NotStarted)];
+        assert!(Series::continue_after(&reading, &books).is_none());
+    }
+}
@@ -1,19 +1,35 @@
+use crate::core::domains::progress_unit::{PositionScale, ProgressUnit};
+use crate::core::domains::session_context::SessionContext;
+use crate::core::domains::timezone::{resolve_span, UserTimezone};
 use serde::{Deserialize, Serialize};
 
+/// Slowest and fastest audiobook playback speed accepted
+pub const PLAYBACK_SPEED_MIN: f64 = 0.5;
+pub const PLAYBACK_SPEED_MAX: f64 = 4.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReadingSession {
     pub id: Option<i64>,
     pub book_id: i64,
     pub reading_id: Option<i64>, // For rereads
+    pub edition_id: Option<i64>, // None = the book's own format
     pub session_date: chrono::NaiveDate,
     pub start_time: Option<chrono::NaiveTime>,
     pub end_time: Option<chrono::NaiveTime>,
+    pub started_at: Option<chrono::DateTime<chrono::FixedOffset>>, // Full start timestamp with the local offset
+    pub ended_at: Option<chrono::DateTime<chrono::FixedOffset>>,   // May fall on the day after session_date
+    pub timezone: Option<String>, // IANA zone the times were recorded in
     pub start_page: Option<i32>,
     pub end_page: Option<i32>,
     pub pages_read: Option<i32>,
-    pub minutes_read: Option<i32>,
+    pub position_unit: Option<ProgressUnit>, // Set when positions were recorded as a percentage or location
+    pub start_position: Option<f64>, // In position_unit; start_page/end_page hold the converted pages
+    pub end_position: Option<f64>,
+    pub minutes_read: Option<i32>, // Audiobooks: minutes of the recording covered
+    pub playback_speed: Option<f64>, // Audiobooks: 1.5 = one and a half times normal speed
     pub duration_seconds: Option<i32>,
     pub photo_path: Option<String>,
+    pub context: SessionContext, // Location, device, mood/energy and interruptions
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -49,30 +65,166 @@ impl ReadingSession {
             id: None,
             book_id,
             reading_id: None,
+            edition_id: None,
             session_date,
             start_time: None,
             end_time: None,
+            started_at: None,
+            ended_at: None,
+            timezone: None,
             start_page,
             end_page,
             pages_read,
+            position_unit: None,
+            start_position: None,
+            end_position: None,
             minutes_read: None,
+            playback_speed: None,
             duration_seconds: None,
             photo_path: None,
+            context: SessionContext::default(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
         })
     }
 
     /// Calculates duration in seconds from start and end times
+    /// Uses the full timestamps when known; otherwise an end time before the
+    /// start time is taken to be after midnight (equal times give zero)
     pub fn calculate_duration(&mut self) {
+        if let (Some(started_at), Some(ended_at)) = (self.started_at, self.ended_at) {
+            self.duration_seconds = Some((ended_at - started_at).num_seconds().max(0) as i32);
+            return;
+        }
+
         if let (Some(start), Some(end)) = (self.start_time, self.end_time) {
-            let start_datetime = chrono::NaiveDateTime::new(self.session_date, start);
-            let end_datetime = chrono::NaiveDateTime::new(self.session_date, end);
-            
-            if let Ok(duration) = end_datetime.signed_duration_since(start_datetime).to_std() {
-                self.duration_seconds = Some(duration.as_secs() as i32);
+            let mut duration = end.signed_duration_since(start);
+            if duration < chrono::Duration::zero() {
+                duration += chrono::Duration::days(1);
+            }
+            self.duration_seconds = Some(duration.num_seconds() as i32);
+        }
+    }
+
+    /// Resolves the local start and end times to full timestamps in the given zone
+    /// and recalculates the duration; clears the timestamps when a time is missing
+    pub fn resolve_times(&mut self, timezone: &UserTimezone) -> Result<(), String> {
+        match (self.start_time, self.end_time) {
+            (Some(start), Some(end)) => {
+                let (started_at, ended_at) = resolve_span(timezone, self.session_date, start, end)?;
+                self.started_at = Some(started_at);
+                self.ended_at = Some(ended_at);
+                self.timezone = timezone.name();
+                self.calculate_duration();
+            }
+            (Some(start), None) => {
+                self.started_at = Some(timezone.resolve(self.session_date, start)?);
+                self.ended_at = None;
+                self.timezone = timezone.name();
+            }
+            _ => {
+                self.started_at = None;
+                self.ended_at = None;
             }
         }
+
+        Ok(())
+    }
+
+    /// Sets the session span from exact instants (e.g. from the session timer)
+    /// Date and wall-clock times are taken in the given zone
+    pub fn set_span(
+        &mut self,
+        started_at: chrono::DateTime<chrono::Utc>,
+        ended_at: chrono::DateTime<chrono::Utc>,
+        timezone: &UserTimezone,
+    ) -> Result<(), String> {
+        if ended_at < started_at {
+            return Err("Session cannot end before it starts".to_string());
+        }
+
+        let started_local = timezone.to_local(started_at);
+        let ended_local = timezone.to_local(ended_at);
+
+        self.session_date = started_local.date_naive();
+        self.start_time = chrono::NaiveTime::from_hms_opt(
+            chrono::Timelike::hour(&started_local),
+            chrono::Timelike::minute(&started_local),
+            chrono::Timelike::second(&started_local),
+        );
+        self.end_time = chrono::NaiveTime::from_hms_opt(
+            chrono::Timelike::hour(&ended_local),
+            chrono::Timelike::minute(&ended_local),
+            chrono::Timelike::second(&ended_local),
+        );
+        self.started_at = Some(started_local);
+        self.ended_at = Some(ended_local);
+        self.timezone = timezone.name();
+
+        Ok(())
+    }
+
+    /// Records start and end positions as a percentage or location, and sets the pages they convert to
+    pub fn set_positions(&mut self, scale: &PositionScale, start: Option<f64>, end: Option<f64>) -> Result<(), String> {
+        for position in [start, end].into_iter().flatten() {
+            scale.validate(position)?;
+        }
+        if let (Some(start), Some(end)) = (start, end) {
+            if end < start {
+                return Err(format!("End position ({}) cannot be before start position ({})", end, start));
+            }
+        }
+
+        self.position_unit = Some(scale.unit);
+        self.start_position = start;
+        self.end_position = end;
+        self.start_page = start.map(|p| scale.to_page(p));
+        self.end_page = end.map(|p| scale.to_page(p));
+        self.update_pages_read()
+    }
+
+    /// Recomputes recorded positions after the pages were changed directly
+    pub fn sync_positions(&mut self, scale: &PositionScale) {
+        if self.position_unit.is_some() {
+            self.position_unit = Some(scale.unit);
+            self.start_position = self.start_page.map(|p| scale.from_page(p));
+            self.end_position = self.end_page.map(|p| scale.from_page(p));
+        }
+    }
+
+    /// Drops recorded positions, leaving the session in pages
+    pub fn clear_positions(&mut self) {
+        self.position_unit = None;
+        self.start_position = None;
+        self.end_position = None;
+    }
+
+    /// Sets the audiobook playback speed, checking it is within the accepted range
+    pub fn set_playback_speed(&mut self, speed: Option<f64>) -> Result<(), String> {
+        if let Some(speed) = speed {
+            if !(PLAYBACK_SPEED_MIN..=PLAYBACK_SPEED_MAX).contains(&speed) {
+                return Err(format!(
+                    "Playback speed must be between {}x and {}x",
+                    PLAYBACK_SPEED_MIN, PLAYBACK_SPEED_MAX
+                ));
+            }
+        }
+        self.playback_speed = speed;
+        Ok(())
+    }
+
+    /// Recording minutes covered by a timed listening session at its playback speed
+    pub fn content_minutes_from_duration(&self) -> Option<i32> {
+        self.duration_seconds
+            .map(|seconds| (seconds as f64 / 60.0 * self.playback_speed.unwrap_or(1.0)).round() as i32)
+    }
+
+    /// Wall-clock time spent listening: the measured duration, or the minutes
+    /// covered at the playback speed. None for sessions without listened minutes
+    pub fn listening_seconds(&self) -> Option<i32> {
+        let minutes = self.minutes_read?;
+        self.duration_seconds
+            .or_else(|| Some((minutes as f64 * 60.0 / self.playback_speed.unwrap_or(1.0)).round() as i32))
     }
 
     /// Updates pages read based on start and end page
@@ -90,3 +242,47 @@ impl ReadingSession {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn session_between(start: (u32, u32), end: (u32, u32)) -> ReadingSession {
+        let date = NaiveDate::from_ymd_opt(2024, 10, 26).unwrap();
+        let mut session = ReadingSession::new(1, date, None, None).unwrap();
+        session.start_time = NaiveTime::from_hms_opt(start.0, start.1, 0);
+        session.end_time = NaiveTime::from_hms_opt(end.0, end.1, 0);
+        session
+    }
+
+    #[test]
+    fn duration_from_times_wraps_past_midnight() {
+        let mut session = session_between((23, 30), (0, 15));
+        session.calculate_duration();
+        assert_eq!(session.duration_seconds, Some(45 * 60));
+    }
+
+    #[test]
+    fn equal_times_have_no_duration() {
+        let mut session = session_between((9, 0), (9, 0));
+        session.calculate_duration();
+        assert_eq!(session.duration_seconds, Some(0));
+    }
+
+    #[test]
+    fn resolved_times_count_the_real_elapsed_time() {
+        // Clocks in Berlin go back an hour during the night of 26-27 October 2024
+        let mut session = session_between((23, 0), (4, 0));
+        session.resolve_times(&UserTimezone::parse("Europe/Berlin").unwrap()).unwrap();
+        assert_eq!(session.duration_seconds, Some(6 * 3600));
+        assert_eq!(session.timezone.as_deref(), Some("Europe/Berlin"));
+    }
+
+    #[test]
+    fn rejects_an_end_page_before_the_start_page() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert!(ReadingSession::new(1, date, Some(20), Some(10)).is_err());
+        assert_eq!(ReadingSession::new(1, date, Some(10), Some(20)).unwrap().pages_read, Some(10));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::core::domains::session::ReadingSession;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Lowest and highest mood/energy rating
+pub const LEVEL_MIN: i32 = 1;
+pub const LEVEL_MAX: i32 = 5;
+
+/// What a session was read on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ReadingDevice {
+    Paper,
+    EReader,
+    Phone,
+    Tablet,
+    Computer,
+    Audio,
+    Other,
+}
+
+impl ReadingDevice {
+    /// Parses a device name; "kindle", "kobo" and "e-reader" are taken as an e-reader
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "paper" => Ok(ReadingDevice::Paper),
+            "ereader" | "e-reader" | "kindle" | "kobo" => Ok(ReadingDevice::EReader),
+            "phone" => Ok(ReadingDevice::Phone),
+            "tablet" => Ok(ReadingDevice::Tablet),
+            "computer" => Ok(ReadingDevice::Computer),
+            "audio" => Ok(ReadingDevice::Audio),
+            "other" => Ok(ReadingDevice::Other),
+            other => Err(format!(
+                "Invalid device: {} (expected paper, ereader, phone, tablet, computer, audio or other)",
+                other
+            )),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadingDevice::Paper => "paper",
+            ReadingDevice::EReader => "ereader",
+            ReadingDevice::Phone => "phone",
+            ReadingDevice::Tablet => "tablet",
+            ReadingDevice::Computer => "computer",
+            ReadingDevice::Audio => "audio",
+            ReadingDevice::Other => "other",
+        }
+    }
+}
+
+/// Optional circumstances of a session, recorded for reading-habit analysis
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SessionContext {
+    pub location: Option<String>, // Free label, e.g. "Home", "Train"
+    pub device: Option<ReadingDevice>,
+    pub mood_before: Option<i32>, // 1 (low) to 5 (high)
+    pub mood_after: Option<i32>,
+    pub energy_before: Option<i32>, // 1 (tired) to 5 (energetic)
+    pub energy_after: Option<i32>,
+    pub interruptions: Option<i32>,
+}
+
+impl SessionContext {
+    /// Checks ratings and counts, and drops a blank location
+    pub fn normalize(mut self) -> Result<Self, String> {
+        self.location = self
+            .location
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty());
+
+        for (field, value) in [
+            ("Mood before", self.mood_before),
+            ("Mood after", self.mood_after),
+            ("Energy before", self.energy_before),
+            ("Energy after", self.energy_after),
+        ] {
+            if let Some(value) = value {
+                if !(LEVEL_MIN..=LEVEL_MAX).contains(&value) {
+                    return Err(format!("{} must be between {} and {}", field, LEVEL_MIN, LEVEL_MAX));
+                }
+            }
+        }
+
+        if self.interruptions.map(|i| i < 0).unwrap_or(false) {
+            return Err("Interruptions cannot be negative".to_string());
+        }
+
+        Ok(self)
+    }
+}
+
+/// Part of the day a session started in (local time)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeOfDay {
+    Morning,   // 05:00-11:59
+    Afternoon, // 12:00-16:59
+    Evening,   // 17:00-21:59
+    Night,     // 22:00-04:59
+}
+
+impl TimeOfDay {
+    pub fn from_time(time: chrono::NaiveTime) -> Self {
+        match chrono::Timelike::hour(&time) {
+            5..=11 => TimeOfDay::Morning,
+            12..=16 => TimeOfDay::Afternoon,
+            17..=21 => TimeOfDay::Evening,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeOfDay::Morning => "morning",
+            TimeOfDay::Afternoon => "afternoon",
+            TimeOfDay::Evening => "evening",
+            TimeOfDay::Night => "night",
+        }
+    }
+}
+
+/// Reading totals for the sessions sharing one context value
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContextBucket {
+    pub key: String,
+    pub session_count: usize,
+    pub pages_read: i32,
+    pub minutes_listened: i32,
+    pub reading_seconds: i64,
+    /// Pages per hour over sessions with both pages and a duration
+    pub pages_per_hour: Option<f64>,
+    pub average_mood_change: Option<f64>, // After minus before
+    pub average_energy_change: Option<f64>,
+    pub average_interruptions: Option<f64>,
+}
+
+/// Groups sessions by their context and measures reading pace in each group
+pub struct ContextAnalysis;
+
+impl ContextAnalysis {
+    /// Sessions grouped by location; labels differing only in case or spacing are merged
+    pub fn by_location(sessions: &[ReadingSession]) -> Vec<ContextBucket> {
+        let mut labels: BTreeMap<String, String> = BTreeMap::new();
+        let grouped = Self::group(sessions, |s| {
+            s.context.location.as_ref().map(|location| {
+                let key = location.trim().to_lowercase();
+                labels.entry(key.clone()).or_insert_with(|| location.trim().to_string());
+                key
+            })
+        });
+
+        grouped
+            .into_iter()
+            .map(|(key, group)| Self::bucket(labels.get(&key).cloned().unwrap_or(key), &group))
+            .collect()
+    }
+
+    pub fn by_device(sessions: &[ReadingSession]) -> Vec<ContextBucket> {
+        Self::group(sessions, |s| s.context.device)
+            .into_iter()
+            .map(|(device, group)| Self::bucket(device.as_str().to_string(), &group))
+            .collect()
+    }
+
+    /// Sessions grouped by the local time they started; untimed sessions are left out
+    pub fn by_time_of_day(sessions: &[ReadingSession]) -> Vec<ContextBucket> {
+        Self::group(sessions, |s| {
+            s.started_at
+                .map(|started_at| started_at.time())
+                .or(s.start_time)
+                .map(TimeOfDay::from_time)
+        })
+        .into_iter()
+        .map(|(time_of_day, group)| Self::bucket(time_of_day.as_str().to_string(), &group))
+        .collect()
+    }
+
+    fn group<K: Ord>(
+        sessions: &[ReadingSession],
+        mut key: impl FnMut(&ReadingSession) -> Option<K>,
+    ) -> BTreeMap<K, Vec<&ReadingSession>> {
+        let mut groups: BTreeMap<K, Vec<&ReadingSession>> = BTreeMap::new();
+        for session in sessions {
+            if let Some(k) = key(session) {
+                groups.entry(k).or_default().push(session);
+            }
+        }
+        groups
+    }
+
+    fn bucket(key: String, sessions: &[&ReadingSession]) -> ContextBucket {
+        let (timed_pages, timed_seconds) = sessions
+            .iter()
+            .filter_map(|s| match (s.pages_read, s.duration_seconds) {
+                (Some(pages), Some(seconds)) if pages > 0 && seconds > 0 => Some((pages, seconds as i64)),
+                _ => None,
+            })
+            .fold((0, 0i64), |(pages, seconds), (p, s)| (pages + p, seconds + s));
+
+        ContextBucket {
+            key,
+            session_count: sessions.len(),
+            pages_read: sessions.iter().filter_map(|s| s.pages_read).sum(),
+            minutes_listened: sessions.iter().filter_map(|s| s.minutes_read).sum(),
+            reading_seconds: sessions.iter().filter_map(|s| s.duration_seconds).map(|d| d as i64).sum(),
+            pages_per_hour: (timed_seconds > 0).then(|| Self::round(timed_pages as f64 * 3600.0 / timed_seconds as f64)),
+            average_mood_change: Self::average_change(sessions, |c| (c.mood_before, c.mood_after)),
+            average_energy_change: Self::average_change(sessions, |c| (c.energy_before, c.energy_after)),
+            average_interruptions: Self::average(sessions.iter().filter_map(|s| s.context.interruptions)),
+        }
+    }
+
+    fn average_change(
+        sessions: &[&ReadingSession],
+        levels: impl Fn(&SessionContext) -> (Option<i32>, Option<i32>),
+    ) -> Option<f64> {
+        Self::average(sessions.iter().filter_map(|s| match levels(&s.context) {
+            (Some(before), Some(after)) => Some(after - before),
+            _ => None,
+        }))
+    }
+
+    fn average(values: impl Iterator<Item = i32>) -> Option<f64> {
+        let (sum, count) = values.fold((0i64, 0usize), |(sum, count), v| (sum + v as i64, count + 1));
+        (count > 0).then(|| Self::round(sum as f64 / count as f64))
+    }
+
+    fn round(value: f64) -> f64 {
+        (value * 100.0).round() / 100.0
+    }
+}
@@ -0,0 +1,117 @@
+use crate::core::domains::book::Book;
+use crate::core::domains::csv_format::{CsvFormat, CsvRecord};
+use crate::core::domains::library_import::{normalize_isbn, normalize_title, parse_import_date};
+use chrono::NaiveDate;
+
+/// One reading session parsed from a paper-log CSV
+/// Columns (case-insensitive): title or isbn, date, and optionally
+/// start_time, end_time, start_page, end_page, minutes
+#[derive(Debug, Clone)]
+pub struct SessionCsvRow {
+    pub row_number: usize,
+    pub title: Option<String>,
+    pub isbn: Option<String>,
+    pub session_date: NaiveDate,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub start_page: Option<i32>,
+    pub end_page: Option<i32>,
+    pub minutes_read: Option<i32>,
+}
+
+impl SessionCsvRow {
+    fn from_record(record: &CsvRecord) -> Result<Self, String> {
+        let title = first_of(record, &["title", "book"]).map(str::to_string);
+        let isbn = first_of(record, &["isbn", "isbn13", "isbn10"]).map(str::to_string);
+        if title.is_none() && isbn.is_none() {
+            return Err("Row has neither a title nor an ISBN".to_string());
+        }
+
+        let date = first_of(record, &["date", "session_date"])
+            .ok_or("Row has no date".to_string())?;
+        let session_date = parse_import_date(date)
+            .ok_or_else(|| format!("Invalid date '{}'. Expected YYYY-MM-DD", date))?;
+
+        Ok(SessionCsvRow {
+            row_number: record.row_number,
+            title,
+            isbn,
+            session_date,
+            start_time: first_of(record, &["start_time", "start"]).map(str::to_string),
+            end_time: first_of(record, &["end_time", "end"]).map(str::to_string),
+            start_page: parse_number(record, &["start_page", "from_page"])?,
+            end_page: parse_number(record, &["end_page", "to_page"])?,
+            minutes_read: parse_number(record, &["minutes", "minutes_read"])?,
+        })
+    }
+
+    /// Finds the book this row refers to: by ISBN first, then by exact (normalized) title
+    pub fn match_book<'b>(&self, books: &'b [Book]) -> Result<&'b Book, String> {
+        if let Some(isbn) = self.isbn.as_deref().and_then(normalize_isbn) {
+            let by_isbn = books
+                .iter()
+                .find(|b| b.isbn.as_deref().and_then(normalize_isbn).as_deref() == Some(isbn.as_str()));
+            if let Some(book) = by_isbn {
+                return Ok(book);
+            }
+        }
+
+        let title = match &self.title {
+            Some(title) => title,
+            None => return Err(format!("No book with ISBN {}", self.isbn.as_deref().unwrap_or(""))),
+        };
+
+        let wanted = normalize_title(title);
+        let matches: Vec<&Book> = books
+            .iter()
+            .filter(|b| normalize_title(&b.title) == wanted)
+            .collect();
+
+        match matches.as_slice() {
+            [book] => Ok(book),
+            [] => Err(format!("No book titled '{}'", title)),
+            _ => Err(format!("{} books are titled '{}'; add the ISBN to pick one", matches.len(), title)),
+        }
+    }
+}
+
+/// Rows read from a session CSV plus per-row errors (row number, message).
+pub type ParsedSessionCsv = (Vec<SessionCsvRow>, Vec<(usize, String)>);
+
+/// Parses a session CSV into rows and (row number, error) pairs for rows that can't be read
+pub fn parse_session_csv(content: &str) -> Result<ParsedSessionCsv, String> {
+    let (headers, records) = CsvFormat::parse_with_headers(content)?;
+
+    let has = |names: &[&str]| headers.iter().any(|h| names.iter().any(|n| h.eq_ignore_ascii_case(n)));
+    if !has(&["date", "session_date"]) {
+        return Err("CSV needs a 'date' column".to_string());
+    }
+    if !has(&["title", "book", "isbn", "isbn13", "isbn10"]) {
+        return Err("CSV needs a 'title' or 'isbn' column".to_string());
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for record in &records {
+        match SessionCsvRow::from_record(record) {
+            Ok(row) => rows.push(row),
+            Err(e) => errors.push((record.row_number, e)),
+        }
+    }
+
+    Ok((rows, errors))
+}
+
+fn first_of<'r>(record: &'r CsvRecord, columns: &[&str]) -> Option<&'r str> {
+    columns.iter().find_map(|column| record.get(column))
+}
+
+fn parse_number(record: &CsvRecord, columns: &[&str]) -> Result<Option<i32>, String> {
+    match first_of(record, columns) {
+        Some(value) => value
+            .parse::<i32>()
+            .map(Some)
+            .map_err(|_| format!("Invalid {} '{}'", columns[0], value)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,43 @@
+use crate::core::domains::book_cover::{mime_type_for_extension, thumbnail_file_name, StoredImage};
+
+/// A page scan or photo stored in the app's photos folder, linked to a reading session.
+/// Files are named by the SHA-256 of their content, so the same image is stored once.
+#[derive(Debug, Clone)]
+pub struct SessionPhoto {
+    pub id: Option<i64>,
+    pub session_id: i64,
+    pub hash: String,      // SHA-256 of the original image, hex
+    pub extension: String, // "jpg", "png" or "webp"
+    pub width: u32,
+    pub height: u32,
+    pub source: Option<String>, // File path the photo was attached from
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl SessionPhoto {
+    pub fn new(session_id: i64, image: StoredImage, source: Option<String>) -> Self {
+        SessionPhoto {
+            id: None,
+            session_id,
+            hash: image.hash,
+            extension: image.extension,
+            width: image.width,
+            height: image.height,
+            source,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{}.{}", self.hash, self.extension)
+    }
+
+    /// Thumbnails are always JPEG
+    pub fn thumbnail_file_name(&self) -> String {
+        thumbnail_file_name(&self.hash)
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        mime_type_for_extension(&self.extension)
+    }
+}
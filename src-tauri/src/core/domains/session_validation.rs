@@ -0,0 +1,249 @@
+use crate::core::domains::session::ReadingSession;
+use chrono::{NaiveDate, NaiveTime};
+
+/// How a consistency problem is handled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueSeverity {
+    Error,   // The session is rejected
+    Warning, // The session is saved once the user confirms
+}
+
+/// Kinds of session consistency problems
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IssueKind {
+    InvalidTime,
+    PageOutOfRange,
+    Overlap,
+    PageRegression,
+}
+
+impl IssueKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueKind::InvalidTime => "invalid_time",
+            IssueKind::PageOutOfRange => "page_out_of_range",
+            IssueKind::Overlap => "overlap",
+            IssueKind::PageRegression => "page_regression",
+        }
+    }
+
+    pub fn severity(&self) -> IssueSeverity {
+        match self {
+            IssueKind::InvalidTime | IssueKind::PageOutOfRange => IssueSeverity::Error,
+            IssueKind::Overlap | IssueKind::PageRegression => IssueSeverity::Warning,
+        }
+    }
+}
+
+/// A consistency problem found in a session
+#[derive(Debug, Clone)]
+pub struct SessionIssue {
+    pub kind: IssueKind,
+    pub message: String,
+    pub conflicting_session_id: Option<i64>,
+}
+
+impl SessionIssue {
+    fn new(kind: IssueKind, message: String, conflicting_session_id: Option<i64>) -> Self {
+        SessionIssue { kind, message, conflicting_session_id }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.kind.severity() == IssueSeverity::Error
+    }
+}
+
+/// Parses an HH:MM:SS (or HH:MM) time; an empty value means no time
+/// Unlike a silent `.ok()`, a malformed value is reported as an issue
+pub fn parse_session_time(field: &str, value: Option<&str>) -> Result<Option<NaiveTime>, SessionIssue> {
+    let value = match value.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+
+    NaiveTime::parse_from_str(value, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+        .map(Some)
+        .map_err(|_| SessionIssue::new(
+            IssueKind::InvalidTime,
+            format!("Invalid {} '{}'. Expected HH:MM:SS", field, value),
+            None,
+        ))
+}
+
+/// Checks a session against the book's length and the user's other sessions
+pub struct SessionValidator;
+
+impl SessionValidator {
+    /// `total_pages` is the length of the format read (the edition's when one is set)
+    /// `others` may include the session itself; it is skipped by ID
+    pub fn validate(session: &ReadingSession, total_pages: Option<i32>, others: &[ReadingSession]) -> Vec<SessionIssue> {
+        let others: Vec<&ReadingSession> = others
+            .iter()
+            .filter(|o| session.id.is_none() || o.id != session.id)
+            .collect();
+
+        let mut issues = Vec::new();
+        issues.extend(Self::check_page_range(session, total_pages));
+        issues.extend(Self::check_overlaps(session, &others));
+        issues.extend(Self::check_page_regression(session, &others));
+        issues
+    }
+
+    fn check_page_range(session: &ReadingSession, total_pages: Option<i32>) -> Vec<SessionIssue> {
+        let total = match total_pages {
+            Some(total) => total,
+            None => return Vec::new(),
+        };
+
+        [("Start page", session.start_page), ("End page", session.end_page)]
+            .into_iter()
+            .filter_map(|(label, page)| page.filter(|p| *p > total).map(|p| (label, p)))
+            .map(|(label, page)| SessionIssue::new(
+                IssueKind::PageOutOfRange,
+                format!("{} ({}) is beyond the last page ({})", label, page, total),
+                None,
+            ))
+            .collect()
+    }
+
+    /// Sessions of any book whose time spans intersect this one
+    fn check_overlaps(session: &ReadingSession, others: &[&ReadingSession]) -> Vec<SessionIssue> {
+        let (start, end) = match (session.started_at, session.ended_at) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return Vec::new(),
+        };
+
+        others
+            .iter()
+            .filter(|other| match (other.started_at, other.ended_at) {
+                (Some(other_start), Some(other_end)) => start < other_end && other_start < end,
+                _ => false,
+            })
+            .map(|other| SessionIssue::new(
+                IssueKind::Overlap,
+                format!(
+                    "Overlaps another session on {} ({} - {})",
+                    other.session_date.format("%Y-%m-%d"),
+                    format_time(other.start_time),
+                    format_time(other.end_time),
+                ),
+                other.id,
+            ))
+            .collect()
+    }
+
+    /// Starting before the end page of the previous session of the same reading and format
+    fn check_page_regression(session: &ReadingSession, others: &[&ReadingSession]) -> Vec<SessionIssue> {
+        let start_page = match session.start_page {
+            Some(page) => page,
+            None => return Vec::new(),
+        };
+
+        let previous = others
+            .iter()
+            .filter(|o| o.book_id == session.book_id
+                && o.reading_id == session.reading_id
+                && o.edition_id == session.edition_id
+                && o.end_page.is_some()
+                && sort_key(o) < sort_key(session))
+            .max_by_key(|o| sort_key(o));
+
+        match previous {
+            Some(previous) if previous.end_page.map(|end| start_page < end).unwrap_or(false) => vec![SessionIssue::new(
+                IssueKind::PageRegression,
+                format!(
+                    "Starts at page {} but the previous session on {} ended at page {}",
+                    start_page,
+                    previous.session_date.format("%Y-%m-%d"),
+                    previous.end_page.unwrap_or(0),
+                ),
+                previous.id,
+            )],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Chronological order of sessions: date, then start time, then when they were logged
+fn sort_key(session: &ReadingSession) -> (NaiveDate, Option<NaiveTime>, chrono::DateTime<chrono::Utc>) {
+    (session.session_date, session.start_time, session.created_at)
+}
+
+fn format_time(time: Option<NaiveTime>) -> String {
+    time.map(|t| t.format("%H:%M").to_string()).unwrap_or_else(|| "?".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domains::timezone::UserTimezone;
+
+    /// A session of book 1 with local times resolved in Berlin
+    fn session(id: i64, day: u32, times: (&str, &str), pages: (i32, i32)) -> ReadingSession {
+        let date = NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+        let mut session = ReadingSession::new(1, date, Some(pages.0), Some(pages.1)).unwrap();
+        session.id = Some(id);
+        session.start_time = parse_session_time("start time", Some(times.0)).unwrap();
+        session.end_time = parse_session_time("end time", Some(times.1)).unwrap();
+        session.resolve_times(&UserTimezone::parse("Europe/Berlin").unwrap()).unwrap();
+        session
+    }
+
+    fn kinds(issues: &[SessionIssue]) -> Vec<IssueKind> {
+        issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn parses_times_with_or_without_seconds() {
+        assert_eq!(parse_session_time("start time", Some("21:05")).unwrap(), NaiveTime::from_hms_opt(21, 5, 0));
+        assert_eq!(parse_session_time("start time", Some(" 21:05:30 ")).unwrap(), NaiveTime::from_hms_opt(21, 5, 30));
+        assert_eq!(parse_session_time("start time", Some("")).unwrap(), None);
+        assert_eq!(parse_session_time("start time", None).unwrap(), None);
+    }
+
+    #[test]
+    fn reports_a_malformed_time_as_an_error() {
+        let issue = parse_session_time("end time", Some("25:00")).unwrap_err();
+        assert_eq!(issue.kind, IssueKind::InvalidTime);
+        assert!(issue.is_error());
+    }
+
+    #[test]
+    fn pages_beyond_the_book_are_an_error() {
+        let issues = SessionValidator::validate(&session(1, 1, ("10:00", "11:00"), (290, 310)), Some(300), &[]);
+        assert_eq!(kinds(&issues), vec![IssueKind::PageOutOfRange]);
+        assert!(issues[0].is_error());
+    }
+
+    #[test]
+    fn warns_about_an_overnight_session_overlapping_the_next_morning() {
+        let night = session(1, 1, ("23:00", "01:00"), (10, 20));
+        let morning = session(2, 2, ("00:30", "01:30"), (20, 30));
+        let issues = SessionValidator::validate(&morning, None, &[night]);
+        assert_eq!(kinds(&issues), vec![IssueKind::Overlap]);
+        assert_eq!(issues[0].conflicting_session_id, Some(1));
+        assert!(!issues[0].is_error());
+    }
+
+    #[test]
+    fn back_to_back_sessions_do_not_overlap() {
+        let first = session(1, 1, ("10:00", "11:00"), (10, 20));
+        let second = session(2, 1, ("11:00", "12:00"), (20, 30));
+        assert!(SessionValidator::validate(&second, None, &[first]).is_empty());
+    }
+
+    #[test]
+    fn warns_when_starting_before_the_previous_end_page() {
+        let earlier = session(1, 1, ("10:00", "11:00"), (10, 50));
+        let later = session(2, 2, ("10:00", "11:00"), (40, 60));
+        let issues = SessionValidator::validate(&later, None, &[earlier]);
+        assert_eq!(kinds(&issues), vec![IssueKind::PageRegression]);
+    }
+
+    #[test]
+    fn skips_the_session_itself_when_validating_an_update() {
+        let saved = session(1, 1, ("10:00", "11:00"), (10, 50));
+        assert!(SessionValidator::validate(&saved, Some(300), std::slice::from_ref(&saved)).is_empty());
+    }
+}
@@ -1,5 +1,8 @@
 use crate::core::domains::book::Book;
+use crate::core::domains::reading::Reading;
+use crate::core::domains::reading_speed::{ReadingSpeed, SpeedUnit};
 use crate::core::domains::session::ReadingSession;
+use chrono::Datelike;
 
 /// Domain service for calculating reading statistics
 pub struct StatisticsCalculator;
@@ -21,20 +24,22 @@ impl StatisticsCalculator {
             .sum()
     }
 
-    /// Calculates average reading speed (pages per hour)
-    pub fn average_reading_speed(sessions: &[ReadingSession]) -> Option<f64> {
-        let total_pages: i32 = Self::total_pages_read(sessions);
-        let total_seconds: i32 = sessions
+    /// Calculates total time spent reading from sessions (seconds)
+    pub fn total_duration_seconds(sessions: &[ReadingSession]) -> i32 {
+        sessions
             .iter()
             .filter_map(|s| s.duration_seconds)
-            .sum();
+            .sum()
+    }
 
-        if total_seconds > 0 {
-            let hours = total_seconds as f64 / 3600.0;
-            Some(total_pages as f64 / hours)
-        } else {
-            None
-        }
+    /// Calculates average reading speed (pages per hour), ignoring outlier sessions
+    pub fn average_reading_speed(sessions: &[ReadingSession]) -> Option<f64> {
+        ReadingSpeed::from_sessions(sessions, SpeedUnit::Pages).map(|speed| speed.units_per_hour)
+    }
+
+    /// Calculates average listening speed (audio minutes per minute), ignoring outlier sessions
+    pub fn average_listening_speed(sessions: &[ReadingSession]) -> Option<f64> {
+        ReadingSpeed::from_sessions(sessions, SpeedUnit::AudioMinutes).map(|speed| speed.units_per_minute())
     }
 
     /// Calculates books completed count
@@ -55,5 +60,19 @@ impl StatisticsCalculator {
         }
         genre_map
     }
+
+    /// Groups ratings by year (completion year, falling back to start/creation year)
+    pub fn ratings_by_year(readings: &[Reading]) -> std::collections::BTreeMap<i32, Vec<f64>> {
+        let mut year_map = std::collections::BTreeMap::new();
+        for reading in readings {
+            if let Some(rating) = reading.rating {
+                let date = reading.completed_at
+                    .or(reading.started_at)
+                    .unwrap_or(reading.created_at);
+                year_map.entry(date.year()).or_insert_with(Vec::new).push(rating);
+            }
+        }
+        year_map
+    }
 }
 
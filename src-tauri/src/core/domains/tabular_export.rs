@@ -0,0 +1,76 @@
+use serde_json::Value;
+use crate::core::domains::csv_format::CsvFormat;
+
+/// Output formats for tabular exports
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableFormat {
+    Csv,
+    Jsonl, // One JSON object per line
+}
+
+/// Writes records (JSON objects) as a table.
+/// Columns are the selected fields in the given order, or every field of the records.
+pub fn write_table(records: &[Value], fields: Option<&[String]>, format: &TableFormat) -> Result<String, String> {
+    let available: Vec<String> = records
+        .iter()
+        .filter_map(|r| r.as_object())
+        .flat_map(|object| object.keys().cloned())
+        .fold(Vec::new(), |mut columns, key| {
+            if !columns.contains(&key) {
+                columns.push(key);
+            }
+            columns
+        });
+
+    let columns: Vec<String> = match fields {
+        Some(selected) if !selected.is_empty() => {
+            if let Some(unknown) = selected.iter().find(|f| !available.contains(f) && !records.is_empty()) {
+                return Err(format!(
+                    "Unknown field '{}'. Available fields: {}",
+                    unknown,
+                    available.join(", ")
+                ));
+            }
+            selected.to_vec()
+        }
+        _ => available,
+    };
+
+    let mut lines = Vec::new();
+    match format {
+        TableFormat::Csv => {
+            lines.push(CsvFormat::write_row(&columns));
+            for record in records {
+                let row: Vec<String> = columns
+                    .iter()
+                    .map(|column| cell_text(record.get(column).unwrap_or(&Value::Null)))
+                    .collect();
+                lines.push(CsvFormat::write_row(&row));
+            }
+        }
+        TableFormat::Jsonl => {
+            for record in records {
+                let object: serde_json::Map<String, Value> = columns
+                    .iter()
+                    .map(|column| (column.clone(), record.get(column).cloned().unwrap_or(Value::Null)))
+                    .collect();
+                lines.push(
+                    serde_json::to_string(&object).map_err(|e| format!("Failed to serialize record: {}", e))?,
+                );
+            }
+        }
+    }
+
+    Ok(if lines.is_empty() { String::new() } else { lines.join("\n") + "\n" })
+}
+
+/// Spreadsheet cell for a JSON value; lists and objects are kept as JSON text
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        other => other.to_string(),
+    }
+}
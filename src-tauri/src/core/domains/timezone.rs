@@ -0,0 +1,194 @@
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Setting holding the user's IANA time zone (e.g. "Europe/Lisbon")
+pub const TIMEZONE_SETTING: &str = "timezone";
+
+/// The time zone used to place sessions on local days
+/// Falls back to the system zone when no zone is configured
+#[derive(Debug, Clone, Copy)]
+pub enum UserTimezone {
+    Named(Tz),
+    System,
+}
+
+impl UserTimezone {
+    /// Parses an IANA zone name; an empty value means the system zone
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Ok(UserTimezone::System);
+        }
+
+        name.parse::<Tz>()
+            .map(UserTimezone::Named)
+            .map_err(|_| format!("Unknown time zone: {}", name))
+    }
+
+    /// IANA name stored with sessions (None for the unnamed system zone)
+    pub fn name(&self) -> Option<String> {
+        match self {
+            UserTimezone::Named(tz) => Some(tz.name().to_string()),
+            UserTimezone::System => None,
+        }
+    }
+
+    /// Converts an instant to local time in this zone
+    pub fn to_local(&self, instant: DateTime<Utc>) -> DateTime<FixedOffset> {
+        match self {
+            UserTimezone::Named(tz) => instant.with_timezone(tz).fixed_offset(),
+            UserTimezone::System => instant.with_timezone(&chrono::Local).fixed_offset(),
+        }
+    }
+
+    /// The local calendar date of an instant
+    pub fn date_of(&self, instant: DateTime<Utc>) -> NaiveDate {
+        self.to_local(instant).date_naive()
+    }
+
+    /// Today's local date
+    pub fn today(&self) -> NaiveDate {
+        self.date_of(Utc::now())
+    }
+
+    /// Resolves a local wall-clock time to an instant
+    /// Ambiguous times (clocks going back) take the earlier instant and
+    /// skipped times (clocks going forward) move past the gap
+    pub fn resolve(&self, date: NaiveDate, time: NaiveTime) -> Result<DateTime<FixedOffset>, String> {
+        let local = NaiveDateTime::new(date, time);
+
+        for shift in 0..=2 {
+            let candidate = local + Duration::minutes(30 * shift);
+            if let Some(resolved) = self.resolve_exact(candidate) {
+                return Ok(resolved);
+            }
+        }
+
+        Err(format!("Local time {} does not exist in this time zone", local))
+    }
+
+    fn resolve_exact(&self, local: NaiveDateTime) -> Option<DateTime<FixedOffset>> {
+        match self {
+            UserTimezone::Named(tz) => first_of(tz.from_local_datetime(&local)).map(|d| d.fixed_offset()),
+            UserTimezone::System => first_of(chrono::Local.from_local_datetime(&local)).map(|d| d.fixed_offset()),
+        }
+    }
+
+    /// Seconds of the span [start, end) that fall on the given local day
+    pub fn seconds_on_day(&self, start: DateTime<Utc>, end: DateTime<Utc>, day: NaiveDate) -> i64 {
+        let day_start = self.day_start(day);
+        let day_end = day.succ_opt().and_then(|next| self.day_start(next));
+
+        match (day_start, day_end) {
+            (Some(day_start), Some(day_end)) => {
+                let from = start.max(day_start);
+                let to = end.min(day_end);
+                (to - from).num_seconds().max(0)
+            }
+            _ => 0,
+        }
+    }
+
+    fn day_start(&self, day: NaiveDate) -> Option<DateTime<Utc>> {
+        self.resolve(day, NaiveTime::MIN).ok().map(|d| d.with_timezone(&Utc))
+    }
+}
+
+fn first_of<T: TimeZone>(result: LocalResult<DateTime<T>>) -> Option<DateTime<T>> {
+    match result {
+        LocalResult::Single(d) => Some(d),
+        LocalResult::Ambiguous(earliest, _) => Some(earliest),
+        LocalResult::None => None,
+    }
+}
+
+/// Resolves a session's local start and end times to instants
+/// An end time before the start time is taken to be on the next day;
+/// equal times are a zero-length session on the same day
+pub fn resolve_span(
+    timezone: &UserTimezone,
+    date: NaiveDate,
+    start: NaiveTime,
+    end: NaiveTime,
+) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>), String> {
+    let started_at = timezone.resolve(date, start)?;
+
+    let end_date = if end < start {
+        date.succ_opt().ok_or("Session end date is out of range".to_string())?
+    } else {
+        date
+    };
+    let ended_at = timezone.resolve(end_date, end)?;
+
+    Ok((started_at, ended_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn berlin() -> UserTimezone {
+        UserTimezone::parse("Europe/Berlin").unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn span_ending_before_its_start_ends_the_next_day() {
+        let (start, end) = resolve_span(&berlin(), date(2024, 1, 1), time(23, 0), time(1, 0)).unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-01-01T23:00:00+01:00");
+        assert_eq!(end.to_rfc3339(), "2024-01-02T01:00:00+01:00");
+    }
+
+    #[test]
+    fn equal_times_are_a_zero_length_span() {
+        let (start, end) = resolve_span(&berlin(), date(2024, 1, 1), time(9, 0), time(9, 0)).unwrap();
+        assert_eq!(start, end);
+    }
+
+    #[test]
+    fn span_across_spring_forward_is_one_hour_shorter() {
+        let (start, end) = resolve_span(&berlin(), date(2024, 3, 31), time(1, 0), time(4, 0)).unwrap();
+        assert_eq!((end - start).num_hours(), 2);
+    }
+
+    #[test]
+    fn skipped_time_moves_past_the_gap() {
+        let resolved = berlin().resolve(date(2024, 3, 31), time(2, 30)).unwrap();
+        assert_eq!(resolved.to_rfc3339(), "2024-03-31T03:00:00+02:00");
+    }
+
+    #[test]
+    fn ambiguous_time_takes_the_earlier_instant() {
+        let resolved = berlin().resolve(date(2024, 10, 27), time(2, 30)).unwrap();
+        assert_eq!(resolved.to_rfc3339(), "2024-10-27T02:30:00+02:00");
+    }
+
+    #[test]
+    fn overnight_span_is_split_between_local_days() {
+        let (start, end) = resolve_span(&berlin(), date(2024, 1, 1), time(23, 0), time(1, 30)).unwrap();
+        let (start, end) = (start.to_utc(), end.to_utc());
+        assert_eq!(berlin().seconds_on_day(start, end, date(2024, 1, 1)), 3600);
+        assert_eq!(berlin().seconds_on_day(start, end, date(2024, 1, 2)), 5400);
+        assert_eq!(berlin().seconds_on_day(start, end, date(2024, 1, 3)), 0);
+    }
+
+    #[test]
+    fn days_are_split_in_the_users_zone_not_utc() {
+        // 00:30 in Berlin is still the previous day in UTC
+        let (start, end) = resolve_span(&berlin(), date(2024, 1, 2), time(0, 30), time(1, 0)).unwrap();
+        assert_eq!(berlin().seconds_on_day(start.to_utc(), end.to_utc(), date(2024, 1, 2)), 1800);
+        assert_eq!(berlin().date_of(start.to_utc()), date(2024, 1, 2));
+    }
+
+    #[test]
+    fn rejects_unknown_zone_names() {
+        assert!(UserTimezone::parse("Mars/Olympus").is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use crate::app::dtos::author_dto::{
+    AuthorDto, AuthorDetailDto, BookContributorDto, CreateAuthorCommand, UpdateAuthorCommand,
+    SetBookContributorsCommand, MergeAuthorsCommand, ListAuthorsFilters,
+};
+
+/// Primary interface for author service operations
+pub trait AuthorService: Send + Sync {
+    fn create(&self, command: CreateAuthorCommand) -> Result<AuthorDto, String>;
+    fn update(&self, command: UpdateAuthorCommand) -> Result<AuthorDto, String>;
+    fn delete(&self, id: i64) -> Result<(), String>;
+    fn get(&self, id: i64) -> Result<AuthorDetailDto, String>;
+    fn list(&self, filters: ListAuthorsFilters) -> Result<Vec<AuthorDto>, String>;
+    fn get_book_contributors(&self, book_id: i64) -> Result<Vec<BookContributorDto>, String>;
+    fn set_book_contributors(&self, command: SetBookContributorsCommand) -> Result<Vec<BookContributorDto>, String>;
+    fn merge(&self, command: MergeAuthorsCommand) -> Result<AuthorDto, String>;
+}
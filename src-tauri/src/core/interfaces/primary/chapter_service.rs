@@ -0,0 +1,8 @@
+use crate::app::dtos::chapter_dto::{ChapterDto, ChapterProgressDto, SetChaptersCommand};
+
+/// Primary interface for audiobook chapter operations
+pub trait ChapterService: Send + Sync {
+    fn list(&self, book_id: i64) -> Result<Vec<ChapterDto>, String>;
+    fn set_chapters(&self, command: SetChaptersCommand) -> Result<Vec<ChapterDto>, String>;
+    fn get_progress(&self, book_id: i64) -> Result<ChapterProgressDto, String>;
+}
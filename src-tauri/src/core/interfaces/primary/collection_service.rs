@@ -1,6 +1,6 @@
 use crate::app::dtos::collection_dto::{
     CollectionDto, CreateCollectionCommand, UpdateCollectionCommand, AddBooksToCollectionCommand,
-    ListCollectionsFilters,
+    ListCollectionsFilters, CollectionStatsDto,
 };
 
 /// Primary interface for collection service operations
@@ -11,5 +11,6 @@ pub trait CollectionService: Send + Sync {
     fn list(&self, filters: ListCollectionsFilters) -> Result<Vec<CollectionDto>, String>;
     fn add_books(&self, command: AddBooksToCollectionCommand) -> Result<(), String>;
     fn remove_book(&self, collection_id: i64, book_id: i64) -> Result<(), String>;
+    fn get_stats(&self, collection_id: i64) -> Result<CollectionStatsDto, String>;
 }
 
@@ -0,0 +1,17 @@
+use crate::app::dtos::cover_dto::{
+    BookCoverDto, CoverBackupDto, CoverImageDto, ImportCoverCommand, RemoteCoversResultDto,
+};
+
+/// Primary interface for locally stored book covers
+pub trait CoverService: Send + Sync {
+    fn import_cover(&self, command: ImportCoverCommand) -> Result<BookCoverDto, String>;
+    /// Stores the image behind every book's cover_url that has no local cover yet
+    fn import_remote_covers(&self) -> Result<RemoteCoversResultDto, String>;
+    fn get_cover_image(&self, book_id: i64, thumbnail: bool) -> Result<Option<CoverImageDto>, String>;
+    fn remove_cover(&self, book_id: i64) -> Result<(), String>;
+    /// Deletes stored images no book refers to; returns how many were removed
+    fn cleanup_orphaned_covers(&self) -> Result<usize, String>;
+    fn backup_covers(&self) -> Result<Vec<CoverBackupDto>, String>;
+    /// Restores covers from a backup for books that exist; returns how many were restored
+    fn restore_covers(&self, covers: Vec<CoverBackupDto>) -> Result<usize, String>;
+}
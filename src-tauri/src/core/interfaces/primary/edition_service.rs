@@ -0,0 +1,12 @@
+use crate::app::dtos::edition_dto::{
+    EditionDto, WorkProgressDto, CreateEditionCommand, UpdateEditionCommand,
+};
+
+/// Primary interface for edition service operations
+pub trait EditionService: Send + Sync {
+    fn create(&self, command: CreateEditionCommand) -> Result<EditionDto, String>;
+    fn update(&self, command: UpdateEditionCommand) -> Result<EditionDto, String>;
+    fn delete(&self, id: i64) -> Result<(), String>;
+    fn list(&self, book_id: i64) -> Result<Vec<EditionDto>, String>;
+    fn get_work_progress(&self, book_id: i64) -> Result<WorkProgressDto, String>;
+}
@@ -0,0 +1,20 @@
+use crate::app::dtos::BookSummaryDto;
+use crate::app::dtos::export_dto::{ExportFileDto, ExportMarkdownCommand, MarkdownExportDto};
+
+/// Primary interface for exporting the library to other formats
+pub trait ExportService: Send + Sync {
+    fn export_library(&self, format: String) -> Result<ExportFileDto, String>;
+    fn export_markdown(&self, command: ExportMarkdownCommand) -> Result<MarkdownExportDto, String>;
+    /// Renders a book's PDF report around its generated summary
+    fn export_book_pdf(&self, book_id: i64, summary: BookSummaryDto) -> Result<ExportFileDto, String>;
+    /// Writes citations of the given books (BibTeX, RIS or CSL-JSON)
+    fn export_citations(&self, format: String, book_ids: Vec<i64>) -> Result<ExportFileDto, String>;
+    /// Writes already-listed records (serialized DTOs) of a dataset as CSV or JSONL
+    fn export_records(
+        &self,
+        dataset: &str,
+        format: String,
+        fields: Option<Vec<String>>,
+        records: Vec<serde_json::Value>,
+    ) -> Result<ExportFileDto, String>;
+}
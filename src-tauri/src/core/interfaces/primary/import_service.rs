@@ -0,0 +1,16 @@
+use crate::app::dtos::import_dto::{
+    ImportLibraryCommand, ImportPreviewDto, ImportResultDto, ImportKindleClippingsCommand,
+    KindleImportResultDto, ImportKoreaderCommand, ImportCalibreCommand, ExternalImportReportDto,
+    ImportCitationsCommand,
+};
+
+/// Primary interface for importing libraries from other services
+pub trait ImportService: Send + Sync {
+    fn preview(&self, command: ImportLibraryCommand) -> Result<ImportPreviewDto, String>;
+    fn import(&self, command: ImportLibraryCommand) -> Result<ImportResultDto, String>;
+    fn import_kindle_clippings(&self, command: ImportKindleClippingsCommand) -> Result<KindleImportResultDto, String>;
+    fn import_koreader(&self, command: ImportKoreaderCommand) -> Result<ExternalImportReportDto, String>;
+    fn import_calibre(&self, command: ImportCalibreCommand) -> Result<ExternalImportReportDto, String>;
+    /// Imports BibTeX or RIS references as Article/Pdf books
+    fn import_citations(&self, command: ImportCitationsCommand) -> Result<ImportResultDto, String>;
+}
@@ -0,0 +1,8 @@
+use crate::app::dtos::metadata_dto::{BookMetadataDto, IsbnDto};
+
+/// Primary interface for ISBN validation and metadata lookup
+pub trait MetadataService: Send + Sync {
+    fn validate_isbn(&self, isbn: String) -> Result<IsbnDto, String>;
+    /// Returns cached metadata, fetching it from the configured provider on a miss (or when `refresh`)
+    fn lookup_isbn(&self, isbn: String, refresh: bool) -> Result<BookMetadataDto, String>;
+}
@@ -1,23 +1,41 @@
 pub mod agenda_service;
+pub mod author_service;
 pub mod book_service;
+pub mod chapter_service;
 pub mod goal_service;
 pub mod session_service;
+pub mod session_photo_service;
 pub mod note_service;
 pub mod journal_service;
 pub mod collection_service;
+pub mod cover_service;
+pub mod edition_service;
+pub mod import_service;
+pub mod export_service;
+pub mod metadata_service;
 pub mod tag_service;
 pub mod reading_service;
+pub mod series_service;
 pub mod settings_service;
 pub mod statistics_service;
 
 pub use agenda_service::AgendaService;
+pub use author_service::AuthorService;
 pub use book_service::BookService;
+pub use chapter_service::ChapterService;
 pub use goal_service::GoalService;
 pub use session_service::SessionService;
+pub use session_photo_service::SessionPhotoService;
 pub use note_service::NoteService;
 pub use journal_service::JournalService;
 pub use collection_service::CollectionService;
+pub use cover_service::CoverService;
+pub use edition_service::EditionService;
+pub use import_service::ImportService;
+pub use export_service::ExportService;
+pub use metadata_service::MetadataService;
 pub use tag_service::TagService;
 pub use reading_service::ReadingService;
+pub use series_service::SeriesService;
 pub use settings_service::SettingsService;
 pub use statistics_service::StatisticsService;
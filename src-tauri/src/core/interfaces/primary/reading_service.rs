@@ -1,4 +1,4 @@
-use crate::app::dtos::reading_dto::{ReadingDto, CreateReadingCommand, ListReadingsFilters};
+use crate::app::dtos::reading_dto::{ReadingDto, CreateReadingCommand, RateReadingCommand, ListReadingsFilters};
 
 /// Primary interface for reading service operations
 pub trait ReadingService: Send + Sync {
@@ -6,5 +6,6 @@ pub trait ReadingService: Send + Sync {
     fn get(&self, id: i64) -> Result<ReadingDto, String>;
     fn get_current(&self, book_id: i64) -> Result<Option<ReadingDto>, String>;
     fn list(&self, filters: ListReadingsFilters) -> Result<Vec<ReadingDto>, String>;
+    fn rate(&self, command: RateReadingCommand) -> Result<ReadingDto, String>;
 }
 
@@ -0,0 +1,14 @@
+use crate::app::dtos::BookDto;
+use crate::app::dtos::series_dto::{
+    SeriesDto, SeriesDetailDto, CreateSeriesCommand, UpdateSeriesCommand, ListSeriesFilters,
+};
+
+/// Primary interface for series service operations
+pub trait SeriesService: Send + Sync {
+    fn create(&self, command: CreateSeriesCommand) -> Result<SeriesDto, String>;
+    fn update(&self, command: UpdateSeriesCommand) -> Result<SeriesDto, String>;
+    fn delete(&self, id: i64) -> Result<(), String>;
+    fn get(&self, id: i64) -> Result<SeriesDetailDto, String>;
+    fn list(&self, filters: ListSeriesFilters) -> Result<Vec<SeriesDto>, String>;
+    fn continue_series(&self, book_id: i64) -> Result<Option<BookDto>, String>;
+}
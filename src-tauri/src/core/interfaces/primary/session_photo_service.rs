@@ -0,0 +1,17 @@
+use crate::app::dtos::session_photo_dto::{
+    AttachSessionPhotoCommand, SessionPhotoBackupDto, SessionPhotoDto, SessionPhotoImageDto,
+};
+
+/// Primary interface for photos attached to reading sessions
+pub trait SessionPhotoService: Send + Sync {
+    fn attach_photo(&self, command: AttachSessionPhotoCommand) -> Result<SessionPhotoDto, String>;
+    fn list_photos(&self, session_id: i64) -> Result<Vec<SessionPhotoDto>, String>;
+    fn get_photo_image(&self, photo_id: i64, thumbnail: bool) -> Result<SessionPhotoImageDto, String>;
+    /// Unlinks a photo and deletes its file when no other session uses it
+    fn delete_photo(&self, photo_id: i64) -> Result<(), String>;
+    /// Deletes stored images no session refers to; returns how many were removed
+    fn cleanup_orphaned_photos(&self) -> Result<usize, String>;
+    fn backup_photos(&self) -> Result<Vec<SessionPhotoBackupDto>, String>;
+    /// Restores photos from a backup for sessions that exist; returns how many were restored
+    fn restore_photos(&self, photos: Vec<SessionPhotoBackupDto>) -> Result<usize, String>;
+}
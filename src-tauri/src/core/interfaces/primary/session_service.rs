@@ -1,4 +1,9 @@
-use crate::app::dtos::session_dto::{SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters};
+use crate::app::dtos::session_dto::{
+    SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters,
+    ActiveSessionDto, StartActiveSessionCommand, FinishActiveSessionCommand, SessionValidationDto,
+    CreateSessionsBulkCommand, ImportSessionsCsvCommand, SessionCsvPreviewDto, BulkSessionsResultDto,
+    CorrectProgressCommand, ProgressCorrectionDto,
+};
 
 /// Primary interface for session service operations
 pub trait SessionService: Send + Sync {
@@ -7,5 +12,26 @@ pub trait SessionService: Send + Sync {
     fn delete(&self, id: i64) -> Result<(), String>;
     fn get(&self, id: i64) -> Result<SessionDto, String>;
     fn list(&self, filters: ListSessionsFilters) -> Result<Vec<SessionDto>, String>;
+
+    // Dry-run consistency checks, so the UI can show warnings before saving
+    fn validate_new(&self, command: CreateSessionCommand) -> Result<SessionValidationDto, String>;
+    fn validate_update(&self, command: UpdateSessionCommand) -> Result<SessionValidationDto, String>;
+
+    // Bulk entry; each affected book's progress is recalculated once at the end
+    fn create_bulk(&self, command: CreateSessionsBulkCommand) -> Result<BulkSessionsResultDto, String>;
+    fn preview_csv_import(&self, command: ImportSessionsCsvCommand) -> Result<SessionCsvPreviewDto, String>;
+    fn import_csv(&self, command: ImportSessionsCsvCommand) -> Result<BulkSessionsResultDto, String>;
+
+    // Progress correction: rewrites the session history to a corrected position and logs the change
+    fn correct_progress(&self, command: CorrectProgressCommand) -> Result<ProgressCorrectionDto, String>;
+    fn list_progress_corrections(&self, book_id: i64) -> Result<Vec<ProgressCorrectionDto>, String>;
+
+    // Session timer (one at a time, kept across restarts)
+    fn start_timer(&self, command: StartActiveSessionCommand) -> Result<ActiveSessionDto, String>;
+    fn pause_timer(&self) -> Result<ActiveSessionDto, String>;
+    fn resume_timer(&self) -> Result<ActiveSessionDto, String>;
+    fn get_active_timer(&self) -> Result<Option<ActiveSessionDto>, String>;
+    fn finish_timer(&self, command: FinishActiveSessionCommand) -> Result<SessionDto, String>;
+    fn cancel_timer(&self) -> Result<(), String>;
 }
 
@@ -1,7 +1,9 @@
-use crate::app::dtos::statistics_dto::StatisticsDto;
+use crate::app::dtos::statistics_dto::{ContextStatisticsDto, ContextStatisticsFilters, StatisticsDto};
 
 /// Primary interface for statistics service operations
 pub trait StatisticsService: Send + Sync {
     fn get(&self) -> Result<StatisticsDto, String>;
+    /// Pages per hour and mood/energy changes by location, device and time of day
+    fn get_context(&self, filters: ContextStatisticsFilters) -> Result<ContextStatisticsDto, String>;
 }
 
@@ -0,0 +1,16 @@
+use crate::core::domains::active_session::ActiveSession;
+
+/// Repository trait for the running session timer (Port/Interface)
+pub trait ActiveSessionRepository: Send + Sync {
+    /// Creates the active session
+    fn create(&self, session: &mut ActiveSession) -> Result<(), String>;
+
+    /// Updates the active session (pause state)
+    fn update(&self, session: &ActiveSession) -> Result<(), String>;
+
+    /// Deletes the active session by ID
+    fn delete(&self, id: i64) -> Result<(), String>;
+
+    /// Finds the active session, if a timer is running
+    fn find_current(&self) -> Result<Option<ActiveSession>, String>;
+}
@@ -0,0 +1,34 @@
+use crate::core::domains::author::{Author, BookContributor, ContributorRole};
+
+/// Repository trait for Author entity (Port/Interface)
+pub trait AuthorRepository: Send + Sync {
+    /// Creates a new author
+    fn create(&self, author: &mut Author) -> Result<(), String>;
+
+    /// Updates an existing author
+    fn update(&self, author: &Author) -> Result<(), String>;
+
+    /// Deletes an author by ID
+    fn delete(&self, id: i64) -> Result<(), String>;
+
+    /// Finds an author by ID
+    fn find_by_id(&self, id: i64) -> Result<Option<Author>, String>;
+
+    /// Finds an author by name (case-insensitive)
+    fn find_by_name(&self, name: &str) -> Result<Option<Author>, String>;
+
+    /// Finds all authors
+    fn find_all(&self) -> Result<Vec<Author>, String>;
+
+    /// Finds the contributors of a book, in display order
+    fn find_contributors_by_book_id(&self, book_id: i64) -> Result<Vec<BookContributor>, String>;
+
+    /// Finds every book link (with role) for an author
+    fn find_contributions_by_author_id(&self, author_id: i64) -> Result<Vec<BookContributor>, String>;
+
+    /// Replaces all contributors of a book (list order becomes display order)
+    fn set_book_contributors(&self, book_id: i64, contributors: &[(i64, ContributorRole)]) -> Result<(), String>;
+
+    /// Moves all book links from one author to another and deletes the source author
+    fn merge(&self, source_id: i64, target_id: i64) -> Result<(), String>;
+}
@@ -0,0 +1,16 @@
+use crate::core::domains::book_cover::BookCover;
+
+/// Repository trait for local book covers (Port/Interface)
+pub trait BookCoverRepository: Send + Sync {
+    /// Finds the local cover of a book
+    fn find_by_book_id(&self, book_id: i64) -> Result<Option<BookCover>, String>;
+
+    /// Finds every local cover
+    fn find_all(&self) -> Result<Vec<BookCover>, String>;
+
+    /// Links a cover to a book, replacing its previous cover
+    fn save(&self, cover: &BookCover) -> Result<(), String>;
+
+    /// Unlinks the cover of a book (the image file is removed by orphan cleanup)
+    fn delete_by_book_id(&self, book_id: i64) -> Result<(), String>;
+}
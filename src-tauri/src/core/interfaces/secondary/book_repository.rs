@@ -23,6 +23,9 @@ pub trait BookRepository: Send + Sync {
     /// Finds books by type
     fn find_by_type(&self, book_type: BookType) -> Result<Vec<Book>, String>;
 
+    /// Finds books belonging to a series, ordered by volume number
+    fn find_by_series_id(&self, series_id: i64) -> Result<Vec<Book>, String>;
+
     /// Finds books with filters
     fn find_with_filters(
         &self,
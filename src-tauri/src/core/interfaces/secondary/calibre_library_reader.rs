@@ -0,0 +1,7 @@
+use crate::core::domains::calibre_library::CalibreBook;
+
+/// Reads books from an external Calibre library (Port/Interface)
+pub trait CalibreLibraryReader: Send + Sync {
+    /// Reads every book from a Calibre metadata.db (or the library folder containing it)
+    fn read_books(&self, library_path: &str) -> Result<Vec<CalibreBook>, String>;
+}
@@ -0,0 +1,10 @@
+use crate::core::domains::audiobook_chapter::AudiobookChapter;
+
+/// Repository trait for AudiobookChapter entity (Port/Interface)
+pub trait ChapterRepository: Send + Sync {
+    /// Finds the chapters of a book in order
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<AudiobookChapter>, String>;
+
+    /// Replaces all chapters of a book and sets their IDs
+    fn replace_for_book(&self, book_id: i64, chapters: &mut [AudiobookChapter]) -> Result<(), String>;
+}
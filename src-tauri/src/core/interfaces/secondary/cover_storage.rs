@@ -0,0 +1,20 @@
+use crate::core::domains::book_cover::StoredImage;
+
+/// Stores cover images and their thumbnails in the app's covers folder (Port/Interface)
+pub trait CoverStorage: Send + Sync {
+    /// Validates and stores an image (skipped when the same content is already stored)
+    /// and makes sure its thumbnail exists
+    fn store(&self, bytes: &[u8]) -> Result<StoredImage, String>;
+
+    /// Reads an image from outside the covers folder, e.g. a file picked by the user
+    fn read_external(&self, path: &str) -> Result<Vec<u8>, String>;
+
+    /// Reads a stored file by name
+    fn read(&self, file_name: &str) -> Result<Vec<u8>, String>;
+
+    /// Hashes of all stored images
+    fn list_hashes(&self) -> Result<Vec<String>, String>;
+
+    /// Deletes an image and its thumbnail
+    fn delete(&self, hash: &str) -> Result<(), String>;
+}
@@ -0,0 +1,19 @@
+use crate::core::domains::edition::Edition;
+
+/// Repository trait for Edition entity (Port/Interface)
+pub trait EditionRepository: Send + Sync {
+    /// Creates a new edition
+    fn create(&self, edition: &mut Edition) -> Result<(), String>;
+
+    /// Updates an existing edition
+    fn update(&self, edition: &Edition) -> Result<(), String>;
+
+    /// Deletes an edition by ID
+    fn delete(&self, id: i64) -> Result<(), String>;
+
+    /// Finds an edition by ID
+    fn find_by_id(&self, id: i64) -> Result<Option<Edition>, String>;
+
+    /// Finds all editions of a book
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<Edition>, String>;
+}
@@ -0,0 +1,7 @@
+use crate::core::domains::library_export::ExportedFile;
+
+/// Writes exported files to disk (Port/Interface)
+pub trait ExportFileWriter: Send + Sync {
+    /// Writes files under the target directory, creating sub-folders as needed
+    fn write_files(&self, target_dir: &str, files: &[ExportedFile]) -> Result<(), String>;
+}
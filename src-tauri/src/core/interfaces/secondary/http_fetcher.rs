@@ -0,0 +1,5 @@
+/// Downloads files over HTTP(S) (Port/Interface)
+pub trait HttpFetcher: Send + Sync {
+    /// Fetches the body of a URL, failing when it is larger than `max_bytes`
+    fn get(&self, url: &str, max_bytes: u64) -> Result<Vec<u8>, String>;
+}
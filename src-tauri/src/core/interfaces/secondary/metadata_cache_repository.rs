@@ -0,0 +1,10 @@
+use crate::core::domains::book_metadata::BookMetadata;
+
+/// Repository trait for cached ISBN metadata (Port/Interface)
+pub trait MetadataCacheRepository: Send + Sync {
+    /// Finds cached metadata by ISBN-13
+    fn find_by_isbn(&self, isbn: &str) -> Result<Option<BookMetadata>, String>;
+
+    /// Stores metadata, replacing any cached entry for the same ISBN
+    fn save(&self, metadata: &BookMetadata) -> Result<(), String>;
+}
@@ -0,0 +1,7 @@
+use crate::core::domains::book_metadata::BookMetadata;
+
+/// Fetches book metadata from an external catalogue (Port/Interface)
+pub trait MetadataProvider: Send + Sync {
+    /// Looks up an ISBN-13 at the service under `base_url`; Ok(None) when the ISBN is unknown
+    fn fetch_by_isbn(&self, base_url: &str, isbn: &str) -> Result<Option<BookMetadata>, String>;
+}
@@ -1,24 +1,56 @@
 // Secondary interfaces for repositories
+pub mod active_session_repository;
 pub mod agenda_repository;
+pub mod author_repository;
 pub mod backup_repository;
+pub mod book_cover_repository;
 pub mod book_repository;
+pub mod calibre_library_reader;
+pub mod chapter_repository;
 pub mod collection_repository;
+pub mod cover_storage;
+pub mod edition_repository;
+pub mod export_file_writer;
 pub mod goal_repository;
+pub mod http_fetcher;
 pub mod journal_repository;
+pub mod metadata_cache_repository;
+pub mod metadata_provider;
 pub mod note_repository;
+pub mod photo_storage;
+pub mod progress_correction_repository;
 pub mod reading_repository;
+pub mod series_repository;
+pub mod session_photo_repository;
 pub mod session_repository;
 pub mod settings_repository;
 pub mod tag_repository;
+pub mod transaction_manager;
 
+pub use active_session_repository::*;
 pub use agenda_repository::*;
+pub use author_repository::*;
 pub use backup_repository::*;
+pub use book_cover_repository::*;
 pub use book_repository::*;
+pub use calibre_library_reader::*;
+pub use chapter_repository::*;
 pub use collection_repository::*;
+pub use cover_storage::*;
+pub use edition_repository::*;
+pub use export_file_writer::*;
 pub use goal_repository::*;
+pub use http_fetcher::*;
 pub use journal_repository::*;
+pub use metadata_cache_repository::*;
+pub use metadata_provider::*;
 pub use note_repository::*;
+pub use photo_storage::*;
+pub use progress_correction_repository::*;
 pub use reading_repository::*;
+pub use series_repository::*;
+pub use session_photo_repository::*;
 pub use session_repository::*;
 pub use settings_repository::*;
 pub use tag_repository::*;
+pub use transaction_manager::*;
@@ -0,0 +1,18 @@
+use crate::core::domains::progress_correction::ProgressCorrection;
+use crate::core::domains::session::ReadingSession;
+
+/// Repository trait for the progress correction audit log (Port/Interface)
+pub trait ProgressCorrectionRepository: Send + Sync {
+    /// Saves a correction's adjustment session and rewritten sessions, and records the
+    /// correction, in one transaction. The adjustment's ID is set on it, on the
+    /// correction and on the changes that describe it.
+    fn create(
+        &self,
+        correction: &mut ProgressCorrection,
+        adjustment: Option<&mut ReadingSession>,
+        updated: &[ReadingSession],
+    ) -> Result<(), String>;
+
+    /// Finds the corrections of a book, most recent first
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<ProgressCorrection>, String>;
+}
@@ -0,0 +1,22 @@
+use crate::core::domains::series::Series;
+
+/// Repository trait for Series entity (Port/Interface)
+pub trait SeriesRepository: Send + Sync {
+    /// Creates a new series
+    fn create(&self, series: &mut Series) -> Result<(), String>;
+
+    /// Updates an existing series
+    fn update(&self, series: &Series) -> Result<(), String>;
+
+    /// Deletes a series by ID (books keep existing, detached from the series)
+    fn delete(&self, id: i64) -> Result<(), String>;
+
+    /// Finds a series by ID
+    fn find_by_id(&self, id: i64) -> Result<Option<Series>, String>;
+
+    /// Finds a series by name
+    fn find_by_name(&self, name: &str) -> Result<Option<Series>, String>;
+
+    /// Finds all series
+    fn find_all(&self) -> Result<Vec<Series>, String>;
+}
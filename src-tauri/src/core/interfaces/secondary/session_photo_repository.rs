@@ -0,0 +1,20 @@
+use crate::core::domains::session_photo::SessionPhoto;
+
+/// Repository trait for photos linked to reading sessions (Port/Interface)
+pub trait SessionPhotoRepository: Send + Sync {
+    /// Links a stored photo to a session; if the session already has the same image,
+    /// the existing link is kept and loaded into `photo`
+    fn create(&self, photo: &mut SessionPhoto) -> Result<(), String>;
+
+    /// Finds a photo by ID
+    fn find_by_id(&self, id: i64) -> Result<Option<SessionPhoto>, String>;
+
+    /// Finds the photos of a session, oldest first
+    fn find_by_session_id(&self, session_id: i64) -> Result<Vec<SessionPhoto>, String>;
+
+    /// Finds every session photo
+    fn find_all(&self) -> Result<Vec<SessionPhoto>, String>;
+
+    /// Unlinks a photo (the image file is removed by the service when unused)
+    fn delete(&self, id: i64) -> Result<(), String>;
+}
@@ -1,3 +1,4 @@
+use crate::core::domains::reading_speed::SpeedSample;
 use crate::core::domains::session::ReadingSession;
 
 /// Repository trait for ReadingSession entity (Port/Interface)
@@ -5,9 +6,15 @@ pub trait SessionRepository: Send + Sync {
     /// Creates a new session
     fn create(&self, session: &mut ReadingSession) -> Result<(), String>;
 
+    /// Creates several sessions in one transaction (all or none are saved)
+    fn create_many(&self, sessions: &mut [ReadingSession]) -> Result<(), String>;
+
     /// Updates an existing session
     fn update(&self, session: &ReadingSession) -> Result<(), String>;
 
+    /// Updates several sessions in one transaction (all or none are saved)
+    fn update_many(&self, sessions: &[ReadingSession]) -> Result<(), String>;
+
     /// Deletes a session by ID
     fn delete(&self, id: i64) -> Result<(), String>;
 
@@ -17,6 +24,10 @@ pub trait SessionRepository: Send + Sync {
     /// Finds all sessions
     fn find_all(&self) -> Result<Vec<ReadingSession>, String>;
 
+    /// Progress and duration of every timed session, in its book's unit
+    /// Sessions logged against another edition are left out
+    fn find_speed_samples(&self) -> Result<Vec<SpeedSample>, String>;
+
     /// Finds sessions by book ID
     fn find_by_book_id(&self, book_id: i64) -> Result<Vec<ReadingSession>, String>;
 
@@ -0,0 +1,6 @@
+/// Groups repository writes into one database transaction (Port/Interface)
+pub trait TransactionManager: Send + Sync {
+    /// Runs `work`, committing its writes if it returns Ok and rolling all of them back otherwise.
+    /// Calls may nest; an inner call only rolls back its own writes.
+    fn run(&self, work: &mut dyn FnMut() -> Result<(), String>) -> Result<(), String>;
+}
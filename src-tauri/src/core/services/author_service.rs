@@ -0,0 +1,268 @@
+use crate::app::dtos::BookDto;
+use crate::app::dtos::author_dto::{
+    AuthorDto, AuthorDetailDto, AuthorBookDto, AuthorStatsDto, BookContributorDto,
+    CreateAuthorCommand, UpdateAuthorCommand, SetBookContributorsCommand, MergeAuthorsCommand,
+    ListAuthorsFilters, contributor_role_to_string, string_to_contributor_role,
+};
+use crate::core::domains::author::{Author, ContributorRole};
+use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::statistics_calculator::StatisticsCalculator;
+use crate::core::interfaces::primary::AuthorService;
+use crate::core::interfaces::secondary::{AuthorRepository, BookRepository, SessionRepository};
+
+/// Implementation of AuthorService
+pub struct AuthorServiceImpl<'a> {
+    author_repository: &'a dyn AuthorRepository,
+    book_repository: &'a dyn BookRepository,
+    session_repository: &'a dyn SessionRepository,
+}
+
+impl<'a> AuthorServiceImpl<'a> {
+    pub fn new(
+        author_repository: &'a dyn AuthorRepository,
+        book_repository: &'a dyn BookRepository,
+        session_repository: &'a dyn SessionRepository,
+    ) -> Self {
+        AuthorServiceImpl {
+            author_repository,
+            book_repository,
+            session_repository,
+        }
+    }
+
+    /// Rewrites books.author from the book's 'author' role contributors
+    fn sync_book_author_field(&self, book_id: i64) -> Result<(), String> {
+        let mut book = match self.book_repository.find_by_id(book_id)? {
+            Some(book) => book,
+            None => return Ok(()),
+        };
+
+        let names: Vec<String> = self.author_repository
+            .find_contributors_by_book_id(book_id)?
+            .into_iter()
+            .filter(|c| c.role == ContributorRole::Author)
+            .map(|c| c.author.name)
+            .collect();
+
+        let author = Author::join_names(&names);
+        if book.author != author {
+            book.author = author;
+            book.updated_at = chrono::Utc::now();
+            self.book_repository.update(&book)?;
+        }
+
+        Ok(())
+    }
+
+    /// Aggregates progress and time spent across the given books
+    fn calculate_stats(&self, books: &[Book]) -> Result<AuthorStatsDto, String> {
+        let mut sessions = Vec::new();
+        for book in books {
+            if let Some(book_id) = book.id {
+                sessions.extend(self.session_repository.find_by_book_id(book_id)?);
+            }
+        }
+
+        let mut pages_read = 0;
+        let mut minutes_listened = 0;
+        for book in books {
+            let is_completed = book.status == BookStatus::Completed;
+            match book.book_type {
+                BookType::Audiobook => {
+                    minutes_listened += if is_completed {
+                        book.total_minutes.unwrap_or(0)
+                    } else {
+                        book.current_minutes_audio
+                    };
+                }
+                _ => {
+                    pages_read += if is_completed {
+                        book.total_pages.unwrap_or(0)
+                    } else {
+                        book.current_page_text
+                    };
+                }
+            }
+        }
+
+        Ok(AuthorStatsDto {
+            total_books: books.len() as i32,
+            completed_books: StatisticsCalculator::books_completed(books) as i32,
+            pages_read,
+            minutes_listened,
+            time_spent_seconds: StatisticsCalculator::total_duration_seconds(&sessions),
+        })
+    }
+}
+
+impl<'a> AuthorService for AuthorServiceImpl<'a> {
+    fn create(&self, command: CreateAuthorCommand) -> Result<AuthorDto, String> {
+        let mut author = Author::new(command.name)?;
+
+        // De-duplicate: names are compared ignoring case and extra whitespace
+        if let Some(existing) = self.author_repository.find_by_name(&author.name)? {
+            return Err(format!("Author '{}' already exists", existing.name));
+        }
+
+        self.author_repository.create(&mut author)?;
+
+        Ok(AuthorDto::from(author))
+    }
+
+    fn update(&self, command: UpdateAuthorCommand) -> Result<AuthorDto, String> {
+        let mut author = self.author_repository
+            .find_by_id(command.id)?
+            .ok_or_else(|| format!("Author with id {} not found", command.id))?;
+
+        if let Some(name) = command.name {
+            author.update_name(name)?;
+
+            if let Some(existing) = self.author_repository.find_by_name(&author.name)? {
+                if existing.id != author.id {
+                    return Err(format!(
+                        "Author '{}' already exists; merge the two authors instead",
+                        existing.name
+                    ));
+                }
+            }
+        }
+
+        self.author_repository.update(&author)?;
+
+        // Keep the display string of every linked book in step with the new name
+        for contribution in self.author_repository.find_contributions_by_author_id(command.id)? {
+            if contribution.role == ContributorRole::Author {
+                self.sync_book_author_field(contribution.book_id)?;
+            }
+        }
+
+        Ok(AuthorDto::from(author))
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        self.author_repository
+            .find_by_id(id)?
+            .ok_or_else(|| format!("Author with id {} not found", id))?;
+
+        let contributions = self.author_repository.find_contributions_by_author_id(id)?;
+        if !contributions.is_empty() {
+            return Err(format!(
+                "Author is linked to {} book(s); remove or merge them first",
+                contributions.len()
+            ));
+        }
+
+        self.author_repository.delete(id)?;
+
+        Ok(())
+    }
+
+    fn get(&self, id: i64) -> Result<AuthorDetailDto, String> {
+        let author = self.author_repository
+            .find_by_id(id)?
+            .ok_or_else(|| format!("Author with id {} not found", id))?;
+
+        // Group roles per book (an author can also be the narrator of their own book)
+        let mut book_roles: Vec<(i64, Vec<String>)> = Vec::new();
+        for contribution in self.author_repository.find_contributions_by_author_id(id)? {
+            let role = contributor_role_to_string(&contribution.role);
+            match book_roles.iter_mut().find(|(book_id, _)| *book_id == contribution.book_id) {
+                Some((_, roles)) => roles.push(role),
+                None => book_roles.push((contribution.book_id, vec![role])),
+            }
+        }
+
+        let mut books = Vec::new();
+        let mut entries = Vec::new();
+        for (book_id, roles) in book_roles {
+            if let Some(book) = self.book_repository.find_by_id(book_id)? {
+                books.push(book.clone());
+                entries.push(AuthorBookDto {
+                    book: BookDto::from(book),
+                    roles,
+                });
+            }
+        }
+
+        let stats = self.calculate_stats(&books)?;
+
+        Ok(AuthorDetailDto {
+            author: AuthorDto::from(author),
+            books: entries,
+            stats,
+        })
+    }
+
+    fn list(&self, _filters: ListAuthorsFilters) -> Result<Vec<AuthorDto>, String> {
+        let authors = self.author_repository.find_all()?;
+        Ok(authors.into_iter().map(AuthorDto::from).collect())
+    }
+
+    fn get_book_contributors(&self, book_id: i64) -> Result<Vec<BookContributorDto>, String> {
+        let contributors = self.author_repository.find_contributors_by_book_id(book_id)?;
+        Ok(contributors.into_iter().map(BookContributorDto::from).collect())
+    }
+
+    fn set_book_contributors(&self, command: SetBookContributorsCommand) -> Result<Vec<BookContributorDto>, String> {
+        let book = self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+
+        let mut contributors: Vec<(i64, ContributorRole)> = Vec::new();
+        for input in command.contributors {
+            let role = string_to_contributor_role(&input.role)?;
+            if !role.is_valid_for(&book.book_type) {
+                return Err("Narrators can only be added to audiobooks".to_string());
+            }
+
+            let author_id = find_or_create_author(self.author_repository, &input.name)?;
+            if !contributors.iter().any(|(id, r)| *id == author_id && *r == role) {
+                contributors.push((author_id, role));
+            }
+        }
+
+        self.author_repository.set_book_contributors(command.book_id, &contributors)?;
+        self.sync_book_author_field(command.book_id)?;
+
+        self.get_book_contributors(command.book_id)
+    }
+
+    fn merge(&self, command: MergeAuthorsCommand) -> Result<AuthorDto, String> {
+        if command.source_id == command.target_id {
+            return Err("Cannot merge an author into itself".to_string());
+        }
+
+        self.author_repository
+            .find_by_id(command.source_id)?
+            .ok_or_else(|| format!("Author with id {} not found", command.source_id))?;
+        let target = self.author_repository
+            .find_by_id(command.target_id)?
+            .ok_or_else(|| format!("Author with id {} not found", command.target_id))?;
+
+        let affected_books: Vec<i64> = self.author_repository
+            .find_contributions_by_author_id(command.source_id)?
+            .into_iter()
+            .map(|c| c.book_id)
+            .collect();
+
+        self.author_repository.merge(command.source_id, command.target_id)?;
+
+        for book_id in affected_books {
+            self.sync_book_author_field(book_id)?;
+        }
+
+        Ok(AuthorDto::from(target))
+    }
+}
+
+/// Returns the ID of the author with this name, creating the author if needed
+pub(crate) fn find_or_create_author(author_repository: &dyn AuthorRepository, name: &str) -> Result<i64, String> {
+    let mut author = Author::new(name.to_string())?;
+
+    if let Some(existing) = author_repository.find_by_name(&author.name)? {
+        return existing.id.ok_or("Author ID missing".to_string());
+    }
+
+    author_repository.create(&mut author)?;
+    author.id.ok_or("Author ID missing after insert".to_string())
+}
@@ -1,26 +1,100 @@
-use crate::app::dtos::{BookDto, CreateBookCommand, UpdateBookCommand, BookSummaryDto, ListBooksFilters};
+use crate::app::dtos::{BookDto, CreateBookCommand, UpdateBookCommand, BookSummaryDto, ListBooksFilters, FinishPredictionDto};
 use crate::core::domains::book::{Book, BookStatus, BookType};
 use crate::core::interfaces::primary::BookService;
-use crate::core::interfaces::secondary::{BookRepository, NoteRepository};
+use crate::core::interfaces::secondary::{
+    BookRepository, NoteRepository, AuthorRepository, SessionRepository, SettingsRepository,
+    SessionPhotoRepository, PhotoStorage, BookCoverRepository, CoverStorage,
+};
+use crate::core::domains::author::{Author, ContributorRole};
 use crate::core::domains::note::Note;
+use crate::core::domains::progress_unit::{ProgressUnit, PROGRESS_UNIT_SETTING};
+use crate::core::domains::reading_speed::SpeedModel;
+use crate::core::domains::session::ReadingSession;
+use crate::core::services::author_service::find_or_create_author;
+use crate::core::services::cover_service::delete_unused_cover_file;
+use crate::core::services::series_service::next_in_series;
+use crate::core::services::session_photo_service::delete_unused_photo_files;
+use crate::core::services::session_service::user_timezone_setting;
 use std::collections::HashMap;
 
 /// Implementation of BookService
 pub struct BookServiceImpl<'a> {
     book_repository: &'a dyn BookRepository,
     note_repository: &'a dyn NoteRepository,
+    author_repository: &'a dyn AuthorRepository,
+    session_repository: &'a dyn SessionRepository,
+    settings_repository: &'a dyn SettingsRepository,
+    photo_repository: &'a dyn SessionPhotoRepository,
+    photo_storage: &'a dyn PhotoStorage,
+    cover_repository: &'a dyn BookCoverRepository,
+    cover_storage: &'a dyn CoverStorage,
 }
 
 impl<'a> BookServiceImpl<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         book_repository: &'a dyn BookRepository,
         note_repository: &'a dyn NoteRepository,
+        author_repository: &'a dyn AuthorRepository,
+        session_repository: &'a dyn SessionRepository,
+        settings_repository: &'a dyn SettingsRepository,
+        photo_repository: &'a dyn SessionPhotoRepository,
+        photo_storage: &'a dyn PhotoStorage,
+        cover_repository: &'a dyn BookCoverRepository,
+        cover_storage: &'a dyn CoverStorage,
     ) -> Self {
         BookServiceImpl {
             book_repository,
             note_repository,
+            author_repository,
+            session_repository,
+            settings_repository,
+            photo_repository,
+            photo_storage,
+            cover_repository,
+            cover_storage,
         }
     }
+
+    /// Converts books to DTOs with time-remaining and finish-date predictions
+    /// `sessions` need only cover the given books; speeds are measured across
+    /// the whole library so books with few sessions can fall back to the global speed
+    fn with_predictions(&self, books: Vec<Book>, sessions: Vec<ReadingSession>) -> Result<Vec<BookDto>, String> {
+        let today = user_timezone_setting(self.settings_repository)?.today();
+        let model = SpeedModel::build(&self.session_repository.find_speed_samples()?, sessions);
+
+        Ok(books
+            .into_iter()
+            .map(|book| {
+                let prediction = model.predict(&book, today);
+                let mut dto = BookDto::from(book);
+                dto.prediction = prediction.map(FinishPredictionDto::from);
+                dto
+            })
+            .collect())
+    }
+
+    /// Links the free-text author field to the authors table, one author per name
+    /// Replaces the book's 'author' role contributors; translators, editors and narrators are kept
+    fn link_author(&self, book_id: i64, author: Option<&str>) -> Result<(), String> {
+        let mut contributors: Vec<(i64, ContributorRole)> = Vec::new();
+        for name in Author::split_names(author.unwrap_or_default()) {
+            let author_id = find_or_create_author(self.author_repository, &name)?;
+            if !contributors.iter().any(|(id, _)| *id == author_id) {
+                contributors.push((author_id, ContributorRole::Author));
+            }
+        }
+
+        contributors.extend(
+            self.author_repository
+                .find_contributors_by_book_id(book_id)?
+                .into_iter()
+                .filter(|c| c.role != ContributorRole::Author)
+                .filter_map(|c| c.author.id.map(|id| (id, c.role))),
+        );
+
+        self.author_repository.set_book_contributors(book_id, &contributors)
+    }
 }
 
 impl<'a> BookService for BookServiceImpl<'a> {
@@ -51,6 +125,15 @@ impl<'a> BookService for BookServiceImpl<'a> {
         book.publication_year = command.publication_year;
         book.cover_url = command.cover_url;
         book.url = command.url;
+        book.update_series(command.series_id, command.series_index)?;
+
+        // Text books follow the default progress unit unless one is given
+        let progress_unit = match (command.progress_unit.as_deref(), &book.book_type) {
+            (Some(unit), _) => ProgressUnit::parse(unit)?,
+            (None, BookType::Audiobook) => ProgressUnit::Page,
+            (None, _) => default_progress_unit_setting(self.settings_repository)?,
+        };
+        book.update_progress_unit(progress_unit, command.total_locations)?;
 
         // Validate
         book.validate_current_page()?;
@@ -58,6 +141,10 @@ impl<'a> BookService for BookServiceImpl<'a> {
         // Save via repository
         self.book_repository.create(&mut book)?;
 
+        if let Some(book_id) = book.id {
+            self.link_author(book_id, book.author.as_deref())?;
+        }
+
         // Convert to DTO and return
         Ok(BookDto::from(book))
     }
@@ -67,6 +154,7 @@ impl<'a> BookService for BookServiceImpl<'a> {
         let mut book = self.book_repository
             .find_by_id(command.id)?
             .ok_or_else(|| format!("Book with id {} not found", command.id))?;
+        let was_completed = book.status == BookStatus::Completed;
 
         // Update fields if provided
         if let Some(title) = command.title {
@@ -76,6 +164,9 @@ impl<'a> BookService for BookServiceImpl<'a> {
             book.title = title;
         }
 
+        // Only relink when the text actually changed, so multi-author books
+        // (whose display string is "A, B") are not collapsed into one author
+        let author_changed = command.author.is_some() && command.author != book.author;
         if let Some(author) = command.author {
             book.author = Some(author);
         }
@@ -86,6 +177,17 @@ impl<'a> BookService for BookServiceImpl<'a> {
 
         if let Some(book_type_str) = command.book_type {
             book.book_type = string_to_book_type(&book_type_str)?;
+            if book.book_type == BookType::Audiobook {
+                book.progress_unit = ProgressUnit::Page;
+            }
+        }
+
+        if command.progress_unit.is_some() || command.total_locations.is_some() {
+            let progress_unit = match command.progress_unit.as_deref() {
+                Some(unit) => ProgressUnit::parse(unit)?,
+                None => book.progress_unit,
+            };
+            book.update_progress_unit(progress_unit, command.total_locations.or(book.total_locations))?;
         }
 
         if let Some(isbn) = command.isbn {
@@ -178,6 +280,16 @@ impl<'a> BookService for BookServiceImpl<'a> {
             book.url = Some(url);
         }
 
+        if command.series_id.is_some() || command.series_index.is_some() {
+            let series_id = command.series_id.unwrap_or(book.series_id);
+            // Leaving a series drops the volume number too
+            let series_index = match series_id {
+                Some(_) => command.series_index.unwrap_or(book.series_index),
+                None => None,
+            };
+            book.update_series(series_id, series_index)?;
+        }
+
         // Update timestamp
         book.updated_at = chrono::Utc::now();
 
@@ -187,8 +299,21 @@ impl<'a> BookService for BookServiceImpl<'a> {
         // Save via repository
         self.book_repository.update(&book)?;
 
+        if author_changed {
+            self.link_author(command.id, book.author.as_deref())?;
+        }
+
+        // Suggest the next volume when this update completes a book of a series
+        let next = if !was_completed && book.status == BookStatus::Completed {
+            next_in_series(self.book_repository, &book)?
+        } else {
+            None
+        };
+
         // Convert to DTO and return
-        Ok(BookDto::from(book))
+        let mut dto = BookDto::from(book);
+        dto.next_in_series = next.map(|next| Box::new(BookDto::from(next)));
+        Ok(dto)
     }
 
     fn delete(&self, id: i64) -> Result<(), String> {
@@ -197,8 +322,23 @@ impl<'a> BookService for BookServiceImpl<'a> {
             .find_by_id(id)?
             .ok_or_else(|| format!("Book with id {} not found", id))?;
 
+        // Photos of the book's sessions, whose files go once the links are deleted
+        let mut photos = Vec::new();
+        for session in self.session_repository.find_by_book_id(id)? {
+            if let Some(session_id) = session.id {
+                photos.extend(self.photo_repository.find_by_session_id(session_id)?);
+            }
+        }
+
+        // The cover link goes with the book; its image stays if another book shares it
+        let cover = self.cover_repository.find_by_book_id(id)?;
+
         // Delete via repository (cascade deletes should be handled by database)
         self.book_repository.delete(id)?;
+        delete_unused_photo_files(self.photo_repository, self.photo_storage, &photos)?;
+        if let Some(cover) = cover {
+            delete_unused_cover_file(self.cover_repository, self.cover_storage, &cover.hash)?;
+        }
 
         Ok(())
     }
@@ -208,7 +348,10 @@ impl<'a> BookService for BookServiceImpl<'a> {
             .find_by_id(id)?
             .ok_or_else(|| format!("Book with id {} not found", id))?;
 
-        Ok(BookDto::from(book))
+        let sessions = self.session_repository.find_by_book_id(id)?;
+        self.with_predictions(vec![book], sessions)?
+            .pop()
+            .ok_or_else(|| format!("Book with id {} not found", id))
     }
 
     fn list(&self, filters: ListBooksFilters) -> Result<Vec<BookDto>, String> {
@@ -255,7 +398,7 @@ impl<'a> BookService for BookServiceImpl<'a> {
         eprintln!("[BookService] Calling find_with_filters with: status={:?}, book_type={:?}, is_archived={:?}, is_wishlist={:?}, collection_id={:?}",
                   status_enum, book_type_enum, filters.is_archived, filters.is_wishlist, filters.collection_id);
 
-        let books = self.book_repository.find_with_filters(
+        let mut books = self.book_repository.find_with_filters(
             status_enum,
             book_type_enum,
             filters.is_archived,
@@ -263,9 +406,28 @@ impl<'a> BookService for BookServiceImpl<'a> {
             filters.collection_id,
         )?;
 
+        if let Some(min_rating) = filters.min_rating {
+            books.retain(|b| b.average_rating.map(|r| r >= min_rating).unwrap_or(false));
+        }
+
+        match filters.sort_by.as_deref() {
+            Some("rating") => {
+                // Highest rated first; unrated books go last (repository order is kept for ties)
+                books.sort_by(|a, b| {
+                    b.average_rating
+                        .unwrap_or(0.0)
+                        .partial_cmp(&a.average_rating.unwrap_or(0.0))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                });
+            }
+            Some("added_at") | None => {}
+            Some(other) => return Err(format!("Invalid sort field: {}", other)),
+        }
+
         eprintln!("[BookService] Found {} books from repository", books.len());
 
-        Ok(books.into_iter().map(BookDto::from).collect())
+        let sessions = self.session_repository.find_all()?;
+        self.with_predictions(books, sessions)
     }
 
     fn generate_summary(&self, book_id: i64) -> Result<BookSummaryDto, String> {
@@ -340,7 +502,7 @@ fn extract_themes(notes: &[Note]) -> Vec<String> {
     
     for note in notes {
         all_text.push_str(&note.content);
-        all_text.push_str(" ");
+        all_text.push(' ');
     }
 
     // Simple word frequency (filter out common words)
@@ -368,7 +530,7 @@ fn extract_themes(notes: &[Note]) -> Vec<String> {
     let words: Vec<String> = lowercase_text
         .split_whitespace()
         .filter(|w| w.len() > 3) // Filter short words
-        .filter(|w| !stop_words.contains(&w))
+        .filter(|w| !stop_words.contains(w))
         .map(|w| w.to_string())
         .collect();
 
@@ -382,7 +544,7 @@ fn extract_themes(notes: &[Note]) -> Vec<String> {
         .into_iter()
         .map(|(word, count)| (count, word))
         .collect();
-    themes.sort_by(|a, b| b.0.cmp(&a.0));
+    themes.sort_by_key(|t| std::cmp::Reverse(t.0));
     
     themes
         .into_iter()
@@ -391,3 +553,10 @@ fn extract_themes(notes: &[Note]) -> Vec<String> {
         .collect()
 }
 
+/// Progress unit new text books are tracked in, from the defaultProgressUnit setting
+pub(crate) fn default_progress_unit_setting(settings_repository: &dyn SettingsRepository) -> Result<ProgressUnit, String> {
+    match settings_repository.get(PROGRESS_UNIT_SETTING)? {
+        Some(setting) => ProgressUnit::parse_default(&setting.value),
+        None => Ok(ProgressUnit::Page),
+    }
+}
@@ -0,0 +1,88 @@
+use crate::app::dtos::chapter_dto::{ChapterDto, ChapterProgressDto, ChapterProgressItemDto, SetChaptersCommand};
+use crate::core::domains::audiobook_chapter::{parse_timestamp, AudiobookChapter, ChapterProgress, ChapterStatus};
+use crate::core::domains::book::{Book, BookType};
+use crate::core::interfaces::primary::ChapterService;
+use crate::core::interfaces::secondary::{BookRepository, ChapterRepository};
+
+/// Implementation of ChapterService
+pub struct ChapterServiceImpl<'a> {
+    chapter_repository: &'a dyn ChapterRepository,
+    book_repository: &'a dyn BookRepository,
+}
+
+impl<'a> ChapterServiceImpl<'a> {
+    pub fn new(
+        chapter_repository: &'a dyn ChapterRepository,
+        book_repository: &'a dyn BookRepository,
+    ) -> Self {
+        ChapterServiceImpl {
+            chapter_repository,
+            book_repository,
+        }
+    }
+
+    /// Finds the book and checks it is an audiobook
+    fn find_audiobook(&self, book_id: i64) -> Result<Book, String> {
+        let book = self.book_repository
+            .find_by_id(book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", book_id))?;
+
+        if book.book_type != BookType::Audiobook {
+            return Err(format!("\"{}\" is not an audiobook; only audiobooks have chapters", book.title));
+        }
+
+        Ok(book)
+    }
+}
+
+impl<'a> ChapterService for ChapterServiceImpl<'a> {
+    fn list(&self, book_id: i64) -> Result<Vec<ChapterDto>, String> {
+        let chapters = self.chapter_repository.find_by_book_id(book_id)?;
+        Ok(chapters.into_iter().map(ChapterDto::from).collect())
+    }
+
+    fn set_chapters(&self, command: SetChaptersCommand) -> Result<Vec<ChapterDto>, String> {
+        let book = self.find_audiobook(command.book_id)?;
+
+        let chapters = command
+            .chapters
+            .into_iter()
+            .map(|input| {
+                let start_seconds = parse_timestamp(&input.start)?;
+                AudiobookChapter::new(command.book_id, input.title, start_seconds)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut chapters = AudiobookChapter::arrange(chapters, book.total_minutes)?;
+        self.chapter_repository.replace_for_book(command.book_id, &mut chapters)?;
+
+        Ok(chapters.into_iter().map(ChapterDto::from).collect())
+    }
+
+    fn get_progress(&self, book_id: i64) -> Result<ChapterProgressDto, String> {
+        let book = self.find_audiobook(book_id)?;
+        let chapters = self.chapter_repository.find_by_book_id(book_id)?;
+
+        // Book progress is kept in whole minutes
+        let position_seconds = book.current_minutes_audio * 60;
+        let total_seconds = book.total_minutes.map(|minutes| minutes * 60);
+        let progress = ChapterProgress::compute(&chapters, position_seconds, total_seconds);
+
+        let chapters_finished = progress.iter().filter(|p| p.status == ChapterStatus::Finished).count() as i32;
+        let current_chapter = progress
+            .iter()
+            .find(|p| p.status == ChapterStatus::Current)
+            .cloned()
+            .map(ChapterProgressItemDto::from);
+
+        Ok(ChapterProgressDto {
+            book_id,
+            position_seconds,
+            total_seconds,
+            chapters_finished,
+            chapters_total: progress.len() as i32,
+            current_chapter,
+            chapters: progress.into_iter().map(ChapterProgressItemDto::from).collect(),
+        })
+    }
+}
@@ -1,25 +1,33 @@
 use crate::app::dtos::collection_dto::{
     CollectionDto, CreateCollectionCommand, UpdateCollectionCommand, AddBooksToCollectionCommand,
-    ListCollectionsFilters,
+    ListCollectionsFilters, CollectionStatsDto, MonthlyCompletionsDto,
 };
+use crate::core::domains::book::{Book, BookStatus, BookType};
 use crate::core::domains::collection::Collection;
+use crate::core::domains::session::ReadingSession;
+use crate::core::domains::statistics_calculator::StatisticsCalculator;
 use crate::core::interfaces::primary::CollectionService;
-use crate::core::interfaces::secondary::{CollectionRepository, BookRepository};
+use crate::core::interfaces::secondary::{CollectionRepository, BookRepository, SessionRepository};
+use chrono::Datelike;
+use std::collections::BTreeMap;
 
 /// Implementation of CollectionService
 pub struct CollectionServiceImpl<'a> {
     collection_repository: &'a dyn CollectionRepository,
     book_repository: &'a dyn BookRepository,
+    session_repository: &'a dyn SessionRepository,
 }
 
 impl<'a> CollectionServiceImpl<'a> {
     pub fn new(
         collection_repository: &'a dyn CollectionRepository,
         book_repository: &'a dyn BookRepository,
+        session_repository: &'a dyn SessionRepository,
     ) -> Self {
         CollectionServiceImpl {
             collection_repository,
             book_repository,
+            session_repository,
         }
     }
 }
@@ -108,5 +116,134 @@ impl<'a> CollectionService for CollectionServiceImpl<'a> {
         self.collection_repository.remove_book(book_id, collection_id)?;
         Ok(())
     }
+
+    fn get_stats(&self, collection_id: i64) -> Result<CollectionStatsDto, String> {
+        let collection = self.collection_repository
+            .find_by_id(collection_id)?
+            .ok_or_else(|| format!("Collection with id {} not found", collection_id))?;
+
+        // Books in the collection, regardless of archive/wishlist state
+        let books = self.book_repository
+            .find_with_filters(None, None, None, None, Some(collection_id))?;
+
+        // Sessions for every book in the collection
+        let mut sessions = Vec::new();
+        for book in &books {
+            if let Some(book_id) = book.id {
+                sessions.extend(self.session_repository.find_by_book_id(book_id)?);
+            }
+        }
+
+        let total_books = books.len() as i32;
+        let completed_books = StatisticsCalculator::books_completed(&books) as i32;
+        let completion_percentage = if total_books > 0 {
+            completed_books as f64 / total_books as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        let mut total_pages = 0;
+        let mut pages_read = 0;
+        let mut total_minutes = 0;
+        let mut minutes_listened = 0;
+
+        for book in &books {
+            let is_completed = book.status == BookStatus::Completed;
+            match book.book_type {
+                BookType::Audiobook => {
+                    let total = book.total_minutes.unwrap_or(0);
+                    total_minutes += total;
+                    minutes_listened += if is_completed { total } else { book.current_minutes_audio };
+                }
+                _ => {
+                    let total = book.total_pages.unwrap_or(0);
+                    total_pages += total;
+                    pages_read += if is_completed { total } else { book.current_page_text };
+                }
+            }
+        }
+
+        Ok(CollectionStatsDto {
+            collection_id,
+            collection_name: collection.name,
+            total_books,
+            completed_books,
+            completion_percentage,
+            total_pages,
+            pages_read,
+            total_minutes,
+            minutes_listened,
+            time_spent_seconds: StatisticsCalculator::total_duration_seconds(&sessions),
+            average_days_to_finish: average_days_to_finish(&books, &sessions),
+            average_rating: average_rating(&books),
+            completions_timeline: completions_timeline(&books),
+        })
+    }
+}
+
+/// Average number of days between the first session and completion for completed books
+fn average_days_to_finish(books: &[Book], sessions: &[ReadingSession]) -> Option<f64> {
+    let mut durations = Vec::new();
+
+    for book in books {
+        if book.status != BookStatus::Completed {
+            continue;
+        }
+
+        if let (Some(book_id), Some(completed_at)) = (book.id, book.status_changed_at) {
+            let first_session_date = sessions
+                .iter()
+                .filter(|s| s.book_id == book_id)
+                .map(|s| s.session_date)
+                .min();
+
+            if let Some(started) = first_session_date {
+                let days = (completed_at.date_naive() - started).num_days().max(0);
+                durations.push(days as f64);
+            }
+        }
+    }
+
+    if durations.is_empty() {
+        None
+    } else {
+        Some(durations.iter().sum::<f64>() / durations.len() as f64)
+    }
+}
+
+/// Average of the rated books' ratings, each book counting once however often it was reread
+fn average_rating(books: &[Book]) -> Option<f64> {
+    let ratings: Vec<f64> = books.iter().filter_map(|b| b.average_rating).collect();
+
+    if ratings.is_empty() {
+        None
+    } else {
+        let average = ratings.iter().sum::<f64>() / ratings.len() as f64;
+        Some((average * 100.0).round() / 100.0)
+    }
+}
+
+/// Groups completed books by the month they were completed (oldest first)
+fn completions_timeline(books: &[Book]) -> Vec<MonthlyCompletionsDto> {
+    let mut by_month: BTreeMap<(i32, u32), i32> = BTreeMap::new();
+
+    for book in books {
+        if book.status != BookStatus::Completed {
+            continue;
+        }
+        if let Some(completed_at) = book.status_changed_at {
+            let date = completed_at.date_naive();
+            *by_month.entry((date.year(), date.month())).or_insert(0) += 1;
+        }
+    }
+
+    by_month
+        .into_iter()
+        .map(|((year, month), books_completed)| MonthlyCompletionsDto {
+            year,
+            month,
+            books_completed,
+        })
+        .collect()
 }
 
@@ -0,0 +1,196 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use crate::app::dtos::cover_dto::{
+    BookCoverDto, CoverBackupDto, CoverImageDto, CoverImportErrorDto, ImportCoverCommand,
+    RemoteCoversResultDto,
+};
+use crate::core::domains::book_cover::{is_remote_source, BookCover};
+use crate::core::interfaces::primary::CoverService;
+use crate::core::interfaces::secondary::{BookCoverRepository, BookRepository, CoverStorage, HttpFetcher};
+
+/// Largest cover download accepted
+const MAX_DOWNLOAD_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Implementation of CoverService
+pub struct CoverServiceImpl<'a> {
+    book_repository: &'a dyn BookRepository,
+    cover_repository: &'a dyn BookCoverRepository,
+    cover_storage: &'a dyn CoverStorage,
+    http_fetcher: &'a dyn HttpFetcher,
+}
+
+impl<'a> CoverServiceImpl<'a> {
+    pub fn new(
+        book_repository: &'a dyn BookRepository,
+        cover_repository: &'a dyn BookCoverRepository,
+        cover_storage: &'a dyn CoverStorage,
+        http_fetcher: &'a dyn HttpFetcher,
+    ) -> Self {
+        CoverServiceImpl {
+            book_repository,
+            cover_repository,
+            cover_storage,
+            http_fetcher,
+        }
+    }
+
+    /// Stores image bytes and links them to a book, then drops the replaced image if unused
+    fn link_cover(&self, book_id: i64, bytes: &[u8], source: Option<String>) -> Result<BookCover, String> {
+        let previous = self.cover_repository.find_by_book_id(book_id)?;
+        let image = self.cover_storage.store(bytes)?;
+        let cover = BookCover::new(book_id, image, source);
+        self.cover_repository.save(&cover)?;
+
+        if let Some(previous) = previous {
+            if previous.hash != cover.hash {
+                self.delete_if_unused(&previous.hash)?;
+            }
+        }
+        Ok(cover)
+    }
+
+    fn read_source(&self, source: &str) -> Result<Vec<u8>, String> {
+        if is_remote_source(source) {
+            self.http_fetcher.get(source.trim(), MAX_DOWNLOAD_BYTES)
+        } else {
+            self.cover_storage.read_external(source)
+        }
+    }
+
+    fn delete_if_unused(&self, hash: &str) -> Result<(), String> {
+        delete_unused_cover_file(self.cover_repository, self.cover_storage, hash)
+    }
+}
+
+impl<'a> CoverService for CoverServiceImpl<'a> {
+    fn import_cover(&self, command: ImportCoverCommand) -> Result<BookCoverDto, String> {
+        self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+        if command.source.trim().is_empty() {
+            return Err("Cover source cannot be empty".to_string());
+        }
+
+        let bytes = self.read_source(&command.source)?;
+        let cover = self.link_cover(command.book_id, &bytes, Some(command.source.trim().to_string()))?;
+        Ok(BookCoverDto::from(cover))
+    }
+
+    fn import_remote_covers(&self) -> Result<RemoteCoversResultDto, String> {
+        let local_book_ids: Vec<i64> = self.cover_repository.find_all()?.iter().map(|c| c.book_id).collect();
+        let mut result = RemoteCoversResultDto {
+            imported_count: 0,
+            already_local_count: 0,
+            errors: Vec::new(),
+        };
+
+        for book in self.book_repository.find_all()? {
+            let (book_id, url) = match (book.id, book.cover_url.as_deref()) {
+                (Some(id), Some(url)) if is_remote_source(url) => (id, url.trim().to_string()),
+                _ => continue,
+            };
+            if local_book_ids.contains(&book_id) {
+                result.already_local_count += 1;
+                continue;
+            }
+
+            let stored = self
+                .http_fetcher
+                .get(&url, MAX_DOWNLOAD_BYTES)
+                .and_then(|bytes| self.link_cover(book_id, &bytes, Some(url.clone())));
+            match stored {
+                Ok(_) => result.imported_count += 1,
+                Err(error) => result.errors.push(CoverImportErrorDto { book_id, title: book.title, error }),
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn get_cover_image(&self, book_id: i64, thumbnail: bool) -> Result<Option<CoverImageDto>, String> {
+        let cover = match self.cover_repository.find_by_book_id(book_id)? {
+            Some(cover) => cover,
+            None => return Ok(None),
+        };
+
+        let (file_name, mime_type) = if thumbnail {
+            (cover.thumbnail_file_name(), "image/jpeg")
+        } else {
+            (cover.file_name(), cover.mime_type())
+        };
+        let bytes = self.cover_storage.read(&file_name)?;
+
+        Ok(Some(CoverImageDto {
+            book_id,
+            mime_type: mime_type.to_string(),
+            data_url: format!("data:{};base64,{}", mime_type, BASE64.encode(bytes)),
+        }))
+    }
+
+    fn remove_cover(&self, book_id: i64) -> Result<(), String> {
+        if let Some(cover) = self.cover_repository.find_by_book_id(book_id)? {
+            self.cover_repository.delete_by_book_id(book_id)?;
+            self.delete_if_unused(&cover.hash)?;
+        }
+        Ok(())
+    }
+
+    fn cleanup_orphaned_covers(&self) -> Result<usize, String> {
+        let used: Vec<String> = self.cover_repository.find_all()?.into_iter().map(|c| c.hash).collect();
+        let mut removed = 0;
+
+        for hash in self.cover_storage.list_hashes()? {
+            if !used.contains(&hash) {
+                self.cover_storage.delete(&hash)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn backup_covers(&self) -> Result<Vec<CoverBackupDto>, String> {
+        self.cover_repository
+            .find_all()?
+            .into_iter()
+            .map(|cover| {
+                Ok(CoverBackupDto {
+                    book_id: cover.book_id,
+                    data_base64: BASE64.encode(self.cover_storage.read(&cover.file_name())?),
+                    file_name: cover.file_name(),
+                    source: cover.source,
+                })
+            })
+            .collect()
+    }
+
+    fn restore_covers(&self, covers: Vec<CoverBackupDto>) -> Result<usize, String> {
+        let mut restored = 0;
+
+        for backup in covers {
+            if self.book_repository.find_by_id(backup.book_id)?.is_none() {
+                continue;
+            }
+            let bytes = BASE64
+                .decode(backup.data_base64.trim())
+                .map_err(|e| format!("Invalid cover data for {}: {}", backup.file_name, e))?;
+            self.link_cover(backup.book_id, &bytes, backup.source)?;
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
+}
+
+/// Deletes a cover image once no book links to it any more
+pub(crate) fn delete_unused_cover_file(
+    cover_repository: &dyn BookCoverRepository,
+    cover_storage: &dyn CoverStorage,
+    hash: &str,
+) -> Result<(), String> {
+    let in_use = cover_repository.find_all()?.iter().any(|c| c.hash == hash);
+    if !in_use {
+        cover_storage.delete(hash)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use crate::app::dtos::book_dto::{book_type_to_string, string_to_book_type};
+use crate::app::dtos::edition_dto::{
+    EditionDto, EditionProgressDto, WorkProgressDto, CreateEditionCommand, UpdateEditionCommand,
+};
+use crate::core::domains::book::BookType;
+use crate::core::domains::edition::Edition;
+use crate::core::domains::progress_calculator::ProgressCalculator;
+use crate::core::interfaces::primary::EditionService;
+use crate::core::interfaces::secondary::{EditionRepository, BookRepository, SessionRepository};
+
+/// Implementation of EditionService
+pub struct EditionServiceImpl<'a> {
+    edition_repository: &'a dyn EditionRepository,
+    book_repository: &'a dyn BookRepository,
+    session_repository: &'a dyn SessionRepository,
+}
+
+impl<'a> EditionServiceImpl<'a> {
+    pub fn new(
+        edition_repository: &'a dyn EditionRepository,
+        book_repository: &'a dyn BookRepository,
+        session_repository: &'a dyn SessionRepository,
+    ) -> Self {
+        EditionServiceImpl {
+            edition_repository,
+            book_repository,
+            session_repository,
+        }
+    }
+}
+
+impl<'a> EditionService for EditionServiceImpl<'a> {
+    fn create(&self, command: CreateEditionCommand) -> Result<EditionDto, String> {
+        // Validate book exists
+        self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+
+        let format = string_to_book_type(&command.format)?;
+        let mut edition = Edition::new(
+            command.book_id,
+            format,
+            command.total_pages,
+            command.total_minutes,
+        )?;
+
+        edition.label = command.label;
+        edition.isbn = command.isbn;
+
+        self.edition_repository.create(&mut edition)?;
+
+        Ok(EditionDto::from(edition))
+    }
+
+    fn update(&self, command: UpdateEditionCommand) -> Result<EditionDto, String> {
+        let mut edition = self.edition_repository
+            .find_by_id(command.id)?
+            .ok_or_else(|| format!("Edition with id {} not found", command.id))?;
+
+        if command.format.is_some() || command.total_pages.is_some() || command.total_minutes.is_some() {
+            let format = match command.format {
+                Some(format) => string_to_book_type(&format)?,
+                None => edition.format.clone(),
+            };
+            let total_pages = command.total_pages.or(edition.total_pages);
+            let total_minutes = command.total_minutes.or(edition.total_minutes);
+            edition.update_format(format, total_pages, total_minutes)?;
+        }
+
+        if let Some(label) = command.label {
+            edition.label = Some(label);
+        }
+
+        if let Some(isbn) = command.isbn {
+            edition.isbn = Some(isbn);
+        }
+
+        // Update timestamp
+        edition.updated_at = chrono::Utc::now();
+
+        self.edition_repository.update(&edition)?;
+
+        Ok(EditionDto::from(edition))
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let edition = self.edition_repository
+            .find_by_id(id)?
+            .ok_or_else(|| format!("Edition with id {} not found", id))?;
+
+        // Sessions would silently move to the book's own format and shift progress
+        let session_count = self.session_repository
+            .find_by_book_id(edition.book_id)?
+            .iter()
+            .filter(|s| s.edition_id == Some(id))
+            .count();
+        if session_count > 0 {
+            return Err(format!(
+                "Edition has {} session(s); delete or move them first",
+                session_count
+            ));
+        }
+
+        self.edition_repository.delete(id)?;
+
+        Ok(())
+    }
+
+    fn list(&self, book_id: i64) -> Result<Vec<EditionDto>, String> {
+        let editions = self.edition_repository.find_by_book_id(book_id)?;
+        Ok(editions.into_iter().map(EditionDto::from).collect())
+    }
+
+    fn get_work_progress(&self, book_id: i64) -> Result<WorkProgressDto, String> {
+        let book = self.book_repository
+            .find_by_id(book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", book_id))?;
+
+        let editions = self.edition_repository.find_by_book_id(book_id)?;
+
+        // A book without editions keeps its own progress fields as the source of truth
+        let fraction = if editions.is_empty() {
+            book.calculate_progress() / 100.0
+        } else {
+            let sessions = self.session_repository.find_by_book_id(book_id)?;
+            ProgressCalculator::unified_fraction(&book, &editions, &sessions)
+        };
+
+        let primary_length = match book.book_type {
+            BookType::Audiobook => book.total_minutes.unwrap_or(0),
+            _ => book.total_pages.unwrap_or(0),
+        };
+
+        let mut edition_progress = vec![EditionProgressDto {
+            edition_id: None,
+            format: book_type_to_string(&book.book_type),
+            label: None,
+            total_pages: book.total_pages,
+            total_minutes: book.total_minutes,
+            current_position: (fraction * primary_length as f64).round() as i32,
+        }];
+
+        for edition in editions {
+            edition_progress.push(EditionProgressDto {
+                edition_id: edition.id,
+                format: book_type_to_string(&edition.format),
+                current_position: edition.position_at_fraction(fraction),
+                label: edition.label,
+                total_pages: edition.total_pages,
+                total_minutes: edition.total_minutes,
+            });
+        }
+
+        Ok(WorkProgressDto {
+            book_id,
+            progress_percentage: fraction * 100.0,
+            editions: edition_progress,
+        })
+    }
+}
@@ -0,0 +1,315 @@
+use crate::app::dtos::BookSummaryDto;
+use crate::app::dtos::book_dto::book_status_to_string;
+use crate::app::dtos::export_dto::{
+    ExportFileDto, ExportMarkdownCommand, ExportedFileDto, MarkdownExportDto,
+    export_format_to_string, string_to_citation_format, string_to_export_format, string_to_table_format,
+};
+use crate::core::domains::author::ContributorRole;
+use crate::core::domains::citation::{write_citations, CitationFormat, CitationItem};
+use crate::core::domains::library_export::{storygraph_csv, ExportEntry, ExportFormat};
+use crate::core::domains::markdown_export::obsidian_vault;
+use crate::core::domains::pdf_document::PdfDocument;
+use crate::core::domains::reading::ReadingStatus;
+use crate::core::domains::tabular_export::{write_table, TableFormat};
+use crate::core::interfaces::primary::ExportService;
+use crate::core::interfaces::secondary::{
+    AuthorRepository, BookRepository, ExportFileWriter, JournalRepository, NoteRepository, ReadingRepository,
+    SessionRepository, TagRepository,
+};
+
+/// Implementation of ExportService
+pub struct ExportServiceImpl<'a> {
+    book_repository: &'a dyn BookRepository,
+    reading_repository: &'a dyn ReadingRepository,
+    tag_repository: &'a dyn TagRepository,
+    note_repository: &'a dyn NoteRepository,
+    journal_repository: &'a dyn JournalRepository,
+    session_repository: &'a dyn SessionRepository,
+    author_repository: &'a dyn AuthorRepository,
+    file_writer: &'a dyn ExportFileWriter,
+}
+
+impl<'a> ExportServiceImpl<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: &'a dyn BookRepository,
+        reading_repository: &'a dyn ReadingRepository,
+        tag_repository: &'a dyn TagRepository,
+        note_repository: &'a dyn NoteRepository,
+        journal_repository: &'a dyn JournalRepository,
+        session_repository: &'a dyn SessionRepository,
+        author_repository: &'a dyn AuthorRepository,
+        file_writer: &'a dyn ExportFileWriter,
+    ) -> Self {
+        ExportServiceImpl {
+            book_repository,
+            reading_repository,
+            tag_repository,
+            note_repository,
+            journal_repository,
+            session_repository,
+            author_repository,
+            file_writer,
+        }
+    }
+
+    /// Citation of a book with its authors and editors in display order
+    fn citation_item(&self, book_id: i64) -> Result<CitationItem, String> {
+        let book = self.book_repository
+            .find_by_id(book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", book_id))?;
+        let contributors = self.author_repository.find_contributors_by_book_id(book_id)?;
+        let names = |role: ContributorRole| -> Vec<String> {
+            contributors.iter().filter(|c| c.role == role).map(|c| c.author.name.clone()).collect()
+        };
+
+        Ok(CitationItem::from_book(&book, names(ContributorRole::Author), names(ContributorRole::Editor)))
+    }
+
+    /// Loads every book (or a single one) with its readings, tags, notes and journal entries
+    fn load_entries(&self, book_id: Option<i64>) -> Result<Vec<ExportEntry>, String> {
+        let books = match book_id {
+            Some(id) => vec![self.book_repository
+                .find_by_id(id)?
+                .ok_or_else(|| format!("Book with id {} not found", id))?],
+            None => self.book_repository.find_all()?,
+        };
+        let mut entries = Vec::new();
+
+        for book in books {
+            let book_id = match book.id {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let mut readings = self.reading_repository.find_by_book_id(book_id)?;
+            readings.sort_by_key(|r| r.reading_number);
+
+            let tags = self.tag_repository
+                .find_by_book_id(book_id)?
+                .into_iter()
+                .map(|t| t.name)
+                .collect();
+
+            let notes = self.note_repository.find_by_book_id(book_id)?;
+            let journal_entries = self.journal_repository.find_by_book_id(book_id)?;
+            let mut sessions = self.session_repository.find_by_book_id(book_id)?;
+            sessions.sort_by_key(|s| (s.session_date, s.start_time));
+
+            entries.push(ExportEntry { book, readings, tags, notes, journal_entries, sessions });
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Printable report of one book: details, summary, reading timeline and notes by page
+fn book_report_pdf(entry: &ExportEntry, summary: &BookSummaryDto) -> String {
+    let book = &entry.book;
+    let date = |d: chrono::DateTime<chrono::Utc>| d.format("%Y-%m-%d").to_string();
+    let mut pdf = PdfDocument::new(&book.title);
+
+    pdf.title(&book.title);
+    if let Some(author) = &book.author {
+        pdf.paragraph(&format!("by {}", author));
+    }
+    let mut details = vec![format!("Status: {}", book_status_to_string(&book.status).replace('_', " "))];
+    if let Some(pages) = book.total_pages {
+        details.push(format!("{} pages", pages));
+    }
+    if let Some(year) = book.publication_year {
+        details.push(format!("published {}", year));
+    }
+    if let Some(isbn) = &book.isbn {
+        details.push(format!("ISBN {}", isbn));
+    }
+    pdf.meta(&details.join("  \u{2022}  "));
+    if !entry.tags.is_empty() {
+        pdf.meta(&format!("Tags: {}", entry.tags.join(", ")));
+    }
+
+    pdf.heading("Summary");
+    pdf.paragraph(&format!("{} notes recorded.", summary.total_notes));
+    if !summary.key_themes.is_empty() {
+        pdf.paragraph(&format!("Key themes: {}", summary.key_themes.join(", ")));
+    }
+    let (rating, review) = entry.latest_review();
+    if let Some(rating) = rating {
+        pdf.paragraph(&format!("Rating: {} / 5", rating));
+    }
+    if let Some(review) = review {
+        pdf.paragraph(&review);
+    }
+
+    // Timeline: readings starting and finishing, with the sessions in between
+    let mut timeline: Vec<(chrono::NaiveDate, String)> = Vec::new();
+    for reading in &entry.readings {
+        if let Some(started) = reading.started_at {
+            timeline.push((started.date_naive(), format!("Started reading #{}", reading.reading_number)));
+        }
+        if let (ReadingStatus::Completed, Some(completed)) = (&reading.status, reading.completed_at) {
+            timeline.push((completed.date_naive(), format!("Finished reading #{}", reading.reading_number)));
+        }
+    }
+    for session in &entry.sessions {
+        let mut parts = Vec::new();
+        if let (Some(start), Some(end)) = (session.start_page, session.end_page) {
+            parts.push(format!("pages {}\u{2013}{}", start, end));
+        }
+        if let Some(pages) = session.pages_read {
+            parts.push(format!("{} pages", pages));
+        }
+        if let Some(minutes) = session.minutes_read {
+            parts.push(format!("{} min", minutes));
+        }
+        let text = if parts.is_empty() { "Reading session".to_string() } else { format!("Session: {}", parts.join(", ")) };
+        timeline.push((session.session_date, text));
+    }
+    timeline.sort_by_key(|(day, _)| *day);
+
+    if !timeline.is_empty() {
+        pdf.heading("Reading timeline");
+        for (day, text) in &timeline {
+            pdf.bullet(&format!("{}  {}", day.format("%Y-%m-%d"), text));
+        }
+    }
+
+    if !entry.notes.is_empty() {
+        let mut notes: Vec<_> = entry.notes.iter().collect();
+        notes.sort_by_key(|n| (n.page.is_none(), n.page, n.created_at));
+
+        pdf.heading("Notes");
+        for note in notes {
+            pdf.spacer();
+            pdf.meta(&match note.page {
+                Some(page) => format!("Page {}  \u{2022}  {}", page, date(note.created_at)),
+                None => date(note.created_at),
+            });
+            pdf.paragraph(note.content.trim());
+        }
+    }
+
+    pdf.render()
+}
+
+impl<'a> ExportService for ExportServiceImpl<'a> {
+    fn export_library(&self, format: String) -> Result<ExportFileDto, String> {
+        let format = string_to_export_format(&format)?;
+        let entries = self.load_entries(None)?;
+        let date = chrono::Local::now().format("%Y-%m-%d");
+
+        let (file_name, mime_type, content) = match format {
+            ExportFormat::StoryGraphCsv => (
+                format!("storygraph-export-{}.csv", date),
+                "text/csv",
+                storygraph_csv(&entries),
+            ),
+        };
+
+        Ok(ExportFileDto {
+            format: export_format_to_string(&format),
+            file_name,
+            mime_type: mime_type.to_string(),
+            content,
+        })
+    }
+
+    fn export_markdown(&self, command: ExportMarkdownCommand) -> Result<MarkdownExportDto, String> {
+        let entries = self.load_entries(command.book_id)?;
+
+        // Journal entries without a book only belong to a full export
+        let unlinked_journal = match command.book_id {
+            Some(_) => Vec::new(),
+            None => self.journal_repository
+                .find_all(None, None, None)?
+                .into_iter()
+                .filter(|j| j.book_id.is_none())
+                .collect(),
+        };
+
+        let files = obsidian_vault(&entries, &unlinked_journal);
+        if let Some(target_dir) = &command.target_dir {
+            self.file_writer.write_files(target_dir, &files)?;
+        }
+
+        Ok(MarkdownExportDto {
+            book_count: entries.len(),
+            note_count: entries.iter().map(|e| e.notes.len()).sum(),
+            journal_entry_count: entries.iter().map(|e| e.journal_entries.len()).sum::<usize>()
+                + unlinked_journal.len(),
+            written_to: command.target_dir,
+            files: files
+                .into_iter()
+                .map(|f| ExportedFileDto { path: f.path, content: f.content })
+                .collect(),
+        })
+    }
+
+    fn export_book_pdf(&self, book_id: i64, summary: BookSummaryDto) -> Result<ExportFileDto, String> {
+        let entry = self.load_entries(Some(book_id))?
+            .pop()
+            .ok_or_else(|| format!("Book with id {} not found", book_id))?;
+
+        let safe_title: String = entry.book.title
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '-' })
+            .collect::<String>()
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+
+        Ok(ExportFileDto {
+            format: "pdf".to_string(),
+            file_name: format!("{}-notes.pdf", safe_title.to_lowercase()),
+            mime_type: "application/pdf".to_string(),
+            content: book_report_pdf(&entry, &summary),
+        })
+    }
+
+    fn export_citations(&self, format: String, book_ids: Vec<i64>) -> Result<ExportFileDto, String> {
+        let citation_format = string_to_citation_format(&format)?;
+        if book_ids.is_empty() {
+            return Err("No books to export".to_string());
+        }
+
+        let items = book_ids
+            .iter()
+            .map(|id| self.citation_item(*id))
+            .collect::<Result<Vec<_>, String>>()?;
+        let (extension, mime_type) = match citation_format {
+            CitationFormat::BibTex => ("bib", "application/x-bibtex"),
+            CitationFormat::Ris => ("ris", "application/x-research-info-systems"),
+            CitationFormat::CslJson => ("json", "application/vnd.citationstyles.csl+json"),
+        };
+
+        Ok(ExportFileDto {
+            content: write_citations(&items, &citation_format),
+            format,
+            file_name: format!("citations-{}.{}", chrono::Local::now().format("%Y-%m-%d"), extension),
+            mime_type: mime_type.to_string(),
+        })
+    }
+
+    fn export_records(
+        &self,
+        dataset: &str,
+        format: String,
+        fields: Option<Vec<String>>,
+        records: Vec<serde_json::Value>,
+    ) -> Result<ExportFileDto, String> {
+        let table_format = string_to_table_format(&format)?;
+        let content = write_table(&records, fields.as_deref(), &table_format)?;
+        let (extension, mime_type) = match table_format {
+            TableFormat::Csv => ("csv", "text/csv"),
+            TableFormat::Jsonl => ("jsonl", "application/x-ndjson"),
+        };
+
+        Ok(ExportFileDto {
+            format,
+            file_name: format!("{}-{}.{}", dataset.replace('_', "-"), chrono::Local::now().format("%Y-%m-%d"), extension),
+            mime_type: mime_type.to_string(),
+            content,
+        })
+    }
+}
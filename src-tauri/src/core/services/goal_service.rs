@@ -1,7 +1,8 @@
 use crate::app::dtos::goal_dto::{GoalDto, CreateGoalCommand, UpdateGoalCommand, ListGoalsFilters};
 use crate::core::domains::goal::{Goal, GoalType};
 use crate::core::interfaces::primary::GoalService;
-use crate::core::interfaces::secondary::{GoalRepository, SessionRepository, BookRepository};
+use crate::core::interfaces::secondary::{GoalRepository, SessionRepository, BookRepository, SettingsRepository};
+use crate::core::services::session_service::user_timezone_setting;
 use crate::core::domains::book::BookStatus;
 use chrono::Datelike;
 
@@ -10,6 +11,7 @@ pub struct GoalServiceImpl<'a> {
     goal_repository: &'a dyn GoalRepository,
     session_repository: &'a dyn SessionRepository,
     book_repository: &'a dyn BookRepository,
+    settings_repository: &'a dyn SettingsRepository,
 }
 
 impl<'a> GoalServiceImpl<'a> {
@@ -17,11 +19,13 @@ impl<'a> GoalServiceImpl<'a> {
         goal_repository: &'a dyn GoalRepository,
         session_repository: &'a dyn SessionRepository,
         book_repository: &'a dyn BookRepository,
+        settings_repository: &'a dyn SettingsRepository,
     ) -> Self {
         GoalServiceImpl {
             goal_repository,
             session_repository,
             book_repository,
+            settings_repository,
         }
     }
 }
@@ -123,15 +127,15 @@ impl<'a> GoalService for GoalServiceImpl<'a> {
             self.goal_repository.find_active()?
         };
 
-        let now = chrono::Utc::now();
-        let current_date = now.date_naive();
+        // Current day and month in the user's time zone
+        let current_date = user_timezone_setting(self.settings_repository)?.today();
         let current_year = Datelike::year(&current_date);
         let current_month = Datelike::month(&current_date);
 
         let goal_dtos: Vec<GoalDto> = goals
             .into_iter()
             .map(|goal| {
-                let (progress, percentage) = self.calculate_progress(&goal, current_year, current_month);
+                let (progress, percentage) = self.calculate_progress(&goal, current_date, current_year, current_month);
                 let mut dto = GoalDto::from(goal);
                 dto.current_progress = progress;
                 dto.progress_percentage = percentage;
@@ -144,7 +148,7 @@ impl<'a> GoalService for GoalServiceImpl<'a> {
 }
 
 impl<'a> GoalServiceImpl<'a> {
-    fn calculate_progress(&self, goal: &Goal, current_year: i32, current_month: u32) -> (i32, f64) {
+    fn calculate_progress(&self, goal: &Goal, today: chrono::NaiveDate, current_year: i32, current_month: u32) -> (i32, f64) {
         match goal.goal_type {
             GoalType::PagesMonthly => {
                 if let (Some(goal_year), Some(goal_month)) = (goal.period_year, goal.period_month) {
@@ -216,7 +220,6 @@ impl<'a> GoalServiceImpl<'a> {
             }
             GoalType::MinutesDaily => {
                 // Calculate minutes read today
-                let today = chrono::Utc::now().date_naive();
                 if let Ok(sessions) = self.session_repository.find_by_date_range(today, today) {
                     let minutes = sessions.iter()
                         .map(|s| s.minutes_read.unwrap_or(0))
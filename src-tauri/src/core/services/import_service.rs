@@ -0,0 +1,722 @@
+use chrono::{DateTime, Utc};
+use crate::app::dtos::book_dto::{book_status_to_string, book_type_to_string};
+use crate::app::dtos::import_dto::{
+    ImportLibraryCommand, ImportPreviewDto, ImportResultDto, ImportRowDto, ImportRowErrorDto,
+    ImportKindleClippingsCommand, KindleBookImportDto, KindleImportResultDto,
+    ImportKoreaderCommand, ImportCalibreCommand, ExternalBookMatchDto, ExternalImportReportDto,
+    ImportCitationsCommand, import_source_to_string, string_to_import_source,
+};
+use crate::core::domains::author::{Author, ContributorRole};
+use crate::core::domains::book::{Book, BookType};
+use crate::core::domains::calibre_library::CalibreBook;
+use crate::core::domains::citation::{parse_citations, CitationItem};
+use crate::core::domains::koreader_metadata::KoreaderDocument;
+use crate::core::domains::kindle_clippings::{find_matching_book, group_by_book, parse_clippings, ClippingKind};
+use crate::core::domains::library_import::{parse_library_export, ImportedBook};
+use crate::core::domains::note::Note;
+use crate::core::domains::series::Series;
+use crate::core::domains::tag::Tag;
+use crate::core::interfaces::primary::ImportService;
+use crate::core::interfaces::secondary::{
+    AuthorRepository, BookRepository, CalibreLibraryReader, NoteRepository, ReadingRepository,
+    SeriesRepository, SettingsRepository, TagRepository, TransactionManager,
+};
+use crate::core::services::author_service::find_or_create_author;
+use crate::core::services::session_service::user_timezone_setting;
+
+/// Implementation of ImportService
+pub struct ImportServiceImpl<'a> {
+    book_repository: &'a dyn BookRepository,
+    reading_repository: &'a dyn ReadingRepository,
+    tag_repository: &'a dyn TagRepository,
+    author_repository: &'a dyn AuthorRepository,
+    note_repository: &'a dyn NoteRepository,
+    series_repository: &'a dyn SeriesRepository,
+    calibre_reader: &'a dyn CalibreLibraryReader,
+    settings_repository: &'a dyn SettingsRepository,
+    transaction_manager: &'a dyn TransactionManager,
+}
+
+impl<'a> ImportServiceImpl<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        book_repository: &'a dyn BookRepository,
+        reading_repository: &'a dyn ReadingRepository,
+        tag_repository: &'a dyn TagRepository,
+        author_repository: &'a dyn AuthorRepository,
+        note_repository: &'a dyn NoteRepository,
+        series_repository: &'a dyn SeriesRepository,
+        calibre_reader: &'a dyn CalibreLibraryReader,
+        settings_repository: &'a dyn SettingsRepository,
+        transaction_manager: &'a dyn TransactionManager,
+    ) -> Self {
+        ImportServiceImpl {
+            book_repository,
+            reading_repository,
+            tag_repository,
+            author_repository,
+            note_repository,
+            series_repository,
+            calibre_reader,
+            settings_repository,
+            transaction_manager,
+        }
+    }
+
+    /// Runs the writes for one imported row in a transaction, so a failure
+    /// part-way leaves nothing behind
+    fn in_transaction<T>(&self, work: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+        let mut work = Some(work);
+        let mut output = None;
+        self.transaction_manager.run(&mut || {
+            let work = work.take().ok_or("Import row already saved".to_string())?;
+            output = Some(work()?);
+            Ok(())
+        })?;
+        output.ok_or("Import row was not saved".to_string())
+    }
+
+    /// Saves one imported book with its authors, tags and reading history
+    fn import_book(&self, imported: &ImportedBook, mut book: Book) -> Result<Book, String> {
+        self.book_repository.create(&mut book)?;
+        let book_id = book.id.ok_or("Book ID missing after insert".to_string())?;
+
+        let mut contributors: Vec<(i64, ContributorRole)> = Vec::new();
+        for name in &imported.authors {
+            let author_id = find_or_create_author(self.author_repository, name)?;
+            if !contributors.iter().any(|(id, _)| *id == author_id) {
+                contributors.push((author_id, ContributorRole::Author));
+            }
+        }
+        if !contributors.is_empty() {
+            self.author_repository.set_book_contributors(book_id, &contributors)?;
+        }
+
+        for name in &imported.tags {
+            self.add_tag(book_id, name)?;
+        }
+
+        for reading in imported.to_readings(book_id)? {
+            self.reading_repository.create(&reading)?;
+        }
+
+        Ok(book)
+    }
+
+    /// Tags a book, creating the tag when missing
+    fn add_tag(&self, book_id: i64, name: &str) -> Result<(), String> {
+        let tag_id = match self.tag_repository.find_by_name(name.trim())? {
+            Some(tag) => tag.id,
+            None => {
+                let mut tag = Tag::new(name.to_string(), None)?;
+                self.tag_repository.create(&mut tag)?;
+                tag.id
+            }
+        };
+        if let Some(tag_id) = tag_id {
+            self.tag_repository.add_to_book(book_id, tag_id)?;
+        }
+        Ok(())
+    }
+
+    /// Places a book in a Calibre series, creating the series when missing
+    fn assign_calibre_series(&self, book: &mut Book, calibre_book: &CalibreBook) -> Result<(), String> {
+        if let Some(name) = calibre_book.series.as_deref() {
+            let series_id = find_or_create_series(self.series_repository, name)?;
+            book.update_series(Some(series_id), calibre_book.series_index.filter(|i| *i > 0.0))?;
+        }
+        Ok(())
+    }
+
+    /// Lists a citation's editors after its authors as the book's contributors
+    fn add_editors(&self, book_id: i64, item: &CitationItem) -> Result<(), String> {
+        let mut contributors: Vec<(i64, ContributorRole)> = Vec::new();
+        for name in &item.authors {
+            contributors.push((find_or_create_author(self.author_repository, name)?, ContributorRole::Author));
+        }
+        for name in &item.editors {
+            let author_id = find_or_create_author(self.author_repository, name)?;
+            if !contributors.iter().any(|(id, _)| *id == author_id) {
+                contributors.push((author_id, ContributorRole::Editor));
+            }
+        }
+        self.author_repository.set_book_contributors(book_id, &contributors)
+    }
+
+    /// Creates an ebook for annotations whose title is not in the library
+    fn create_ebook(&self, title: &str, authors: &[String], total_pages: i32) -> Result<Book, String> {
+        let mut book = Book::new(title.to_string(), BookType::Ebook, Some(total_pages.max(1)), None)?;
+        book.author = Author::join_names(authors);
+        self.book_repository.create(&mut book)?;
+        let book_id = book.id.ok_or("Book ID missing after insert".to_string())?;
+
+        let mut contributors: Vec<(i64, ContributorRole)> = Vec::new();
+        for name in authors {
+            contributors.push((find_or_create_author(self.author_repository, name)?, ContributorRole::Author));
+        }
+        if !contributors.is_empty() {
+            self.author_repository.set_book_contributors(book_id, &contributors)?;
+        }
+
+        Ok(book)
+    }
+}
+
+/// Finds a series by name, creating it when missing
+fn find_or_create_series(series_repository: &dyn SeriesRepository, name: &str) -> Result<i64, String> {
+    if let Some(existing) = series_repository.find_by_name(name.trim())? {
+        return existing.id.ok_or("Series ID missing".to_string());
+    }
+
+    let mut series = Series::new(name.to_string(), None, None)?;
+    series_repository.create(&mut series)?;
+    series.id.ok_or("Series ID missing after insert".to_string())
+}
+
+fn empty_report(dry_run: bool) -> ExternalImportReportDto {
+    ExternalImportReportDto {
+        dry_run,
+        books: Vec::new(),
+        created_books: 0,
+        updated_books: 0,
+        imported_notes: 0,
+    }
+}
+
+/// An annotation is already imported when a note with the same text (and timestamp, if known) exists
+fn is_already_imported(existing_notes: &[Note], content: &str, created_at: Option<DateTime<Utc>>) -> bool {
+    existing_notes.iter().any(|note| {
+        note.content.trim() == content.trim()
+            && created_at.is_none_or(|created| note.created_at.timestamp() == created.timestamp())
+    })
+}
+
+/// Matches a row against the library and against earlier rows of the same file
+fn detect_duplicate(
+    imported: &ImportedBook,
+    existing_books: &[Book],
+    earlier_rows: &[(usize, Book)],
+) -> (Option<i64>, Option<usize>) {
+    if let Some(book) = imported.find_duplicate(existing_books) {
+        return (book.id, None);
+    }
+
+    let row = earlier_rows
+        .iter()
+        .find(|(_, book)| imported.find_duplicate(std::slice::from_ref(book)).is_some())
+        .map(|(row, _)| *row);
+    (None, row)
+}
+
+fn to_row_dto(imported: &ImportedBook) -> ImportRowDto {
+    ImportRowDto {
+        row_number: imported.row_number,
+        title: imported.title.clone(),
+        authors: imported.authors.clone(),
+        isbn: imported.isbn.clone(),
+        book_type: book_type_to_string(&imported.book_type),
+        total_pages: imported.total_pages,
+        status: book_status_to_string(&imported.status),
+        is_wishlist: imported.is_wishlist,
+        tags: imported.tags.clone(),
+        rating: imported.rating,
+        date_read: imported.date_read.map(|d| d.format("%Y-%m-%d").to_string()),
+        read_count: imported.read_count,
+        duplicate_of_book_id: None,
+        duplicate_of_row: None,
+        error: None,
+    }
+}
+
+impl<'a> ImportService for ImportServiceImpl<'a> {
+    fn preview(&self, command: ImportLibraryCommand) -> Result<ImportPreviewDto, String> {
+        let source = string_to_import_source(&command.source)?;
+        let (imported_books, parse_errors) = parse_library_export(&source, &command.content)?;
+        let existing_books = self.book_repository.find_all()?;
+
+        let mut rows: Vec<ImportRowDto> = Vec::new();
+        let mut earlier_rows: Vec<(usize, Book)> = Vec::new();
+
+        for imported in &imported_books {
+            let mut row = to_row_dto(imported);
+            match imported.to_book(command.default_total_pages, command.default_total_minutes) {
+                Ok(book) => {
+                    let (book_id, row_number) = detect_duplicate(imported, &existing_books, &earlier_rows);
+                    row.duplicate_of_book_id = book_id;
+                    row.duplicate_of_row = row_number;
+                    earlier_rows.push((imported.row_number, book));
+                }
+                Err(e) => row.error = Some(e),
+            }
+            rows.push(row);
+        }
+
+        for (row_number, error) in parse_errors {
+            rows.push(ImportRowDto {
+                row_number,
+                title: String::new(),
+                authors: Vec::new(),
+                isbn: None,
+                book_type: String::new(),
+                total_pages: None,
+                status: String::new(),
+                is_wishlist: false,
+                tags: Vec::new(),
+                rating: None,
+                date_read: None,
+                read_count: 0,
+                duplicate_of_book_id: None,
+                duplicate_of_row: None,
+                error: Some(error),
+            });
+        }
+        rows.sort_by_key(|row| row.row_number);
+
+        let duplicate_count = rows
+            .iter()
+            .filter(|row| row.duplicate_of_book_id.is_some() || row.duplicate_of_row.is_some())
+            .count();
+        let error_count = rows.iter().filter(|row| row.error.is_some()).count();
+        let importable_count = if command.skip_duplicates.unwrap_or(true) {
+            rows.len() - duplicate_count - error_count
+        } else {
+            rows.len() - error_count
+        };
+
+        Ok(ImportPreviewDto {
+            source: import_source_to_string(&source),
+            total_rows: rows.len(),
+            importable_count,
+            duplicate_count,
+            error_count,
+            rows,
+        })
+    }
+
+    fn import(&self, command: ImportLibraryCommand) -> Result<ImportResultDto, String> {
+        let source = string_to_import_source(&command.source)?;
+        let (imported_books, parse_errors) = parse_library_export(&source, &command.content)?;
+        let skip_duplicates = command.skip_duplicates.unwrap_or(true);
+
+        // Books created by this import count as existing, so repeated rows are skipped too
+        let mut existing_books = self.book_repository.find_all()?;
+        let mut result = ImportResultDto {
+            imported_count: 0,
+            skipped_duplicates: 0,
+            created_book_ids: Vec::new(),
+            errors: parse_errors
+                .into_iter()
+                .map(|(row_number, error)| ImportRowErrorDto { row_number, title: None, error })
+                .collect(),
+        };
+
+        for imported in &imported_books {
+            if skip_duplicates && imported.find_duplicate(&existing_books).is_some() {
+                result.skipped_duplicates += 1;
+                continue;
+            }
+
+            let saved = imported
+                .to_book(command.default_total_pages, command.default_total_minutes)
+                .and_then(|book| self.in_transaction(|| self.import_book(imported, book)));
+
+            match saved {
+                Ok(book) => {
+                    if let Some(id) = book.id {
+                        result.created_book_ids.push(id);
+                    }
+                    result.imported_count += 1;
+                    existing_books.push(book);
+                }
+                Err(error) => result.errors.push(ImportRowErrorDto {
+                    row_number: imported.row_number,
+                    title: Some(imported.title.clone()),
+                    error,
+                }),
+            }
+        }
+
+        result.errors.sort_by_key(|e| e.row_number);
+        Ok(result)
+    }
+
+    fn import_kindle_clippings(&self, command: ImportKindleClippingsCommand) -> Result<KindleImportResultDto, String> {
+        // "Added on" times are the device's local time
+        let timezone = user_timezone_setting(self.settings_repository)?;
+        let (clippings, parse_errors) = parse_clippings(&command.content, &timezone);
+        let groups = group_by_book(clippings);
+        if groups.is_empty() && parse_errors.is_empty() {
+            return Err("No highlights or notes found in clippings file".to_string());
+        }
+
+        let mut books = self.book_repository.find_all()?;
+        let mut result = KindleImportResultDto {
+            books: Vec::new(),
+            total_imported: 0,
+            total_skipped: 0,
+            errors: parse_errors
+                .into_iter()
+                .map(|(row_number, error)| ImportRowErrorDto { row_number, title: None, error })
+                .collect(),
+        };
+
+        for group in groups {
+            let matched = find_matching_book(&group.title, group.author.as_deref(), &books).cloned();
+
+            // Each book's clippings are saved together with the book they create, if any
+            let saved = self.in_transaction(|| {
+                let (book, created_book) = match matched {
+                    Some(book) => (book, false),
+                    None => {
+                        // Unknown title: create it as an ebook long enough to hold every clipping
+                        let furthest_page = group.clippings.iter().filter_map(|c| c.estimated_page()).max();
+                        let total_pages = command.default_total_pages.or(furthest_page).unwrap_or(1);
+                        let authors = group.author.as_deref().map(Author::split_names).unwrap_or_default();
+
+                        (self.create_ebook(&group.title, &authors, total_pages)?, true)
+                    }
+                };
+                let book_id = book.id.ok_or("Book ID missing".to_string())?;
+
+                // A clipping is already imported when a note with the same text and timestamp exists
+                let existing_notes = self.note_repository.find_by_book_id(book_id)?;
+                let mut summary = KindleBookImportDto {
+                    kindle_title: group.title.clone(),
+                    kindle_author: group.author.clone(),
+                    book_id,
+                    book_title: book.title.clone(),
+                    created_book,
+                    highlights_imported: 0,
+                    notes_imported: 0,
+                    already_imported: 0,
+                };
+
+                for clipping in &group.clippings {
+                    if is_already_imported(&existing_notes, &clipping.content, clipping.added_at) {
+                        summary.already_imported += 1;
+                        continue;
+                    }
+
+                    let mut note = Note::new(book_id, clipping.content.clone())?;
+                    note.page = clipping.estimated_page().map(|page| match book.total_pages {
+                        Some(total) => page.min(total),
+                        None => page,
+                    });
+                    if let Some(added) = clipping.added_at {
+                        note.created_at = added;
+                        note.updated_at = added;
+                    }
+                    self.note_repository.create(&mut note)?;
+
+                    match clipping.kind {
+                        ClippingKind::Highlight => summary.highlights_imported += 1,
+                        ClippingKind::Note => summary.notes_imported += 1,
+                    }
+                }
+
+                Ok((summary, if created_book { Some(book) } else { None }))
+            });
+
+            // A failed book is reported and the rest of the file is still imported
+            match saved {
+                Ok((summary, created)) => {
+                    books.extend(created);
+                    result.total_imported += summary.highlights_imported + summary.notes_imported;
+                    result.total_skipped += summary.already_imported;
+                    result.books.push(summary);
+                }
+                Err(error) => result.errors.push(ImportRowErrorDto {
+                    row_number: group.clippings.first().map(|c| c.entry_number).unwrap_or(0),
+                    title: Some(group.title.clone()),
+                    error,
+                }),
+            }
+        }
+
+        result.errors.sort_by_key(|e| e.row_number);
+        Ok(result)
+    }
+
+    fn import_koreader(&self, command: ImportKoreaderCommand) -> Result<ExternalImportReportDto, String> {
+        let dry_run = command.dry_run.unwrap_or(false);
+        let mut books = self.book_repository.find_all()?;
+        let mut report = empty_report(dry_run);
+
+        for file in &command.files {
+            let mut entry = ExternalBookMatchDto {
+                source: file.file_name.clone(),
+                title: String::new(),
+                authors: Vec::new(),
+                book_id: None,
+                matched_title: None,
+                action: "error".to_string(),
+                changes: Vec::new(),
+                new_notes: 0,
+                existing_notes: 0,
+                error: None,
+            };
+
+            let document = match KoreaderDocument::parse(&file.content, &file.file_name) {
+                Ok(document) => document,
+                Err(e) => {
+                    entry.error = Some(e);
+                    report.books.push(entry);
+                    continue;
+                }
+            };
+            let title = match document.title.clone() {
+                Some(title) => title,
+                None => {
+                    entry.error = Some("Sidecar has no title; pick the file next to its book".to_string());
+                    report.books.push(entry);
+                    continue;
+                }
+            };
+            entry.title = title.clone();
+            entry.authors = document.authors.clone();
+
+            let matched = find_matching_book(&title, document.authors.first().map(|a| a.as_str()), &books).cloned();
+            let existing_notes = match matched.as_ref().and_then(|b| b.id) {
+                Some(book_id) => match self.note_repository.find_by_book_id(book_id) {
+                    Ok(notes) => notes,
+                    Err(e) => {
+                        entry.error = Some(e);
+                        report.books.push(entry);
+                        continue;
+                    }
+                },
+                None => Vec::new(),
+            };
+            let new_annotations: Vec<_> = document.annotations
+                .iter()
+                .filter(|a| !is_already_imported(&existing_notes, &a.note_content(), a.created_at))
+                .collect();
+            entry.new_notes = new_annotations.len();
+            entry.existing_notes = document.annotations.len() - new_annotations.len();
+            entry.action = match (&matched, new_annotations.is_empty()) {
+                (None, _) => "create",
+                (Some(_), false) => "update",
+                (Some(_), true) => "unchanged",
+            }
+            .to_string();
+
+            if dry_run {
+                if let Some(book) = &matched {
+                    entry.book_id = book.id;
+                    entry.matched_title = Some(book.title.clone());
+                }
+                report.books.push(entry);
+                continue;
+            }
+
+            // The book (when created) and its new notes are saved together
+            let created_book = matched.is_none();
+            let saved = self.in_transaction(|| {
+                let book = match matched {
+                    Some(book) => book,
+                    None => {
+                        let furthest_page = document.annotations.iter().filter_map(|a| a.page).max();
+                        let total_pages = document.total_pages
+                            .or(command.default_total_pages)
+                            .or(furthest_page)
+                            .unwrap_or(1);
+                        self.create_ebook(&title, &document.authors, total_pages)?
+                    }
+                };
+
+                let book_id = book.id.ok_or("Book ID missing".to_string())?;
+                for annotation in &new_annotations {
+                    let mut note = Note::new(book_id, annotation.note_content())?;
+                    note.page = annotation.page.map(|page| match book.total_pages {
+                        Some(total) => page.min(total),
+                        None => page,
+                    });
+                    if let Some(created) = annotation.created_at {
+                        note.created_at = created;
+                        note.updated_at = created;
+                    }
+                    self.note_repository.create(&mut note)?;
+                }
+                Ok(book)
+            });
+
+            // A failed file is reported and the remaining files are still imported
+            let book = match saved {
+                Ok(book) => book,
+                Err(e) => {
+                    entry.action = "error".to_string();
+                    entry.error = Some(e);
+                    report.books.push(entry);
+                    continue;
+                }
+            };
+
+            entry.book_id = book.id;
+            entry.matched_title = Some(book.title.clone());
+            if created_book {
+                report.created_books += 1;
+                books.push(book);
+            }
+            if entry.action == "update" {
+                report.updated_books += 1;
+            }
+            report.imported_notes += new_annotations.len();
+
+            report.books.push(entry);
+        }
+
+        Ok(report)
+    }
+
+    fn import_calibre(&self, command: ImportCalibreCommand) -> Result<ExternalImportReportDto, String> {
+        let dry_run = command.dry_run.unwrap_or(false);
+        let calibre_books = self.calibre_reader.read_books(&command.library_path)?;
+        let mut books = self.book_repository.find_all()?;
+        let mut report = empty_report(dry_run);
+
+        for (index, calibre_book) in calibre_books.iter().enumerate() {
+            let imported = calibre_book.to_imported_book(index + 1);
+            let mut entry = ExternalBookMatchDto {
+                source: format!("calibre:{}", calibre_book.calibre_id),
+                title: calibre_book.title.clone(),
+                authors: calibre_book.authors.clone(),
+                book_id: None,
+                matched_title: None,
+                action: "create".to_string(),
+                changes: Vec::new(),
+                new_notes: 0,
+                existing_notes: 0,
+                error: None,
+            };
+
+            let outcome = match imported.find_duplicate(&books).cloned() {
+                // Known book: fill in what the library is missing
+                Some(mut book) => {
+                    entry.book_id = book.id;
+                    entry.matched_title = Some(book.title.clone());
+                    let book_id = book.id.ok_or("Book ID missing".to_string())?;
+
+                    if book.isbn.is_none() && imported.isbn.is_some() {
+                        book.isbn = imported.isbn.clone();
+                        entry.changes.push("isbn".to_string());
+                    }
+                    if book.publication_year.is_none() && imported.publication_year.is_some() {
+                        book.publication_year = imported.publication_year;
+                        entry.changes.push("publication_year".to_string());
+                    }
+                    if book.series_id.is_none() && calibre_book.series.is_some() {
+                        entry.changes.push("series".to_string());
+                    }
+                    let current_tags: Vec<String> = self.tag_repository
+                        .find_by_book_id(book_id)?
+                        .into_iter()
+                        .map(|t| t.name.to_lowercase())
+                        .collect();
+                    let new_tags: Vec<&String> = calibre_book.tags
+                        .iter()
+                        .filter(|t| !current_tags.contains(&t.to_lowercase()))
+                        .collect();
+                    if !new_tags.is_empty() {
+                        entry.changes.push("tags".to_string());
+                    }
+                    entry.action = if entry.changes.is_empty() { "unchanged" } else { "update" }.to_string();
+
+                    if dry_run || entry.changes.is_empty() {
+                        Ok(())
+                    } else {
+                        let saved = self.in_transaction(|| {
+                            if book.series_id.is_none() {
+                                self.assign_calibre_series(&mut book, calibre_book)?;
+                            }
+                            self.book_repository.update(&book)?;
+                            new_tags.iter().try_for_each(|name| self.add_tag(book_id, name))
+                        });
+                        if saved.is_ok() {
+                            report.updated_books += 1;
+                        }
+                        saved
+                    }
+                }
+                // New book: created like a CSV import row, then placed in its series
+                None => match imported.to_book(command.default_total_pages, command.default_total_minutes) {
+                    Err(e) => Err(e),
+                    Ok(_) if dry_run => Ok(()),
+                    Ok(mut book) => self
+                        .in_transaction(|| {
+                            self.assign_calibre_series(&mut book, calibre_book)?;
+                            self.import_book(&imported, book)
+                        })
+                        .map(|book| {
+                            entry.book_id = book.id;
+                            entry.matched_title = Some(book.title.clone());
+                            books.push(book);
+                            report.created_books += 1;
+                        }),
+                },
+            };
+
+            if let Err(e) = outcome {
+                entry.action = "error".to_string();
+                entry.error = Some(e);
+            }
+            report.books.push(entry);
+        }
+
+        Ok(report)
+    }
+
+    fn import_citations(&self, command: ImportCitationsCommand) -> Result<ImportResultDto, String> {
+        let (items, parse_errors) = parse_citations(&command.content)?;
+        let skip_duplicates = command.skip_duplicates.unwrap_or(true);
+
+        let mut existing_books = self.book_repository.find_all()?;
+        let mut result = ImportResultDto {
+            imported_count: 0,
+            skipped_duplicates: 0,
+            created_book_ids: Vec::new(),
+            errors: parse_errors
+                .into_iter()
+                .map(|(row_number, error)| ImportRowErrorDto { row_number, title: None, error })
+                .collect(),
+        };
+
+        for item in &items {
+            let imported = item.to_imported_book();
+            if skip_duplicates && imported.find_duplicate(&existing_books).is_some() {
+                result.skipped_duplicates += 1;
+                continue;
+            }
+
+            let saved = imported
+                .to_book(command.default_total_pages, None)
+                .and_then(|mut book| {
+                    book.url = item.url.clone();
+                    self.in_transaction(|| {
+                        let book = self.import_book(&imported, book)?;
+                        if let (Some(id), false) = (book.id, item.editors.is_empty()) {
+                            self.add_editors(id, item)?;
+                        }
+                        Ok(book)
+                    })
+                });
+
+            match saved {
+                Ok(book) => {
+                    if let Some(id) = book.id {
+                        result.created_book_ids.push(id);
+                    }
+                    result.imported_count += 1;
+                    existing_books.push(book);
+                }
+                Err(error) => result.errors.push(ImportRowErrorDto {
+                    row_number: item.row_number,
+                    title: Some(item.title.clone()),
+                    error,
+                }),
+            }
+        }
+
+        result.errors.sort_by_key(|e| e.row_number);
+        Ok(result)
+    }
+}
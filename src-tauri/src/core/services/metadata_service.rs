@@ -0,0 +1,77 @@
+use crate::app::dtos::metadata_dto::{BookMetadataDto, IsbnDto};
+use crate::core::domains::isbn::{isbn13_to_10, parse_isbn};
+use crate::core::interfaces::primary::MetadataService;
+use crate::core::interfaces::secondary::{MetadataCacheRepository, MetadataProvider, SettingsRepository};
+
+/// Setting holding the base URL of the Open Library-compatible metadata service
+const PROVIDER_URL_SETTING: &str = "metadataProviderUrl";
+
+/// Implementation of MetadataService
+pub struct MetadataServiceImpl<'a> {
+    cache_repository: &'a dyn MetadataCacheRepository,
+    settings_repository: &'a dyn SettingsRepository,
+    provider: &'a dyn MetadataProvider,
+}
+
+impl<'a> MetadataServiceImpl<'a> {
+    pub fn new(
+        cache_repository: &'a dyn MetadataCacheRepository,
+        settings_repository: &'a dyn SettingsRepository,
+        provider: &'a dyn MetadataProvider,
+    ) -> Self {
+        MetadataServiceImpl {
+            cache_repository,
+            settings_repository,
+            provider,
+        }
+    }
+
+    fn provider_url(&self) -> Result<Option<String>, String> {
+        Ok(self.settings_repository
+            .get(PROVIDER_URL_SETTING)?
+            .map(|setting| setting.value.trim().to_string())
+            .filter(|url| !url.is_empty()))
+    }
+}
+
+impl<'a> MetadataService for MetadataServiceImpl<'a> {
+    fn validate_isbn(&self, isbn: String) -> Result<IsbnDto, String> {
+        let isbn13 = parse_isbn(&isbn)?;
+        Ok(IsbnDto {
+            isbn10: isbn13_to_10(&isbn13),
+            isbn13,
+        })
+    }
+
+    fn lookup_isbn(&self, isbn: String, refresh: bool) -> Result<BookMetadataDto, String> {
+        let isbn = parse_isbn(&isbn)?;
+        let cached = self.cache_repository.find_by_isbn(&isbn)?;
+
+        if let Some(metadata) = &cached {
+            if !refresh {
+                return Ok(BookMetadataDto::from_metadata(metadata.clone(), true));
+            }
+        }
+
+        let base_url = match self.provider_url()? {
+            Some(url) => url,
+            None => {
+                return match cached {
+                    Some(metadata) => Ok(BookMetadataDto::from_metadata(metadata, true)),
+                    None => Err(format!(
+                        "No cached metadata for ISBN {} and no metadata provider configured",
+                        isbn
+                    )),
+                };
+            }
+        };
+
+        match self.provider.fetch_by_isbn(&base_url, &isbn)? {
+            Some(metadata) => {
+                self.cache_repository.save(&metadata)?;
+                Ok(BookMetadataDto::from_metadata(metadata, false))
+            }
+            None => Err(format!("No metadata found for ISBN {}", isbn)),
+        }
+    }
+}
@@ -1,22 +1,40 @@
 pub mod agenda_service;
+pub mod author_service;
 pub mod book_service;
+pub mod chapter_service;
 pub mod collection_service;
+pub mod cover_service;
+pub mod edition_service;
+pub mod export_service;
 pub mod goal_service;
+pub mod import_service;
 pub mod journal_service;
+pub mod metadata_service;
 pub mod note_service;
 pub mod reading_service;
+pub mod series_service;
+pub mod session_photo_service;
 pub mod session_service;
 pub mod settings_service;
 pub mod statistics_service;
 pub mod tag_service;
 
 pub use agenda_service::AgendaServiceImpl;
+pub use author_service::AuthorServiceImpl;
 pub use book_service::BookServiceImpl;
+pub use chapter_service::ChapterServiceImpl;
 pub use collection_service::CollectionServiceImpl;
+pub use cover_service::CoverServiceImpl;
+pub use edition_service::EditionServiceImpl;
+pub use export_service::ExportServiceImpl;
 pub use goal_service::GoalServiceImpl;
+pub use import_service::ImportServiceImpl;
 pub use journal_service::JournalServiceImpl;
+pub use metadata_service::MetadataServiceImpl;
 pub use note_service::NoteServiceImpl;
 pub use reading_service::ReadingServiceImpl;
+pub use series_service::SeriesServiceImpl;
+pub use session_photo_service::SessionPhotoServiceImpl;
 pub use session_service::SessionServiceImpl;
 pub use settings_service::SettingsServiceImpl;
 pub use statistics_service::StatisticsServiceImpl;
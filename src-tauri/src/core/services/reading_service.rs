@@ -1,5 +1,5 @@
-use crate::app::dtos::reading_dto::{ReadingDto, CreateReadingCommand, ListReadingsFilters};
-use crate::core::domains::reading::Reading;
+use crate::app::dtos::reading_dto::{ReadingDto, CreateReadingCommand, RateReadingCommand, ListReadingsFilters};
+use crate::core::domains::reading::{Reading, ReadingStatus};
 use crate::core::domains::book::BookStatus;
 use crate::core::interfaces::primary::ReadingService;
 use crate::core::interfaces::secondary::{ReadingRepository, BookRepository};
@@ -66,5 +66,45 @@ impl<'a> ReadingService for ReadingServiceImpl<'a> {
         };
         Ok(readings.into_iter().map(|r| r.into()).collect())
     }
+
+    fn rate(&self, command: RateReadingCommand) -> Result<ReadingDto, String> {
+        let book = self.book_repository.find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+
+        let mut reading = match command.reading_id {
+            Some(reading_id) => {
+                let reading = self.reading_repository.find_by_id(reading_id)?
+                    .ok_or_else(|| format!("Reading with id {} not found", reading_id))?;
+                if reading.book_id != command.book_id {
+                    return Err(format!("Reading {} does not belong to book {}", reading_id, command.book_id));
+                }
+                reading
+            }
+            None => match self.reading_repository.find_by_book_id(command.book_id)?.pop() {
+                Some(latest) => latest,
+                None => {
+                    // First read without a tracked cycle: record it from the book's state
+                    let mut first = Reading::new(command.book_id, 1)?;
+                    first.status = match book.status {
+                        BookStatus::NotStarted => ReadingStatus::NotStarted,
+                        BookStatus::Reading | BookStatus::Rereading => ReadingStatus::Reading,
+                        BookStatus::Paused => ReadingStatus::Paused,
+                        BookStatus::Abandoned => ReadingStatus::Abandoned,
+                        BookStatus::Completed => ReadingStatus::Completed,
+                    };
+                    if first.status == ReadingStatus::Completed {
+                        first.completed_at = book.status_changed_at;
+                    }
+                    first.rate(command.rating, command.review)?;
+                    return Ok(self.reading_repository.create(&first)?.into());
+                }
+            },
+        };
+
+        reading.rate(command.rating, command.review)?;
+        let updated = self.reading_repository.update(&reading)?;
+
+        Ok(updated.into())
+    }
 }
 
@@ -0,0 +1,141 @@
+use crate::app::dtos::BookDto;
+use crate::app::dtos::series_dto::{
+    SeriesDto, SeriesDetailDto, CreateSeriesCommand, UpdateSeriesCommand, ListSeriesFilters,
+};
+use crate::core::domains::book::{Book, BookStatus};
+use crate::core::domains::series::Series;
+use crate::core::interfaces::primary::SeriesService;
+use crate::core::interfaces::secondary::{SeriesRepository, BookRepository};
+
+/// Implementation of SeriesService
+pub struct SeriesServiceImpl<'a> {
+    series_repository: &'a dyn SeriesRepository,
+    book_repository: &'a dyn BookRepository,
+}
+
+impl<'a> SeriesServiceImpl<'a> {
+    pub fn new(
+        series_repository: &'a dyn SeriesRepository,
+        book_repository: &'a dyn BookRepository,
+    ) -> Self {
+        SeriesServiceImpl {
+            series_repository,
+            book_repository,
+        }
+    }
+}
+
+impl<'a> SeriesService for SeriesServiceImpl<'a> {
+    fn create(&self, command: CreateSeriesCommand) -> Result<SeriesDto, String> {
+        // Check if series with same name already exists
+        if let Ok(Some(_)) = self.series_repository.find_by_name(command.name.trim()) {
+            return Err(format!("Series with name '{}' already exists", command.name));
+        }
+
+        // Create series entity with validation
+        let mut series = Series::new(command.name, command.author, command.expected_volumes)?;
+
+        // Save via repository
+        self.series_repository.create(&mut series)?;
+
+        Ok(SeriesDto::from(series))
+    }
+
+    fn update(&self, command: UpdateSeriesCommand) -> Result<SeriesDto, String> {
+        // Get existing series
+        let mut series = self.series_repository
+            .find_by_id(command.id)?
+            .ok_or_else(|| format!("Series with id {} not found", command.id))?;
+
+        // Update fields if provided
+        if let Some(name) = command.name {
+            series.update_name(name)?;
+        }
+
+        if let Some(author) = command.author {
+            let author = author.trim().to_string();
+            series.author = if author.is_empty() { None } else { Some(author) };
+        }
+
+        if let Some(expected_volumes) = command.expected_volumes {
+            series.update_expected_volumes(expected_volumes)?;
+        }
+
+        // Update timestamp
+        series.updated_at = chrono::Utc::now();
+
+        // Save via repository
+        self.series_repository.update(&series)?;
+
+        Ok(SeriesDto::from(series))
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        // Check if series exists
+        self.series_repository
+            .find_by_id(id)?
+            .ok_or_else(|| format!("Series with id {} not found", id))?;
+
+        // Delete via repository (ON DELETE SET NULL detaches the books)
+        self.series_repository.delete(id)?;
+
+        Ok(())
+    }
+
+    fn get(&self, id: i64) -> Result<SeriesDetailDto, String> {
+        let series = self.series_repository
+            .find_by_id(id)?
+            .ok_or_else(|| format!("Series with id {} not found", id))?;
+
+        // Books come back ordered by volume number
+        let books = self.book_repository.find_by_series_id(id)?;
+
+        let missing_volumes = series.missing_volumes(&books);
+        let completed_volumes = books
+            .iter()
+            .filter(|b| b.status == BookStatus::Completed)
+            .count() as i32;
+
+        // Next volume to read: the first unfinished one after the last completed volume
+        let last_completed_index = books
+            .iter()
+            .filter(|b| b.status == BookStatus::Completed)
+            .filter_map(|b| b.series_index)
+            .fold(0.0_f64, f64::max);
+        let next_unread = Series::next_unread(&books, last_completed_index)
+            .cloned()
+            .map(BookDto::from);
+
+        Ok(SeriesDetailDto {
+            series: SeriesDto::from(series),
+            books: books.into_iter().map(BookDto::from).collect(),
+            missing_volumes,
+            completed_volumes,
+            next_unread,
+        })
+    }
+
+    fn list(&self, _filters: ListSeriesFilters) -> Result<Vec<SeriesDto>, String> {
+        let series_list = self.series_repository.find_all()?;
+        Ok(series_list.into_iter().map(SeriesDto::from).collect())
+    }
+
+    fn continue_series(&self, book_id: i64) -> Result<Option<BookDto>, String> {
+        let book = self.book_repository
+            .find_by_id(book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", book_id))?;
+
+        Ok(next_in_series(self.book_repository, &book)?.map(BookDto::from))
+    }
+}
+
+/// Next unread volume of a completed book's series (None if unfinished or not in a series)
+pub(crate) fn next_in_series(book_repository: &dyn BookRepository, book: &Book) -> Result<Option<Book>, String> {
+    let series_id = match book.series_id {
+        Some(series_id) => series_id,
+        None => return Ok(None),
+    };
+
+    let books = book_repository.find_by_series_id(series_id)?;
+    Ok(Series::continue_after(book, &books).cloned())
+}
@@ -0,0 +1,217 @@
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use crate::app::dtos::session_photo_dto::{
+    AttachSessionPhotoCommand, SessionPhotoBackupDto, SessionPhotoDto, SessionPhotoImageDto,
+};
+use crate::core::domains::book_cover::StoredImage;
+use crate::core::domains::session_photo::SessionPhoto;
+use crate::core::interfaces::primary::SessionPhotoService;
+use crate::core::interfaces::secondary::{PhotoStorage, SessionPhotoRepository, SessionRepository};
+
+/// Implementation of SessionPhotoService
+pub struct SessionPhotoServiceImpl<'a> {
+    session_repository: &'a dyn SessionRepository,
+    photo_repository: &'a dyn SessionPhotoRepository,
+    photo_storage: &'a dyn PhotoStorage,
+}
+
+impl<'a> SessionPhotoServiceImpl<'a> {
+    pub fn new(
+        session_repository: &'a dyn SessionRepository,
+        photo_repository: &'a dyn SessionPhotoRepository,
+        photo_storage: &'a dyn PhotoStorage,
+    ) -> Self {
+        SessionPhotoServiceImpl {
+            session_repository,
+            photo_repository,
+            photo_storage,
+        }
+    }
+
+    fn find_photo(&self, photo_id: i64) -> Result<SessionPhoto, String> {
+        self.photo_repository
+            .find_by_id(photo_id)?
+            .ok_or_else(|| format!("Photo with id {} not found", photo_id))
+    }
+}
+
+impl<'a> SessionPhotoService for SessionPhotoServiceImpl<'a> {
+    fn attach_photo(&self, command: AttachSessionPhotoCommand) -> Result<SessionPhotoDto, String> {
+        if command.source.trim().is_empty() {
+            return Err("Photo path cannot be empty".to_string());
+        }
+
+        let bytes = self.photo_storage.read_external(&command.source)?;
+        let photo = link_photo(
+            self.session_repository,
+            self.photo_repository,
+            self.photo_storage,
+            command.session_id,
+            &bytes,
+            Some(command.source.trim().to_string()),
+        )?;
+        Ok(SessionPhotoDto::from(photo))
+    }
+
+    fn list_photos(&self, session_id: i64) -> Result<Vec<SessionPhotoDto>, String> {
+        let photos = self.photo_repository.find_by_session_id(session_id)?;
+        Ok(photos.into_iter().map(SessionPhotoDto::from).collect())
+    }
+
+    fn get_photo_image(&self, photo_id: i64, thumbnail: bool) -> Result<SessionPhotoImageDto, String> {
+        let photo = self.find_photo(photo_id)?;
+
+        let (file_name, mime_type) = if thumbnail {
+            (photo.thumbnail_file_name(), "image/jpeg")
+        } else {
+            (photo.file_name(), photo.mime_type())
+        };
+        let bytes = self.photo_storage.read(&file_name)?;
+
+        Ok(SessionPhotoImageDto {
+            photo_id,
+            mime_type: mime_type.to_string(),
+            data_url: format!("data:{};base64,{}", mime_type, BASE64.encode(bytes)),
+        })
+    }
+
+    fn delete_photo(&self, photo_id: i64) -> Result<(), String> {
+        let photo = self.find_photo(photo_id)?;
+        self.photo_repository.delete(photo_id)?;
+        sync_photo_path(self.session_repository, self.photo_repository, photo.session_id)?;
+        delete_unused_photo_files(self.photo_repository, self.photo_storage, &[photo])
+    }
+
+    fn cleanup_orphaned_photos(&self) -> Result<usize, String> {
+        let used: Vec<String> = self.photo_repository.find_all()?.into_iter().map(|p| p.hash).collect();
+        let mut removed = 0;
+
+        for hash in self.photo_storage.list_hashes()? {
+            if !used.contains(&hash) {
+                self.photo_storage.delete(&hash)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+
+    fn backup_photos(&self) -> Result<Vec<SessionPhotoBackupDto>, String> {
+        self.photo_repository
+            .find_all()?
+            .into_iter()
+            .map(|photo| {
+                Ok(SessionPhotoBackupDto {
+                    session_id: photo.session_id,
+                    data_base64: BASE64.encode(self.photo_storage.read(&photo.file_name())?),
+                    file_name: photo.file_name(),
+                    source: photo.source,
+                })
+            })
+            .collect()
+    }
+
+    fn restore_photos(&self, photos: Vec<SessionPhotoBackupDto>) -> Result<usize, String> {
+        let mut restored = 0;
+
+        for backup in photos {
+            if self.session_repository.find_by_id(backup.session_id)?.is_none() {
+                continue;
+            }
+            let bytes = BASE64
+                .decode(backup.data_base64.trim())
+                .map_err(|e| format!("Invalid photo data for {}: {}", backup.file_name, e))?;
+
+            // Restoring the same backup twice must not duplicate photos
+            let image = self.photo_storage.store(&bytes)?;
+            let already_linked = self
+                .photo_repository
+                .find_by_session_id(backup.session_id)?
+                .iter()
+                .any(|p| p.hash == image.hash);
+            if already_linked {
+                continue;
+            }
+
+            let mut photo = SessionPhoto::new(backup.session_id, image, backup.source);
+            self.photo_repository.create(&mut photo)?;
+            sync_photo_path(self.session_repository, self.photo_repository, backup.session_id)?;
+            restored += 1;
+        }
+
+        Ok(restored)
+    }
+}
+
+/// Stores image bytes and links them to a session
+pub(crate) fn link_photo(
+    session_repository: &dyn SessionRepository,
+    photo_repository: &dyn SessionPhotoRepository,
+    photo_storage: &dyn PhotoStorage,
+    session_id: i64,
+    bytes: &[u8],
+    source: Option<String>,
+) -> Result<SessionPhoto, String> {
+    session_repository
+        .find_by_id(session_id)?
+        .ok_or_else(|| format!("Session with id {} not found", session_id))?;
+
+    let image = photo_storage.store(bytes)?;
+    link_stored_photo(session_repository, photo_repository, session_id, image, source)
+}
+
+/// Links an image already in the photos folder to a saved session
+pub(crate) fn link_stored_photo(
+    session_repository: &dyn SessionRepository,
+    photo_repository: &dyn SessionPhotoRepository,
+    session_id: i64,
+    image: StoredImage,
+    source: Option<String>,
+) -> Result<SessionPhoto, String> {
+    let mut photo = SessionPhoto::new(session_id, image, source);
+    photo_repository.create(&mut photo)?;
+    sync_photo_path(session_repository, photo_repository, session_id)?;
+
+    Ok(photo)
+}
+
+/// Keeps the session's photo_path pointing at its first photo, or clears it
+pub(crate) fn sync_photo_path(
+    session_repository: &dyn SessionRepository,
+    photo_repository: &dyn SessionPhotoRepository,
+    session_id: i64,
+) -> Result<(), String> {
+    let mut session = match session_repository.find_by_id(session_id)? {
+        Some(session) => session,
+        None => return Ok(()),
+    };
+
+    let photo_path = photo_repository
+        .find_by_session_id(session_id)?
+        .first()
+        .map(|p| p.file_name());
+    if session.photo_path != photo_path {
+        session.photo_path = photo_path;
+        session_repository.update(&session)?;
+    }
+    Ok(())
+}
+
+/// Deletes the files of unlinked photos that no other session still uses
+pub(crate) fn delete_unused_photo_files(
+    photo_repository: &dyn SessionPhotoRepository,
+    photo_storage: &dyn PhotoStorage,
+    removed: &[SessionPhoto],
+) -> Result<(), String> {
+    if removed.is_empty() {
+        return Ok(());
+    }
+
+    let used: Vec<String> = photo_repository.find_all()?.into_iter().map(|p| p.hash).collect();
+    for photo in removed {
+        if !used.contains(&photo.hash) {
+            photo_storage.delete(&photo.hash)?;
+        }
+    }
+    Ok(())
+}
@@ -1,69 +1,104 @@
-use crate::app::dtos::{SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters};
+use crate::app::dtos::{
+    SessionDto, CreateSessionCommand, UpdateSessionCommand, ListSessionsFilters,
+    ActiveSessionDto, StartActiveSessionCommand, FinishActiveSessionCommand, SessionValidationDto,
+    CreateSessionsBulkCommand, ImportSessionsCsvCommand, SessionCsvPreviewDto, SessionCsvRowDto,
+    BulkSessionsResultDto, SessionIssueDto, ImportRowErrorDto, CorrectProgressCommand, ProgressCorrectionDto,
+};
+use crate::core::domains::active_session::ActiveSession;
+use crate::core::domains::book::{Book, BookType};
+use crate::core::domains::book_cover::StoredImage;
+use crate::core::domains::progress_calculator::ProgressCalculator;
+use crate::core::domains::progress_unit::PositionScale;
+use crate::core::domains::progress_correction::{
+    CorrectionMode, CorrectionUnit, ProgressCorrection, ProgressCorrector,
+};
 use crate::core::domains::session::ReadingSession;
+use crate::core::domains::session_context::{ReadingDevice, SessionContext};
+use crate::core::domains::session_csv::{parse_session_csv, SessionCsvRow};
+use crate::core::domains::session_validation::{parse_session_time, SessionIssue, SessionValidator};
+use crate::core::domains::timezone::{UserTimezone, TIMEZONE_SETTING};
 use crate::core::interfaces::primary::SessionService;
-use crate::core::interfaces::secondary::{SessionRepository, BookRepository};
+use crate::core::interfaces::secondary::{
+    SessionRepository, BookRepository, EditionRepository, ActiveSessionRepository, SettingsRepository,
+    ProgressCorrectionRepository, SessionPhotoRepository, PhotoStorage, ReadingRepository, TransactionManager,
+};
+use crate::core::services::session_photo_service::{delete_unused_photo_files, link_stored_photo};
+use std::collections::BTreeSet;
 
 /// Implementation of SessionService
 pub struct SessionServiceImpl<'a> {
     session_repository: &'a dyn SessionRepository,
     book_repository: &'a dyn BookRepository,
+    edition_repository: &'a dyn EditionRepository,
+    active_session_repository: &'a dyn ActiveSessionRepository,
+    settings_repository: &'a dyn SettingsRepository,
+    progress_correction_repository: &'a dyn ProgressCorrectionRepository,
+    photo_repository: &'a dyn SessionPhotoRepository,
+    photo_storage: &'a dyn PhotoStorage,
+    reading_repository: &'a dyn ReadingRepository,
+    transaction_manager: &'a dyn TransactionManager,
 }
 
 impl<'a> SessionServiceImpl<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         session_repository: &'a dyn SessionRepository,
         book_repository: &'a dyn BookRepository,
+        edition_repository: &'a dyn EditionRepository,
+        active_session_repository: &'a dyn ActiveSessionRepository,
+        settings_repository: &'a dyn SettingsRepository,
+        progress_correction_repository: &'a dyn ProgressCorrectionRepository,
+        photo_repository: &'a dyn SessionPhotoRepository,
+        photo_storage: &'a dyn PhotoStorage,
+        reading_repository: &'a dyn ReadingRepository,
+        transaction_manager: &'a dyn TransactionManager,
     ) -> Self {
         SessionServiceImpl {
             session_repository,
             book_repository,
+            edition_repository,
+            active_session_repository,
+            settings_repository,
+            progress_correction_repository,
+            photo_repository,
+            photo_storage,
+            reading_repository,
+            transaction_manager,
         }
     }
 }
 
 impl<'a> SessionService for SessionServiceImpl<'a> {
     fn create(&self, command: CreateSessionCommand) -> Result<SessionDto, String> {
-        // Validate book exists
-        self.book_repository
-            .find_by_id(command.book_id)?
-            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+        let (mut session, mut issues) = self.build_new_session(&command)?;
 
-        // Parse session date
-        let session_date = chrono::NaiveDate::parse_from_str(&command.session_date, "%Y-%m-%d")
-            .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
+        // Reject inconsistent sessions; warnings need the user's confirmation
+        issues.extend(self.consistency_issues(&session, &[])?);
+        enforce_issues(&issues, command.confirm_warnings.unwrap_or(false))?;
+        let photos = self.store_photos(command.photo_paths.as_deref())?;
 
-        // Parse times if provided
-        let start_time = command.start_time
-            .as_ref()
-            .and_then(|s| chrono::NaiveTime::parse_from_str(s, "%H:%M:%S").ok());
-        let end_time = command.end_time
-            .as_ref()
-            .and_then(|s| chrono::NaiveTime::parse_from_str(s, "%H:%M:%S").ok());
+        // Save via repository
+        self.session_repository.create(&mut session)?;
+        if let Some(id) = session.id {
+            session.photo_path = self.attach_photos(id, photos)?;
+        }
 
-        // Create session entity with validation
-        let mut session = ReadingSession::new(
-            command.book_id,
-            session_date,
-            command.start_page,
-            command.end_page,
-        )?;
+        // Recalculate book progress based on all sessions
+        self.recalculate_book_progress(session.book_id)?;
 
-        // Set optional fields
-        session.reading_id = command.reading_id;
-        session.start_time = start_time;
-        session.end_time = end_time;
-        session.minutes_read = command.minutes_read;
+        // Convert to DTO and return
+        Ok(SessionDto::from(session))
+    }
 
-        // Calculate duration if times are provided
-        if start_time.is_some() && end_time.is_some() {
-            session.calculate_duration();
-        }
+    fn update(&self, command: UpdateSessionCommand) -> Result<SessionDto, String> {
+        let (session, mut issues) = self.build_updated_session(&command)?;
 
-        // Update pages read based on start/end page
-        session.update_pages_read()?;
+        // Reject inconsistent sessions; warnings need the user's confirmation
+        issues.extend(self.consistency_issues(&session, &[])?);
+        enforce_issues(&issues, command.confirm_warnings.unwrap_or(false))?;
 
         // Save via repository
-        self.session_repository.create(&mut session)?;
+        self.session_repository.update(&session)?;
 
         // Recalculate book progress based on all sessions
         self.recalculate_book_progress(session.book_id)?;
@@ -72,67 +107,179 @@ impl<'a> SessionService for SessionServiceImpl<'a> {
         Ok(SessionDto::from(session))
     }
 
-    fn update(&self, command: UpdateSessionCommand) -> Result<SessionDto, String> {
-        // Get existing session
-        let mut session = self.session_repository
-            .find_by_id(command.id)?
-            .ok_or_else(|| format!("Session with id {} not found", command.id))?;
+    fn validate_new(&self, command: CreateSessionCommand) -> Result<SessionValidationDto, String> {
+        let (session, mut issues) = self.build_new_session(&command)?;
+        issues.extend(self.consistency_issues(&session, &[])?);
+        Ok(SessionValidationDto::from_issues(issues))
+    }
 
-        // Update fields if provided
-        if let Some(session_date_str) = command.session_date {
-            session.session_date = chrono::NaiveDate::parse_from_str(&session_date_str, "%Y-%m-%d")
-                .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
-        }
+    fn validate_update(&self, command: UpdateSessionCommand) -> Result<SessionValidationDto, String> {
+        let (session, mut issues) = self.build_updated_session(&command)?;
+        issues.extend(self.consistency_issues(&session, &[])?);
+        Ok(SessionValidationDto::from_issues(issues))
+    }
 
-        if let Some(start_time_str) = command.start_time {
-            session.start_time = if start_time_str.is_empty() {
-                None
-            } else {
-                chrono::NaiveTime::parse_from_str(&start_time_str, "%H:%M:%S").ok()
-            };
+    fn create_bulk(&self, command: CreateSessionsBulkCommand) -> Result<BulkSessionsResultDto, String> {
+        if command.sessions.is_empty() {
+            return Err("No sessions to create".to_string());
         }
 
-        if let Some(end_time_str) = command.end_time {
-            session.end_time = if end_time_str.is_empty() {
-                None
-            } else {
-                chrono::NaiveTime::parse_from_str(&end_time_str, "%H:%M:%S").ok()
-            };
+        let photo_paths: Vec<Option<Vec<String>>> = command.sessions.iter().map(|c| c.photo_paths.clone()).collect();
+        let commands = command.sessions.into_iter().enumerate().map(|(i, c)| (i + 1, c)).collect();
+        let prepared = self.prepare_batch(commands)?;
+
+        // All or nothing: any invalid session rejects the batch
+        let errors: Vec<String> = prepared
+            .iter()
+            .filter_map(|p| p.error.as_ref().map(|e| format!("Session {}: {}", p.row_number, e)))
+            .collect();
+        if !errors.is_empty() {
+            return Err(format!("Invalid sessions: {}", errors.join("; ")));
         }
 
-        if let Some(start_page) = command.start_page {
-            session.start_page = Some(start_page);
+        enforce_batch_warnings(&prepared, "Session", command.confirm_warnings.unwrap_or(false))?;
+
+        let photos = photo_paths
+            .iter()
+            .map(|paths| self.store_photos(paths.as_deref()))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let sessions = prepared.into_iter().filter_map(|p| p.session).collect();
+        let mut result = self.save_batch(sessions, Vec::new())?;
+
+        // Every session was saved, in command order
+        for (session, photos) in result.sessions.iter_mut().zip(photos) {
+            if let Some(id) = session.id {
+                session.photo_path = self.attach_photos(id, photos)?;
+            }
         }
 
-        if let Some(end_page) = command.end_page {
-            session.end_page = Some(end_page);
+        Ok(result)
+    }
+
+    fn preview_csv_import(&self, command: ImportSessionsCsvCommand) -> Result<SessionCsvPreviewDto, String> {
+        let books = self.book_repository.find_all()?;
+        let rows: Vec<SessionCsvRowDto> = self
+            .prepare_csv(&command)?
+            .into_iter()
+            .map(|(row, prepared)| to_csv_row_dto(row, prepared, &books))
+            .collect();
+
+        let error_count = rows.iter().filter(|r| r.error.is_some()).count();
+        let warning_count = rows.iter().filter(|r| r.error.is_none() && !r.warnings.is_empty()).count();
+
+        Ok(SessionCsvPreviewDto {
+            total_rows: rows.len(),
+            importable_count: rows.len() - error_count,
+            warning_count,
+            error_count,
+            rows,
+        })
+    }
+
+    fn import_csv(&self, command: ImportSessionsCsvCommand) -> Result<BulkSessionsResultDto, String> {
+        let prepared = self.prepare_csv(&command)?;
+
+        // Rows with errors are skipped and reported; the rest are saved together
+        let errors: Vec<ImportRowErrorDto> = prepared
+            .iter()
+            .filter_map(|(row, p)| p.error.as_ref().map(|e| ImportRowErrorDto {
+                row_number: p.row_number,
+                title: row.as_ref().and_then(|r| r.title.clone().or_else(|| r.isbn.clone())),
+                error: e.clone(),
+            }))
+            .collect();
+
+        let prepared: Vec<PreparedSession> = prepared.into_iter().map(|(_, p)| p).collect();
+        enforce_batch_warnings(&prepared, "Row", command.confirm_warnings.unwrap_or(false))?;
+
+        let sessions: Vec<ReadingSession> = prepared.into_iter().filter_map(|p| p.session).collect();
+        if sessions.is_empty() {
+            return Err("No importable sessions in the file".to_string());
         }
 
-        if let Some(minutes_read) = command.minutes_read {
-            session.minutes_read = Some(minutes_read);
+        self.save_batch(sessions, errors)
+    }
+
+    fn correct_progress(&self, command: CorrectProgressCommand) -> Result<ProgressCorrectionDto, String> {
+        let book = self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| "Book not found".to_string())?;
+
+        // Shared positions across editions are derived from each format's own sessions
+        if !self.edition_repository.find_by_book_id(command.book_id)?.is_empty() {
+            return Err("Progress of a book read in several editions can't be corrected here; edit its sessions instead".to_string());
         }
 
-        // Recalculate pages_read if start/end page changed
-        if session.start_page.is_some() && session.end_page.is_some() {
-            session.update_pages_read()?;
+        let mode = CorrectionMode::parse(&command.mode)?;
+        let (unit, previous_value, corrected_value, total) = match book.book_type {
+            BookType::Audiobook => (
+                CorrectionUnit::Minutes,
+                book.current_minutes_audio,
+                command.current_minutes.ok_or("current_minutes is required for an audiobook".to_string())?,
+                book.total_minutes,
+            ),
+            _ => (
+                CorrectionUnit::Pages,
+                book.current_page_text,
+                command.current_page.ok_or("current_page is required for this book".to_string())?,
+                book.total_pages,
+            ),
+        };
+
+        if corrected_value < 0 {
+            return Err("Corrected progress cannot be negative".to_string());
+        }
+        if let Some(total) = total.filter(|t| corrected_value > *t) {
+            return Err(format!("Corrected progress ({}) exceeds the book's total ({})", corrected_value, total));
         }
+        if corrected_value == previous_value {
+            return Err(format!("Progress is already at {}", corrected_value));
+        }
+
+        let sessions = ProgressCorrector::chronological(self.session_repository.find_by_book_id(command.book_id)?);
+        let plan = match mode {
+            CorrectionMode::AdjustmentSession => {
+                let today = user_timezone_setting(self.settings_repository)?.today();
+                ProgressCorrector::plan_adjustment(command.book_id, &sessions, unit, previous_value, corrected_value, today)?
+            }
+            CorrectionMode::Rebase => {
+                ProgressCorrector::plan_rebase(&sessions, command.from_session_id, unit, corrected_value, total)?
+            }
+        };
 
-        // Recalculate duration if times changed
-        if session.start_time.is_some() && session.end_time.is_some() {
-            session.calculate_duration();
+        // Sessions recorded as percentages or locations keep positions matching their new pages
+        let mut updated = plan.updated;
+        if let Ok(scale) = PositionScale::for_book(&book) {
+            for session in updated.iter_mut() {
+                session.sync_positions(&scale);
+            }
         }
 
-        // Update timestamp
-        session.updated_at = chrono::Utc::now();
+        // The rewritten history and its audit entry are saved together
+        let mut correction = ProgressCorrection {
+            id: None,
+            book_id: command.book_id,
+            mode,
+            unit,
+            previous_value,
+            corrected_value,
+            adjustment_session_id: None,
+            changes: plan.changes,
+            note: command.note.filter(|n| !n.trim().is_empty()),
+            created_at: chrono::Utc::now(),
+        };
+        let mut adjustment = plan.adjustment;
+        self.progress_correction_repository.create(&mut correction, adjustment.as_mut(), &updated)?;
 
-        // Save via repository
-        self.session_repository.update(&session)?;
+        self.recalculate_book_progress(command.book_id)?;
 
-        // Recalculate book progress based on all sessions
-        self.recalculate_book_progress(session.book_id)?;
+        Ok(ProgressCorrectionDto::from(correction))
+    }
 
-        // Convert to DTO and return
-        Ok(SessionDto::from(session))
+    fn list_progress_corrections(&self, book_id: i64) -> Result<Vec<ProgressCorrectionDto>, String> {
+        let corrections = self.progress_correction_repository.find_by_book_id(book_id)?;
+        Ok(corrections.into_iter().map(ProgressCorrectionDto::from).collect())
     }
 
     fn delete(&self, id: i64) -> Result<(), String> {
@@ -142,9 +289,11 @@ impl<'a> SessionService for SessionServiceImpl<'a> {
             .ok_or_else(|| format!("Session with id {} not found", id))?;
 
         let book_id = session.book_id;
+        let photos = self.photo_repository.find_by_session_id(id)?;
 
-        // Delete via repository
+        // Delete via repository (photo links are removed with the session)
         self.session_repository.delete(id)?;
+        delete_unused_photo_files(self.photo_repository, self.photo_storage, &photos)?;
 
         // Recalculate book progress based on remaining sessions
         self.recalculate_book_progress(book_id)?;
@@ -190,9 +339,487 @@ impl<'a> SessionService for SessionServiceImpl<'a> {
 
         Ok(sessions.into_iter().map(SessionDto::from).collect())
     }
+
+    fn start_timer(&self, command: StartActiveSessionCommand) -> Result<ActiveSessionDto, String> {
+        if self.active_session_repository.find_current()?.is_some() {
+            return Err("A session timer is already running. Finish or cancel it first".to_string());
+        }
+
+        let book = self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+
+        if let Some(edition_id) = command.edition_id {
+            self.validate_edition(edition_id, command.book_id)?;
+        }
+        if let Some(reading_id) = command.reading_id {
+            self.validate_reading(reading_id, command.book_id)?;
+        }
+
+        // Text books continue from where the reader left off
+        let start_page = match (command.start_page, &book.book_type) {
+            (Some(page), _) => Some(page),
+            (None, BookType::Audiobook) => None,
+            (None, _) => Some(book.current_page_text),
+        };
+
+        let now = chrono::Utc::now();
+        let mut active = ActiveSession::new(command.book_id, start_page, now)?;
+        active.reading_id = command.reading_id;
+        active.edition_id = command.edition_id;
+
+        self.active_session_repository.create(&mut active)?;
+
+        Ok(ActiveSessionDto::from_session(&active, now))
+    }
+
+    fn pause_timer(&self) -> Result<ActiveSessionDto, String> {
+        let mut active = self.current_timer()?;
+        let now = chrono::Utc::now();
+
+        active.pause(now)?;
+        self.active_session_repository.update(&active)?;
+
+        Ok(ActiveSessionDto::from_session(&active, now))
+    }
+
+    fn resume_timer(&self) -> Result<ActiveSessionDto, String> {
+        let mut active = self.current_timer()?;
+        let now = chrono::Utc::now();
+
+        active.resume(now)?;
+        self.active_session_repository.update(&active)?;
+
+        Ok(ActiveSessionDto::from_session(&active, now))
+    }
+
+    fn get_active_timer(&self) -> Result<Option<ActiveSessionDto>, String> {
+        let now = chrono::Utc::now();
+        let active = self.active_session_repository.find_current()?;
+
+        Ok(active.map(|a| ActiveSessionDto::from_session(&a, now)))
+    }
+
+    fn finish_timer(&self, command: FinishActiveSessionCommand) -> Result<SessionDto, String> {
+        let active = self.current_timer()?;
+        let active_id = active.id.ok_or("Active session has no ID".to_string())?;
+
+        let book = self.book_repository
+            .find_by_id(active.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", active.book_id))?;
+
+        let now = chrono::Utc::now();
+        let elapsed_seconds = active.elapsed_seconds(now);
+        let timezone = self.user_timezone(None)?;
+
+        let mut session = ReadingSession::new(
+            active.book_id,
+            timezone.date_of(active.started_at),
+            active.start_page,
+            command.end_page,
+        )?;
+
+        // Date and times are recorded in the user's zone; pauses don't count towards the duration
+        session.set_span(active.started_at, active.ended_at(now), &timezone)?;
+        session.reading_id = active.reading_id;
+        session.edition_id = active.edition_id;
+        session.duration_seconds = Some(elapsed_seconds as i32);
+        session.set_playback_speed(command.playback_speed)?;
+        session.minutes_read = match (command.minutes_read, &book.book_type) {
+            (Some(minutes), _) => Some(minutes),
+            (None, BookType::Audiobook) => session.content_minutes_from_duration(),
+            (None, _) => None,
+        };
+
+        session.update_pages_read()?;
+
+        // The timer starts at a page; a percentage or location end is converted to pages
+        if let Some(end_position) = command.end_position {
+            if command.end_page.is_some() {
+                return Err("Give either an end page or an end position, not both".to_string());
+            }
+            let scale = self.position_scale(&book, session.edition_id)?;
+            let start_position = session.start_page.map(|p| scale.from_page(p));
+            session.set_positions(&scale, start_position, Some(end_position))?;
+            session.start_page = active.start_page;
+            session.update_pages_read()?;
+        }
+
+        let issues = self.consistency_issues(&session, &[])?;
+        enforce_issues(&issues, command.confirm_warnings.unwrap_or(false))?;
+
+        // The session, the stopped timer and the book's progress are saved together,
+        // so a failure part-way can't leave the timer running to be finished twice
+        self.transaction_manager.run(&mut || {
+            self.session_repository.create(&mut session)?;
+            self.active_session_repository.delete(active_id)?;
+            self.recalculate_book_progress(session.book_id)
+        })?;
+
+        Ok(SessionDto::from(session))
+    }
+
+    fn cancel_timer(&self) -> Result<(), String> {
+        let active = self.current_timer()?;
+        let active_id = active.id.ok_or("Active session has no ID".to_string())?;
+
+        self.active_session_repository.delete(active_id)
+    }
 }
 
 impl<'a> SessionServiceImpl<'a> {
+    /// Scale converting a book's percentage or location positions to pages
+    /// Editions have their own page counts, so positions are only recorded for the book's own format
+    fn position_scale(&self, book: &Book, edition_id: Option<i64>) -> Result<PositionScale, String> {
+        if edition_id.is_some() {
+            return Err("Positions can only be recorded for the book's own format; record pages for editions".to_string());
+        }
+        PositionScale::for_book(book)
+    }
+
+    /// Checks that the edition exists and belongs to the book
+    fn validate_edition(&self, edition_id: i64, book_id: i64) -> Result<(), String> {
+        let edition = self.edition_repository
+            .find_by_id(edition_id)?
+            .ok_or_else(|| format!("Edition with id {} not found", edition_id))?;
+
+        if edition.book_id != book_id {
+            return Err(format!("Edition {} does not belong to book {}", edition_id, book_id));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the reading exists and belongs to the book
+    fn validate_reading(&self, reading_id: i64, book_id: i64) -> Result<(), String> {
+        let reading = self.reading_repository
+            .find_by_id(reading_id)?
+            .ok_or_else(|| format!("Reading with id {} not found", reading_id))?;
+
+        if reading.book_id != book_id {
+            return Err(format!("Reading {} does not belong to book {}", reading_id, book_id));
+        }
+
+        Ok(())
+    }
+
+    /// Builds the session a create command describes, without saving it
+    /// Malformed times are returned as issues instead of being dropped
+    fn build_new_session(&self, command: &CreateSessionCommand) -> Result<(ReadingSession, Vec<SessionIssue>), String> {
+        // Validate book exists
+        let book = self.book_repository
+            .find_by_id(command.book_id)?
+            .ok_or_else(|| format!("Book with id {} not found", command.book_id))?;
+
+        // Validate the edition belongs to this book
+        if let Some(edition_id) = command.edition_id {
+            self.validate_edition(edition_id, command.book_id)?;
+        }
+
+        // Parse session date
+        let session_date = chrono::NaiveDate::parse_from_str(&command.session_date, "%Y-%m-%d")
+            .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
+
+        // Parse times if provided
+        let mut issues = Vec::new();
+        let start_time = collect_time("start time", command.start_time.as_deref(), &mut issues);
+        let end_time = collect_time("end time", command.end_time.as_deref(), &mut issues);
+
+        // Create session entity with validation
+        let mut session = ReadingSession::new(
+            command.book_id,
+            session_date,
+            command.start_page,
+            command.end_page,
+        )?;
+
+        // Set optional fields
+        session.reading_id = command.reading_id;
+        session.edition_id = command.edition_id;
+        session.start_time = start_time;
+        session.end_time = end_time;
+        session.minutes_read = command.minutes_read;
+        session.context = SessionContext {
+            location: command.location.clone(),
+            device: parse_device(command.device.as_deref())?,
+            mood_before: command.mood_before,
+            mood_after: command.mood_after,
+            energy_before: command.energy_before,
+            energy_after: command.energy_after,
+            interruptions: command.interruptions,
+        }
+        .normalize()?;
+
+        // Resolve times to full timestamps (and duration) in the user's zone
+        let timezone = self.user_timezone(command.timezone.as_deref())?;
+        session.resolve_times(&timezone)?;
+
+        // Timed audiobook sessions without minutes cover the listened time at the playback speed
+        session.set_playback_speed(command.playback_speed)?;
+        if session.minutes_read.is_none() && book.book_type == BookType::Audiobook {
+            session.minutes_read = session.content_minutes_from_duration();
+        }
+
+        // Percentage and location books may record positions, converted to pages
+        if command.start_position.is_some() || command.end_position.is_some() {
+            if command.start_page.is_some() || command.end_page.is_some() {
+                return Err("Give either pages or positions for a session, not both".to_string());
+            }
+            let scale = self.position_scale(&book, session.edition_id)?;
+            session.set_positions(&scale, command.start_position, command.end_position)?;
+        }
+
+        // Update pages read based on start/end page
+        session.update_pages_read()?;
+
+        Ok((session, issues))
+    }
+
+    /// Applies an update command to the stored session, without saving it
+    fn build_updated_session(&self, command: &UpdateSessionCommand) -> Result<(ReadingSession, Vec<SessionIssue>), String> {
+        // Get existing session
+        let mut session = self.session_repository
+            .find_by_id(command.id)?
+            .ok_or_else(|| format!("Session with id {} not found", command.id))?;
+        let original_times = (session.session_date, session.start_time, session.end_time);
+
+        // Update fields if provided
+        if let Some(session_date_str) = &command.session_date {
+            session.session_date = chrono::NaiveDate::parse_from_str(session_date_str, "%Y-%m-%d")
+                .map_err(|e| format!("Invalid date format: {}. Expected YYYY-MM-DD", e))?;
+        }
+
+        // An empty time clears it
+        let mut issues = Vec::new();
+        if let Some(start_time_str) = &command.start_time {
+            session.start_time = collect_time("start time", Some(start_time_str), &mut issues);
+        }
+
+        if let Some(end_time_str) = &command.end_time {
+            session.end_time = collect_time("end time", Some(end_time_str), &mut issues);
+        }
+
+        if let Some(start_page) = command.start_page {
+            session.start_page = Some(start_page);
+        }
+
+        if let Some(end_page) = command.end_page {
+            session.end_page = Some(end_page);
+        }
+
+        if let Some(minutes_read) = command.minutes_read {
+            session.minutes_read = Some(minutes_read);
+        }
+
+        if command.playback_speed.is_some() {
+            session.set_playback_speed(command.playback_speed)?;
+        }
+
+        if let Some(edition_id) = command.edition_id {
+            self.validate_edition(edition_id, session.book_id)?;
+            session.edition_id = Some(edition_id);
+        }
+
+        let mut context = session.context.clone();
+        if command.location.is_some() {
+            context.location = command.location.clone();
+        }
+        if command.device.is_some() {
+            context.device = parse_device(command.device.as_deref())?;
+        }
+        context.mood_before = command.mood_before.or(context.mood_before);
+        context.mood_after = command.mood_after.or(context.mood_after);
+        context.energy_before = command.energy_before.or(context.energy_before);
+        context.energy_after = command.energy_after.or(context.energy_after);
+        context.interruptions = command.interruptions.or(context.interruptions);
+        session.context = context.normalize()?;
+
+        // New positions replace the pages; pages given directly replace recorded positions
+        if command.start_position.is_some() || command.end_position.is_some() {
+            let book = self.book_repository
+                .find_by_id(session.book_id)?
+                .ok_or_else(|| format!("Book with id {} not found", session.book_id))?;
+            let scale = self.position_scale(&book, session.edition_id)?;
+            let start = command.start_position.or(session.start_position);
+            let end = command.end_position.or(session.end_position);
+            session.set_positions(&scale, start, end)?;
+        } else if command.start_page.is_some() || command.end_page.is_some() {
+            session.clear_positions();
+        }
+
+        // Recalculate pages_read if start/end page changed
+        if session.start_page.is_some() && session.end_page.is_some() {
+            session.update_pages_read()?;
+        }
+
+        // Re-resolve timestamps (and duration) only when the date, times or zone change,
+        // so a timer session keeps its pause-excluded duration
+        let timezone_changed = command.timezone.is_some() && command.timezone != session.timezone;
+        if timezone_changed || original_times != (session.session_date, session.start_time, session.end_time) {
+            let timezone_name = command.timezone.clone().or_else(|| session.timezone.clone());
+            let timezone = self.user_timezone(timezone_name.as_deref())?;
+            session.resolve_times(&timezone)?;
+        }
+
+        // Update timestamp
+        session.updated_at = chrono::Utc::now();
+
+        Ok((session, issues))
+    }
+
+    /// Checks a session against the length of the format read and the other sessions
+    /// (this book's, for page order, and any book's around the same days, for overlaps)
+    /// `pending` holds sessions of the same batch that are not saved yet
+    fn consistency_issues(&self, session: &ReadingSession, pending: &[ReadingSession]) -> Result<Vec<SessionIssue>, String> {
+        let total_pages = match session.edition_id {
+            Some(edition_id) => self.edition_repository
+                .find_by_id(edition_id)?
+                .and_then(|edition| edition.total_pages),
+            None => self.book_repository
+                .find_by_id(session.book_id)?
+                .and_then(|book| book.total_pages),
+        };
+
+        let mut others = self.session_repository.find_by_book_id(session.book_id)?;
+        let from = session.session_date.pred_opt().unwrap_or(session.session_date);
+        let to = session.session_date.succ_opt().unwrap_or(session.session_date);
+        others.extend(
+            self.session_repository
+                .find_by_date_range(from, to)?
+                .into_iter()
+                .filter(|other| other.book_id != session.book_id),
+        );
+        others.extend(pending.iter().cloned());
+
+        Ok(SessionValidator::validate(session, total_pages, &others))
+    }
+
+    /// Builds and checks each session of a batch against the saved ones and the
+    /// batch's earlier sessions; sessions with errors are left out of the batch
+    fn prepare_batch(&self, commands: Vec<(usize, CreateSessionCommand)>) -> Result<Vec<PreparedSession>, String> {
+        let mut accepted: Vec<ReadingSession> = Vec::new();
+        let mut prepared = Vec::new();
+
+        for (row_number, command) in commands {
+            let (session, mut issues) = match self.build_new_session(&command) {
+                Ok(built) => built,
+                Err(e) => {
+                    prepared.push(PreparedSession::failed(row_number, e));
+                    continue;
+                }
+            };
+
+            issues.extend(self.consistency_issues(&session, &accepted)?);
+            let errors: Vec<String> = issues.iter().filter(|i| i.is_error()).map(|i| i.message.clone()).collect();
+            if !errors.is_empty() {
+                prepared.push(PreparedSession::failed(row_number, errors.join("; ")));
+                continue;
+            }
+
+            accepted.push(session.clone());
+            prepared.push(PreparedSession {
+                row_number,
+                session: Some(session),
+                warnings: issues,
+                error: None,
+            });
+        }
+
+        Ok(prepared)
+    }
+
+    /// Matches CSV rows to books and prepares them as a batch
+    /// Returns the parsed rows (None for unreadable ones) alongside their preparation
+    fn prepare_csv(&self, command: &ImportSessionsCsvCommand) -> Result<Vec<(Option<SessionCsvRow>, PreparedSession)>, String> {
+        let (rows, parse_errors) = parse_session_csv(&command.content)?;
+        let books = self.book_repository.find_all()?;
+
+        let mut results: Vec<(Option<SessionCsvRow>, PreparedSession)> = parse_errors
+            .into_iter()
+            .map(|(row_number, error)| (None, PreparedSession::failed(row_number, error)))
+            .collect();
+
+        let mut commands = Vec::new();
+        let mut matched_rows = Vec::new();
+        for row in rows {
+            match row.match_book(&books) {
+                Ok(book) => {
+                    commands.push((row.row_number, csv_row_to_command(&row, book.id.unwrap_or(0), command.timezone.clone())));
+                    matched_rows.push(row);
+                }
+                Err(e) => results.push((Some(row.clone()), PreparedSession::failed(row.row_number, e))),
+            }
+        }
+
+        let prepared = self.prepare_batch(commands)?;
+        results.extend(matched_rows.into_iter().map(Some).zip(prepared));
+        results.sort_by_key(|(_, prepared)| prepared.row_number);
+
+        Ok(results)
+    }
+
+    /// Saves a batch in one transaction, then recalculates each affected book once
+    fn save_batch(&self, mut sessions: Vec<ReadingSession>, errors: Vec<ImportRowErrorDto>) -> Result<BulkSessionsResultDto, String> {
+        self.session_repository.create_many(&mut sessions)?;
+
+        let book_ids: BTreeSet<i64> = sessions.iter().map(|s| s.book_id).collect();
+        for book_id in &book_ids {
+            self.recalculate_book_progress(*book_id)?;
+        }
+
+        Ok(BulkSessionsResultDto {
+            created_count: sessions.len(),
+            sessions: sessions.into_iter().map(SessionDto::from).collect(),
+            recalculated_book_ids: book_ids.into_iter().collect(),
+            errors,
+        })
+    }
+
+    /// Stores the photos to attach to a new session, so a bad path or image
+    /// fails before anything is saved (unlinked files are removed by the photo cleanup)
+    fn store_photos(&self, paths: Option<&[String]>) -> Result<Vec<(String, StoredImage)>, String> {
+        paths
+            .unwrap_or_default()
+            .iter()
+            .filter(|path| !path.trim().is_empty())
+            .map(|path| {
+                let bytes = self.photo_storage.read_external(path)?;
+                Ok((path.trim().to_string(), self.photo_storage.store(&bytes)?))
+            })
+            .collect()
+    }
+
+    /// Links stored photos to a saved session; returns the session's photo_path
+    fn attach_photos(&self, session_id: i64, photos: Vec<(String, StoredImage)>) -> Result<Option<String>, String> {
+        let mut first = None;
+        for (source, image) in photos {
+            let photo = link_stored_photo(
+                self.session_repository,
+                self.photo_repository,
+                session_id,
+                image,
+                Some(source),
+            )?;
+            first.get_or_insert(photo.file_name());
+        }
+        Ok(first)
+    }
+
+    /// Time zone for a session: the given one, else the timezone setting, else the system zone
+    fn user_timezone(&self, name: Option<&str>) -> Result<UserTimezone, String> {
+        match name {
+            Some(name) => UserTimezone::parse(name),
+            None => user_timezone_setting(self.settings_repository),
+        }
+    }
+
+    /// Gets the running session timer, or an error if none is running
+    fn current_timer(&self) -> Result<ActiveSession, String> {
+        self.active_session_repository
+            .find_current()?
+            .ok_or_else(|| "No session timer is running".to_string())
+    }
+
     /// Recalculates book progress based on all sessions for the book
     /// Sets current_page_text to the end_page of the most recent session
     /// Sets current_minutes_audio to the sum of all minutes_read from all sessions
@@ -204,6 +831,13 @@ impl<'a> SessionServiceImpl<'a> {
         // Get all sessions for this book
         let mut sessions = self.session_repository.find_by_book_id(book_id)?;
 
+        // Books with several editions share one position across formats
+        let editions = self.edition_repository.find_by_book_id(book_id)?;
+        if !editions.is_empty() {
+            let fraction = ProgressCalculator::unified_fraction(&book, &editions, &sessions);
+            return self.apply_unified_progress(book, fraction);
+        }
+
         // Sort sessions by date (most recent first), then by created_at if dates are equal
         sessions.sort_by(|a, b| {
             let date_cmp = b.session_date.cmp(&a.session_date);
@@ -243,5 +877,145 @@ impl<'a> SessionServiceImpl<'a> {
 
         Ok(())
     }
+
+    /// Moves the book's own page and minute counters to the shared position
+    fn apply_unified_progress(&self, mut book: Book, fraction: f64) -> Result<(), String> {
+        if let Some(total_pages) = book.total_pages {
+            book.update_current_page((fraction * total_pages as f64).round() as i32)?;
+        }
+
+        if let Some(total_minutes) = book.total_minutes {
+            book.update_current_minutes_audio((fraction * total_minutes as f64).round() as i32)?;
+        }
+
+        self.book_repository.update(&book)?;
+
+        Ok(())
+    }
+}
+
+/// Reads the timezone setting, falling back to the system zone
+pub(crate) fn user_timezone_setting(settings_repository: &dyn SettingsRepository) -> Result<UserTimezone, String> {
+    match settings_repository.get(TIMEZONE_SETTING)? {
+        Some(setting) => UserTimezone::parse(&setting.value),
+        None => Ok(UserTimezone::System),
+    }
+}
+
+/// Parses a time field, recording a malformed value as an issue
+fn collect_time(field: &str, value: Option<&str>, issues: &mut Vec<SessionIssue>) -> Option<chrono::NaiveTime> {
+    match parse_session_time(field, value) {
+        Ok(time) => time,
+        Err(issue) => {
+            issues.push(issue);
+            None
+        }
+    }
 }
 
+/// Fails on any error, or on warnings the user has not confirmed
+fn enforce_issues(issues: &[SessionIssue], warnings_confirmed: bool) -> Result<(), String> {
+    let errors: Vec<&str> = issues.iter().filter(|i| i.is_error()).map(|i| i.message.as_str()).collect();
+    if !errors.is_empty() {
+        return Err(format!("Invalid session: {}", errors.join("; ")));
+    }
+
+    let warnings: Vec<&str> = issues.iter().filter(|i| !i.is_error()).map(|i| i.message.as_str()).collect();
+    if !warnings.is_empty() && !warnings_confirmed {
+        return Err(format!("Session needs confirmation: {}", warnings.join("; ")));
+    }
+
+    Ok(())
+}
+
+/// A session of a batch, checked but not saved yet
+struct PreparedSession {
+    row_number: usize,
+    session: Option<ReadingSession>, // None when the row has an error
+    warnings: Vec<SessionIssue>,
+    error: Option<String>,
+}
+
+impl PreparedSession {
+    fn failed(row_number: usize, error: String) -> Self {
+        PreparedSession {
+            row_number,
+            session: None,
+            warnings: Vec::new(),
+            error: Some(error),
+        }
+    }
+}
+
+/// Parses an optional device name; an empty value means none
+fn parse_device(value: Option<&str>) -> Result<Option<ReadingDevice>, String> {
+    value
+        .filter(|v| !v.trim().is_empty())
+        .map(ReadingDevice::parse)
+        .transpose()
+}
+
+/// Fails when a batch has warnings the user has not confirmed
+fn enforce_batch_warnings(prepared: &[PreparedSession], label: &str, warnings_confirmed: bool) -> Result<(), String> {
+    let warnings: Vec<String> = prepared
+        .iter()
+        .flat_map(|p| p.warnings.iter().map(move |w| format!("{} {}: {}", label, p.row_number, w.message)))
+        .collect();
+
+    if !warnings.is_empty() && !warnings_confirmed {
+        return Err(format!("Sessions need confirmation: {}", warnings.join("; ")));
+    }
+
+    Ok(())
+}
+
+fn csv_row_to_command(row: &SessionCsvRow, book_id: i64, timezone: Option<String>) -> CreateSessionCommand {
+    CreateSessionCommand {
+        book_id,
+        reading_id: None,
+        edition_id: None,
+        session_date: row.session_date.format("%Y-%m-%d").to_string(),
+        start_time: row.start_time.clone(),
+        end_time: row.end_time.clone(),
+        timezone,
+        start_page: row.start_page,
+        end_page: row.end_page,
+        minutes_read: row.minutes_read,
+        playback_speed: None,
+        start_position: None,
+        end_position: None,
+        confirm_warnings: None,
+        photo_paths: None,
+        location: None,
+        device: None,
+        mood_before: None,
+        mood_after: None,
+        energy_before: None,
+        energy_after: None,
+        interruptions: None,
+    }
+}
+
+fn to_csv_row_dto(row: Option<SessionCsvRow>, prepared: PreparedSession, books: &[Book]) -> SessionCsvRowDto {
+    let book_id = prepared.session.as_ref().map(|s| s.book_id)
+        .or_else(|| row.as_ref().and_then(|r| r.match_book(books).ok()).and_then(|b| b.id));
+    let book_title = book_id
+        .and_then(|id| books.iter().find(|b| b.id == Some(id)))
+        .map(|b| b.title.clone());
+
+    SessionCsvRowDto {
+        row_number: prepared.row_number,
+        title: row.as_ref().and_then(|r| r.title.clone()),
+        isbn: row.as_ref().and_then(|r| r.isbn.clone()),
+        book_id,
+        book_title,
+        session_date: row.as_ref().map(|r| r.session_date.format("%Y-%m-%d").to_string()),
+        start_time: row.as_ref().and_then(|r| r.start_time.clone()),
+        end_time: row.as_ref().and_then(|r| r.end_time.clone()),
+        start_page: row.as_ref().and_then(|r| r.start_page),
+        end_page: row.as_ref().and_then(|r| r.end_page),
+        minutes_read: row.as_ref().and_then(|r| r.minutes_read),
+        warnings: prepared.warnings.into_iter().map(SessionIssueDto::from).collect(),
+        error: prepared.error,
+    }
+}
@@ -1,5 +1,7 @@
 use crate::app::dtos::SettingDto;
+use crate::core::domains::progress_unit::{ProgressUnit, PROGRESS_UNIT_SETTING};
 use crate::core::domains::setting::Setting;
+use crate::core::domains::timezone::{UserTimezone, TIMEZONE_SETTING};
 use crate::core::interfaces::primary::SettingsService;
 use crate::core::interfaces::secondary::SettingsRepository;
 
@@ -26,6 +28,14 @@ impl<'a> SettingsService for SettingsServiceImpl<'a> {
     }
 
     fn set(&self, key: String, value: String) -> Result<(), String> {
+        // Reject unknown zones so statistics never read an unusable value
+        if key == TIMEZONE_SETTING {
+            UserTimezone::parse(&value)?;
+        }
+        if key == PROGRESS_UNIT_SETTING {
+            ProgressUnit::parse_default(&value)?;
+        }
+
         let setting = Setting::new(key, value);
         self.settings_repository.set(&setting)?;
         Ok(())
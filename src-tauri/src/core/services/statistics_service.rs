@@ -1,34 +1,48 @@
-use crate::app::dtos::statistics_dto::{StatisticsDto, TodayStatistics, MonthStatistics, CurrentBookStatistics};
+use crate::app::dtos::statistics_dto::{
+    StatisticsDto, TodayStatistics, MonthStatistics, CurrentBookStatistics, ReadingSpeedStatistics,
+    ContextStatisticsFilters, ContextStatisticsDto, ContextBucketDto,
+};
 use crate::core::interfaces::primary::StatisticsService;
-use crate::core::interfaces::secondary::{SessionRepository, BookRepository};
+use crate::core::interfaces::secondary::{SessionRepository, BookRepository, SettingsRepository};
 use crate::core::domains::book::BookStatus;
+use crate::core::domains::reading_speed::SpeedModel;
+use crate::core::domains::session::ReadingSession;
+use crate::core::domains::session_context::{ContextAnalysis, ContextBucket};
+use crate::core::domains::timezone::UserTimezone;
+use crate::core::services::session_service::user_timezone_setting;
 use chrono::Datelike;
 
 /// Implementation of StatisticsService
 pub struct StatisticsServiceImpl<'a> {
     session_repository: &'a dyn SessionRepository,
     book_repository: &'a dyn BookRepository,
+    settings_repository: &'a dyn SettingsRepository,
 }
 
 impl<'a> StatisticsServiceImpl<'a> {
     pub fn new(
         session_repository: &'a dyn SessionRepository,
         book_repository: &'a dyn BookRepository,
+        settings_repository: &'a dyn SettingsRepository,
     ) -> Self {
         StatisticsServiceImpl {
             session_repository,
             book_repository,
+            settings_repository,
         }
     }
 }
 
 impl<'a> StatisticsService for StatisticsServiceImpl<'a> {
     fn get(&self) -> Result<StatisticsDto, String> {
-        let today = chrono::Utc::now().date_naive();
-        
-        // Get today's sessions
-        let today_sessions = self.session_repository.find_by_date_range(today, today)?;
-        let today_stats = calculate_today_statistics(&today_sessions);
+        // Days are split in the user's time zone
+        let timezone = user_timezone_setting(self.settings_repository)?;
+        let today = timezone.today();
+
+        // Get today's sessions, plus yesterday's in case one ran past midnight
+        let yesterday = today.pred_opt().unwrap_or(today);
+        let recent_sessions = self.session_repository.find_by_date_range(yesterday, today)?;
+        let today_stats = calculate_today_statistics(&recent_sessions, today, &timezone);
 
         // Get this month's sessions
         let month_start = chrono::NaiveDate::from_ymd_opt(today.year(), today.month(), 1)
@@ -53,42 +67,106 @@ impl<'a> StatisticsService for StatisticsServiceImpl<'a> {
                 }
             });
 
+        // Speeds across the whole library
+        let model = SpeedModel::build(&self.session_repository.find_speed_samples()?, Vec::new());
+        let reading_speed = ReadingSpeedStatistics {
+            pages_per_hour: model.global_pages.as_ref().map(|s| (s.units_per_hour * 100.0).round() / 100.0),
+            listening_speed: model.global_audio.as_ref().map(|s| (s.units_per_minute() * 100.0).round() / 100.0),
+            page_sessions_used: model.global_pages.as_ref().map(|s| s.sessions_used as i32).unwrap_or(0),
+            audio_sessions_used: model.global_audio.as_ref().map(|s| s.sessions_used as i32).unwrap_or(0),
+        };
+
         Ok(StatisticsDto {
             today: today_stats,
             this_month: month_stats,
             current_book,
+            reading_speed,
+        })
+    }
+
+    fn get_context(&self, filters: ContextStatisticsFilters) -> Result<ContextStatisticsDto, String> {
+        let parse = |value: &str, label: &str| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map_err(|e| format!("Invalid {} date: {}", label, e))
+        };
+        let start = filters.start_date.as_deref().map(|d| parse(d, "start")).transpose()?;
+        let end = filters.end_date.as_deref().map(|d| parse(d, "end")).transpose()?;
+
+        let mut sessions = match filters.book_id {
+            Some(book_id) => self.session_repository.find_by_book_id(book_id)?,
+            None => self.session_repository.find_all()?,
+        };
+        sessions.retain(|s| {
+            start.map(|d| s.session_date >= d).unwrap_or(true) && end.map(|d| s.session_date <= d).unwrap_or(true)
+        });
+
+        let to_dtos = |buckets: Vec<ContextBucket>| buckets.into_iter().map(ContextBucketDto::from).collect();
+        Ok(ContextStatisticsDto {
+            by_location: to_dtos(ContextAnalysis::by_location(&sessions)),
+            by_device: to_dtos(ContextAnalysis::by_device(&sessions)),
+            by_time_of_day: to_dtos(ContextAnalysis::by_time_of_day(&sessions)),
         })
     }
 }
 
-fn calculate_today_statistics(sessions: &[crate::core::domains::session::ReadingSession]) -> TodayStatistics {
+/// Pages, minutes and session count go to the day a session started;
+/// reading time is split across local days when a session crosses midnight
+fn calculate_today_statistics(sessions: &[ReadingSession], today: chrono::NaiveDate, timezone: &UserTimezone) -> TodayStatistics {
     let mut pages_read = 0;
     let mut minutes_read = 0;
+    let mut listening_seconds = 0;
+    let mut sessions_count = 0;
     let mut duration_seconds = 0;
 
     for session in sessions {
+        duration_seconds += seconds_on_day(session, today, timezone);
+
+        if session.session_date != today {
+            continue;
+        }
+
+        sessions_count += 1;
         if let Some(pages) = session.pages_read {
             pages_read += pages;
         }
         if let Some(minutes) = session.minutes_read {
             minutes_read += minutes;
         }
-        if let Some(duration) = session.duration_seconds {
-            duration_seconds += duration;
-        }
+        listening_seconds += session.listening_seconds().unwrap_or(0);
     }
 
     TodayStatistics {
+        date: today.format("%Y-%m-%d").to_string(),
         pages_read,
         minutes_read,
-        sessions_count: sessions.len() as i32,
+        listening_minutes: listening_seconds / 60,
+        sessions_count,
         duration_seconds,
     }
 }
 
-fn calculate_month_statistics(sessions: &[crate::core::domains::session::ReadingSession]) -> MonthStatistics {
+/// Reading time of a session that falls on the given local day
+fn seconds_on_day(session: &ReadingSession, day: chrono::NaiveDate, timezone: &UserTimezone) -> i32 {
+    match (session.started_at, session.ended_at) {
+        (Some(started_at), Some(ended_at)) => {
+            let total = (ended_at - started_at).num_seconds();
+            let on_day = timezone.seconds_on_day(started_at.to_utc(), ended_at.to_utc(), day);
+
+            // Timer sessions exclude pauses, so scale the wall-clock share
+            match session.duration_seconds {
+                Some(duration) if total > 0 => (duration as i64 * on_day / total) as i32,
+                _ => on_day as i32,
+            }
+        }
+        _ if session.session_date == day => session.duration_seconds.unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn calculate_month_statistics(sessions: &[ReadingSession]) -> MonthStatistics {
     let mut pages_read = 0;
     let mut minutes_read = 0;
+    let mut listening_seconds = 0;
 
     for session in sessions {
         if let Some(pages) = session.pages_read {
@@ -97,11 +175,17 @@ fn calculate_month_statistics(sessions: &[crate::core::domains::session::Reading
         if let Some(minutes) = session.minutes_read {
             minutes_read += minutes;
         }
+        listening_seconds += session.listening_seconds().unwrap_or(0);
     }
 
+    let average_playback_speed = (listening_seconds > 0)
+        .then(|| (minutes_read as f64 * 60.0 / listening_seconds as f64 * 100.0).round() / 100.0);
+
     MonthStatistics {
         pages_read,
         minutes_read,
+        listening_minutes: listening_seconds / 60,
+        average_playback_speed,
         sessions_count: sessions.len() as i32,
         books_completed: 0, // TODO: Calculate from books with completed status this month
     }
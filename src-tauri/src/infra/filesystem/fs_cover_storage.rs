@@ -0,0 +1,48 @@
+use crate::core::domains::book_cover::StoredImage;
+use crate::core::interfaces::secondary::CoverStorage;
+use crate::infra::filesystem::image_folder::ImageFolder;
+
+/// Largest image accepted as a cover
+pub const MAX_COVER_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Local file system implementation of CoverStorage.
+/// Covers live in a "covers" folder next to the database, in the app data directory.
+pub struct FsCoverStorage {
+    folder: ImageFolder,
+}
+
+impl Default for FsCoverStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsCoverStorage {
+    pub fn new() -> Self {
+        FsCoverStorage {
+            folder: ImageFolder::new("covers", "Cover image", MAX_COVER_BYTES),
+        }
+    }
+}
+
+impl CoverStorage for FsCoverStorage {
+    fn store(&self, bytes: &[u8]) -> Result<StoredImage, String> {
+        self.folder.store(bytes)
+    }
+
+    fn read_external(&self, path: &str) -> Result<Vec<u8>, String> {
+        self.folder.read_external(path)
+    }
+
+    fn read(&self, file_name: &str) -> Result<Vec<u8>, String> {
+        self.folder.read(file_name)
+    }
+
+    fn list_hashes(&self) -> Result<Vec<String>, String> {
+        self.folder.list_hashes()
+    }
+
+    fn delete(&self, hash: &str) -> Result<(), String> {
+        self.folder.delete(hash)
+    }
+}
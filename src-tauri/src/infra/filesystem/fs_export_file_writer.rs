@@ -0,0 +1,46 @@
+use crate::core::domains::library_export::ExportedFile;
+use crate::core::interfaces::secondary::ExportFileWriter;
+use std::fs;
+use std::path::{Component, Path};
+
+/// Local file system implementation of ExportFileWriter
+pub struct FsExportFileWriter;
+
+impl Default for FsExportFileWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsExportFileWriter {
+    pub fn new() -> Self {
+        FsExportFileWriter
+    }
+}
+
+impl ExportFileWriter for FsExportFileWriter {
+    fn write_files(&self, target_dir: &str, files: &[ExportedFile]) -> Result<(), String> {
+        let root = Path::new(target_dir);
+        if !root.is_dir() {
+            return Err(format!("Export folder does not exist: {}", target_dir));
+        }
+
+        for file in files {
+            let relative = Path::new(&file.path);
+            // Exported paths must stay inside the chosen folder
+            if relative.components().any(|c| !matches!(c, Component::Normal(_))) {
+                return Err(format!("Invalid export path: {}", file.path));
+            }
+
+            let path = root.join(relative);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create folder {}: {}", parent.display(), e))?;
+            }
+            fs::write(&path, &file.content)
+                .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+
+        Ok(())
+    }
+}
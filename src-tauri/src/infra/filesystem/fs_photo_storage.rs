@@ -0,0 +1,48 @@
+use crate::core::domains::book_cover::StoredImage;
+use crate::core::interfaces::secondary::PhotoStorage;
+use crate::infra::filesystem::image_folder::ImageFolder;
+
+/// Largest image accepted as a session photo
+pub const MAX_PHOTO_BYTES: u64 = 25 * 1024 * 1024;
+
+/// Local file system implementation of PhotoStorage.
+/// Page scans live in a "session_photos" folder next to the database, in the app data directory.
+pub struct FsPhotoStorage {
+    folder: ImageFolder,
+}
+
+impl Default for FsPhotoStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FsPhotoStorage {
+    pub fn new() -> Self {
+        FsPhotoStorage {
+            folder: ImageFolder::new("session_photos", "Photo", MAX_PHOTO_BYTES),
+        }
+    }
+}
+
+impl PhotoStorage for FsPhotoStorage {
+    fn store(&self, bytes: &[u8]) -> Result<StoredImage, String> {
+        self.folder.store(bytes)
+    }
+
+    fn read_external(&self, path: &str) -> Result<Vec<u8>, String> {
+        self.folder.read_external(path)
+    }
+
+    fn read(&self, file_name: &str) -> Result<Vec<u8>, String> {
+        self.folder.read(file_name)
+    }
+
+    fn list_hashes(&self) -> Result<Vec<String>, String> {
+        self.folder.list_hashes()
+    }
+
+    fn delete(&self, hash: &str) -> Result<(), String> {
+        self.folder.delete(hash)
+    }
+}
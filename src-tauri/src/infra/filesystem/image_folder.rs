@@ -0,0 +1,161 @@
+use crate::core::domains::book_cover::{thumbnail_file_name, StoredImage};
+use image::{ImageFormat, ImageReader};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+/// Thumbnails fit in this box, keeping the aspect ratio
+const THUMBNAIL_WIDTH: u32 = 240;
+const THUMBNAIL_HEIGHT: u32 = 360;
+
+/// A folder of images in the app data directory, named by the SHA-256 of their content
+/// with a JPEG thumbnail next to each. Shared by cover and session photo storage.
+pub(crate) struct ImageFolder {
+    folder: &'static str,
+    label: &'static str, // How the images are called in error messages, e.g. "Cover image"
+    max_bytes: u64,
+}
+
+impl ImageFolder {
+    pub(crate) fn new(folder: &'static str, label: &'static str, max_bytes: u64) -> Self {
+        ImageFolder { folder, label, max_bytes }
+    }
+
+    fn dir(&self) -> Result<PathBuf, String> {
+        let app_data_dir = dirs::data_dir()
+            .ok_or("Failed to get app data directory")?;
+        Ok(app_data_dir.join("library").join(self.folder))
+    }
+
+    fn ensure_dir(&self) -> Result<(), String> {
+        let dir = self.dir()?;
+        fs::create_dir_all(&dir)
+            .map_err(|e| format!("Failed to create {} folder {}: {}", self.folder, dir.display(), e))
+    }
+
+    /// Stored file names are generated here; anything else could point outside the folder
+    fn path_for(&self, file_name: &str) -> Result<PathBuf, String> {
+        let valid = !file_name.is_empty()
+            && file_name.chars().all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_')
+            && !file_name.starts_with('.');
+        if !valid {
+            return Err(format!("Invalid {} file name: {}", self.label.to_lowercase(), file_name));
+        }
+        Ok(self.dir()?.join(file_name))
+    }
+
+    fn too_large(&self) -> String {
+        format!("{} is larger than {} MB", self.label, self.max_bytes / (1024 * 1024))
+    }
+
+    fn write_thumbnail(&self, image: &image::DynamicImage, hash: &str) -> Result<(), String> {
+        let path = self.path_for(&thumbnail_file_name(hash))?;
+        if path.exists() {
+            return Ok(());
+        }
+
+        // JPEG has no alpha channel
+        let thumbnail = image::DynamicImage::ImageRgb8(image.thumbnail(THUMBNAIL_WIDTH, THUMBNAIL_HEIGHT).to_rgb8());
+        let mut bytes = Vec::new();
+        thumbnail
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Jpeg)
+            .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+        fs::write(&path, bytes).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Validates and stores an image (skipped when the same content is already stored)
+    /// and makes sure its thumbnail exists
+    pub(crate) fn store(&self, bytes: &[u8]) -> Result<StoredImage, String> {
+        if bytes.len() as u64 > self.max_bytes {
+            return Err(self.too_large());
+        }
+
+        let reader = ImageReader::new(Cursor::new(bytes))
+            .with_guessed_format()
+            .map_err(|e| format!("Failed to read {}: {}", self.label.to_lowercase(), e))?;
+        let extension = match reader.format() {
+            Some(ImageFormat::Jpeg) => "jpg",
+            Some(ImageFormat::Png) => "png",
+            Some(ImageFormat::WebP) => "webp",
+            _ => return Err(format!("{} must be a JPEG, PNG or WebP image", self.label)),
+        };
+        let image = reader
+            .decode()
+            .map_err(|e| format!("Failed to decode {}: {}", self.label.to_lowercase(), e))?;
+
+        let hash: String = Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect();
+        self.ensure_dir()?;
+
+        let path = self.path_for(&format!("{}.{}", hash, extension))?;
+        if !path.exists() {
+            fs::write(&path, bytes).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        }
+        self.write_thumbnail(&image, &hash)?;
+
+        Ok(StoredImage {
+            hash,
+            extension: extension.to_string(),
+            width: image.width(),
+            height: image.height(),
+        })
+    }
+
+    /// Reads an image from outside the folder, e.g. a file picked by the user
+    pub(crate) fn read_external(&self, path: &str) -> Result<Vec<u8>, String> {
+        let path = Path::new(path.trim());
+        let size = fs::metadata(path)
+            .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?
+            .len();
+        if size > self.max_bytes {
+            return Err(self.too_large());
+        }
+        fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    }
+
+    /// Reads a stored file by name
+    pub(crate) fn read(&self, file_name: &str) -> Result<Vec<u8>, String> {
+        let path = self.path_for(file_name)?;
+        fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+    }
+
+    /// Hashes of all stored images
+    pub(crate) fn list_hashes(&self) -> Result<Vec<String>, String> {
+        let dir = self.dir()?;
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to list {} folder: {}", self.folder, e))?;
+        let mut hashes = Vec::new();
+        for entry in entries {
+            let name = entry
+                .map_err(|e| format!("Failed to list {} folder: {}", self.folder, e))?
+                .file_name()
+                .to_string_lossy()
+                .to_string();
+            let stem = name.split('.').next().unwrap_or("");
+            let hash = stem.trim_end_matches("_thumb").to_string();
+            if !hash.is_empty() && !hashes.contains(&hash) {
+                hashes.push(hash);
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Deletes an image and its thumbnail
+    pub(crate) fn delete(&self, hash: &str) -> Result<(), String> {
+        for extension in ["jpg", "png", "webp"] {
+            let path = self.path_for(&format!("{}.{}", hash, extension))?;
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+            }
+        }
+        let thumbnail = self.path_for(&thumbnail_file_name(hash))?;
+        if thumbnail.exists() {
+            fs::remove_file(&thumbnail).map_err(|e| format!("Failed to delete {}: {}", thumbnail.display(), e))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub mod fs_cover_storage;
+pub mod fs_export_file_writer;
+pub mod fs_photo_storage;
+mod image_folder;
+
+pub use fs_cover_storage::*;
+pub use fs_export_file_writer::*;
+pub use fs_photo_storage::*;
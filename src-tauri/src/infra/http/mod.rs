@@ -0,0 +1,5 @@
+pub mod open_library_provider;
+pub mod ureq_http_fetcher;
+
+pub use open_library_provider::*;
+pub use ureq_http_fetcher::*;
@@ -0,0 +1,39 @@
+use crate::core::domains::book_metadata::BookMetadata;
+use crate::core::interfaces::secondary::MetadataProvider;
+use crate::infra::http::ureq_http_fetcher::get_bytes;
+
+const MAX_RESPONSE_BYTES: u64 = 1024 * 1024;
+
+/// MetadataProvider for services speaking the Open Library books API
+/// (`/api/books?bibkeys=ISBN:...&jscmd=data`), such as openlibrary.org or a local stand-in
+pub struct OpenLibraryProvider;
+
+impl Default for OpenLibraryProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenLibraryProvider {
+    pub fn new() -> Self {
+        OpenLibraryProvider
+    }
+}
+
+impl MetadataProvider for OpenLibraryProvider {
+    fn fetch_by_isbn(&self, base_url: &str, isbn: &str) -> Result<Option<BookMetadata>, String> {
+        let base_url = base_url.trim().trim_end_matches('/');
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            return Err(format!("Metadata provider URL must start with http:// or https://: {}", base_url));
+        }
+
+        let url = format!("{}/api/books?bibkeys=ISBN:{}&format=json&jscmd=data", base_url, isbn);
+        let body = get_bytes(&url, MAX_RESPONSE_BYTES)?;
+
+        let response: serde_json::Value = serde_json::from_slice(&body)
+            .map_err(|e| format!("Invalid metadata response: {}", e))?;
+        Ok(response
+            .get(format!("ISBN:{}", isbn))
+            .and_then(|record| BookMetadata::from_open_library(isbn, record)))
+    }
+}
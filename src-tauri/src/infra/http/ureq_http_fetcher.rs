@@ -0,0 +1,52 @@
+use crate::core::interfaces::secondary::HttpFetcher;
+use std::io::Read;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(15);
+
+/// HttpFetcher implementation on top of ureq (HTTP and HTTPS, follows redirects)
+pub struct UreqHttpFetcher;
+
+impl Default for UreqHttpFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UreqHttpFetcher {
+    pub fn new() -> Self {
+        UreqHttpFetcher
+    }
+}
+
+impl HttpFetcher for UreqHttpFetcher {
+    fn get(&self, url: &str, max_bytes: u64) -> Result<Vec<u8>, String> {
+        get_bytes(url, max_bytes)
+    }
+}
+
+/// GET request returning the response body; non-2xx statuses are errors
+pub(crate) fn get_bytes(url: &str, max_bytes: u64) -> Result<Vec<u8>, String> {
+    let response = ureq::AgentBuilder::new()
+        .timeout(TIMEOUT)
+        .user_agent("Library")
+        .build()
+        .get(url)
+        .call()
+        .map_err(|e| match e {
+            ureq::Error::Status(code, _) => format!("{} returned HTTP {}", url, code),
+            other => format!("Failed to fetch {}: {}", url, other),
+        })?;
+
+    let mut body = Vec::new();
+    response
+        .into_reader()
+        .take(max_bytes + 1)
+        .read_to_end(&mut body)
+        .map_err(|e| format!("Failed to read response from {}: {}", url, e))?;
+    if body.len() as u64 > max_bytes {
+        return Err(format!("Response from {} is larger than {} bytes", url, max_bytes));
+    }
+
+    Ok(body)
+}
@@ -1,2 +1,4 @@
+pub mod filesystem;
+pub mod http;
 pub mod sqlite;
 
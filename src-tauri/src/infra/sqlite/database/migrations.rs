@@ -1,3 +1,5 @@
+use crate::core::domains::author::Author;
+use crate::core::domains::timezone::{resolve_span, UserTimezone, TIMEZONE_SETTING};
 use rusqlite::Connection;
 use std::fs;
 use std::path::Path;
@@ -51,10 +53,78 @@ impl Migration {
         Self::migrate_remove_note_type_and_excerpt(conn)?;
         Self::migrate_remove_sentiment(conn)?;
         Self::migrate_remove_session_notes(conn)?;
+        Self::migrate_add_series(conn)?;
+        Self::migrate_add_authors(conn)?;
+        Self::migrate_add_reading_ratings(conn)?;
+        Self::migrate_add_editions(conn)?;
+        Self::migrate_add_metadata_cache(conn)?;
+        Self::migrate_add_book_covers(conn)?;
+        Self::migrate_add_active_sessions(conn)?;
+        Self::migrate_add_session_timestamps(conn)?;
+        Self::backfill_session_timestamps(conn)?;
+        Self::migrate_add_progress_corrections(conn)?;
+        Self::migrate_add_session_photos(conn)?;
+        Self::migrate_add_session_photo_uniqueness(conn)?;
+        Self::migrate_add_session_context(conn)?;
+        Self::migrate_add_audiobook_chapters(conn)?;
+        Self::migrate_add_progress_units(conn)?;
 
         Ok(())
     }
 
+    /// Returns true if a table with the given name exists
+    fn table_exists(conn: &Connection, table: &str) -> Result<bool, String> {
+        let count: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                [table],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check for {} table: {}", table, e))?;
+
+        Ok(count > 0)
+    }
+
+    /// Returns true if a one-off data migration has already been applied
+    fn migration_applied(conn: &Connection, name: &str) -> Result<bool, String> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS applied_migrations (
+                name TEXT PRIMARY KEY,
+                applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create applied_migrations table: {}", e))?;
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM applied_migrations WHERE name = ?1", [name], |row| row.get(0))
+            .map_err(|e| format!("Failed to check migration {}: {}", name, e))?;
+
+        Ok(count > 0)
+    }
+
+    /// Returns true if the given table has a column with the given name
+    fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool, String> {
+        let mut stmt = conn
+            .prepare(&format!("PRAGMA table_info({})", table))
+            .map_err(|e| format!("Failed to inspect {} table: {}", table, e))?;
+
+        let info_rows = stmt
+            .query_map([], |row| {
+                let name: String = row.get(1)?;
+                Ok(name)
+            })
+            .map_err(|e| format!("Failed to iterate table info: {}", e))?;
+
+        for col in info_rows {
+            if col.map_err(|e| format!("Failed to read column info: {}", e))? == column {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Migration to remove type and excerpt columns from notes table
     /// SQLite doesn't support DROP COLUMN, so we recreate the table
     fn migrate_remove_note_type_and_excerpt(conn: &Connection) -> Result<(), String> {
@@ -336,4 +406,604 @@ impl Migration {
 
         Ok(())
     }
+
+    /// Migration to add series table and series columns on books
+    fn migrate_add_series(conn: &Connection) -> Result<(), String> {
+        if Self::column_exists(conn, "books", "series_id")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "CREATE TABLE IF NOT EXISTS series (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                author TEXT,
+                expected_volumes INTEGER CHECK(expected_volumes IS NULL OR expected_volumes > 0),
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create series table: {}", e))?;
+
+        tx.execute(
+            "ALTER TABLE books ADD COLUMN series_id INTEGER REFERENCES series(id) ON DELETE SET NULL",
+            [],
+        )
+        .map_err(|e| format!("Failed to add series_id column: {}", e))?;
+
+        tx.execute("ALTER TABLE books ADD COLUMN series_index REAL", [])
+            .map_err(|e| format!("Failed to add series_index column: {}", e))?;
+
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_books_series ON books(series_id, series_index)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_series_name ON series(name)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+
+        tx.execute(
+            "CREATE TRIGGER IF NOT EXISTS update_series_timestamp
+             AFTER UPDATE ON series
+             BEGIN
+                 UPDATE series SET updated_at = datetime('now') WHERE id = NEW.id;
+             END",
+            [],
+        )
+        .map_err(|e| format!("Failed to create trigger: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add authors and book_authors tables
+    /// Existing free-text authors are backfilled as 'author' contributors
+    fn migrate_add_authors(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "authors")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "CREATE TABLE authors (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create authors table: {}", e))?;
+
+        tx.execute(
+            "CREATE TABLE book_authors (
+                book_id INTEGER NOT NULL,
+                author_id INTEGER NOT NULL,
+                role TEXT NOT NULL DEFAULT 'author' CHECK(role IN ('author', 'translator', 'editor', 'narrator')),
+                position INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (book_id, author_id, role),
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+                FOREIGN KEY (author_id) REFERENCES authors(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create book_authors table: {}", e))?;
+
+        // Backfill: co-authors in the text ("A & B") become separate authors,
+        // one per distinct name (case-insensitive thanks to NOCASE)
+        let books: Vec<(i64, String)> = {
+            let mut stmt = tx
+                .prepare(
+                    "SELECT id, author FROM books
+                     WHERE author IS NOT NULL AND TRIM(author) != ''
+                     ORDER BY added_at",
+                )
+                .map_err(|e| format!("Failed to read book authors: {}", e))?;
+            let rows = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| format!("Failed to read book authors: {}", e))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Failed to read book authors: {}", e))?;
+            rows
+        };
+
+        for (book_id, author) in books {
+            for (position, name) in Author::split_names(&author).iter().enumerate() {
+                let name = Author::normalize_name(name);
+                tx.execute("INSERT OR IGNORE INTO authors (name) VALUES (?1)", [&name])
+                    .map_err(|e| format!("Failed to backfill authors: {}", e))?;
+
+                tx.execute(
+                    "INSERT OR IGNORE INTO book_authors (book_id, author_id, role, position)
+                     SELECT ?1, id, 'author', ?2 FROM authors WHERE name = ?3",
+                    rusqlite::params![book_id, position as i32, name],
+                )
+                .map_err(|e| format!("Failed to backfill book_authors: {}", e))?;
+            }
+        }
+
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_book_authors_author_id ON book_authors(author_id)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+
+        tx.execute(
+            "CREATE TRIGGER IF NOT EXISTS update_authors_timestamp
+             AFTER UPDATE ON authors
+             BEGIN
+                 UPDATE authors SET updated_at = datetime('now') WHERE id = NEW.id;
+             END",
+            [],
+        )
+        .map_err(|e| format!("Failed to create trigger: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add rating and review to book_readings plus the book_ratings view
+    fn migrate_add_reading_ratings(conn: &Connection) -> Result<(), String> {
+        if Self::column_exists(conn, "book_readings", "rating")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "ALTER TABLE book_readings ADD COLUMN rating REAL CHECK(rating IS NULL OR (rating >= 0.5 AND rating <= 5.0))",
+            [],
+        )
+        .map_err(|e| format!("Failed to add rating column: {}", e))?;
+
+        tx.execute("ALTER TABLE book_readings ADD COLUMN review TEXT", [])
+            .map_err(|e| format!("Failed to add review column: {}", e))?;
+
+        tx.execute(
+            "CREATE VIEW IF NOT EXISTS book_ratings AS
+             SELECT r.book_id,
+                    AVG(r.rating) AS average_rating,
+                    (SELECT r2.rating FROM book_readings r2
+                     WHERE r2.book_id = r.book_id AND r2.rating IS NOT NULL
+                     ORDER BY r2.reading_number DESC LIMIT 1) AS latest_rating,
+                    COUNT(r.rating) AS ratings_count
+             FROM book_readings r
+             WHERE r.rating IS NOT NULL
+             GROUP BY r.book_id",
+            [],
+        )
+        .map_err(|e| format!("Failed to create book_ratings view: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add book_editions and link sessions to a specific edition
+    fn migrate_add_editions(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "book_editions")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "CREATE TABLE book_editions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                book_id INTEGER NOT NULL,
+                format TEXT NOT NULL CHECK(format IN ('physical_book', 'ebook', 'audiobook', 'article', 'PDF', 'comic')),
+                label TEXT,
+                isbn TEXT,
+                total_pages INTEGER,
+                total_minutes INTEGER,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create book_editions table: {}", e))?;
+
+        tx.execute(
+            "ALTER TABLE reading_sessions ADD COLUMN edition_id INTEGER REFERENCES book_editions(id) ON DELETE SET NULL",
+            [],
+        )
+        .map_err(|e| format!("Failed to add edition_id column: {}", e))?;
+
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_book_editions_book_id ON book_editions(book_id)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_reading_sessions_edition_id ON reading_sessions(edition_id)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+
+        tx.execute(
+            "CREATE TRIGGER IF NOT EXISTS update_book_editions_timestamp
+             AFTER UPDATE ON book_editions
+             BEGIN
+                 UPDATE book_editions SET updated_at = datetime('now') WHERE id = NEW.id;
+             END",
+            [],
+        )
+        .map_err(|e| format!("Failed to create trigger: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add the local cache of ISBN metadata lookups
+    fn migrate_add_metadata_cache(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "book_metadata_cache")? {
+            return Ok(());
+        }
+
+        conn.execute(
+            "CREATE TABLE book_metadata_cache (
+                isbn TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                authors TEXT NOT NULL DEFAULT '[]',
+                total_pages INTEGER,
+                publication_year INTEGER,
+                cover_url TEXT,
+                genre TEXT,
+                source TEXT NOT NULL,
+                fetched_at TEXT NOT NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create book_metadata_cache table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add book_covers, linking books to covers stored in the app data folder
+    fn migrate_add_book_covers(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "book_covers")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "CREATE TABLE book_covers (
+                book_id INTEGER PRIMARY KEY,
+                hash TEXT NOT NULL,
+                extension TEXT NOT NULL CHECK(extension IN ('jpg', 'png', 'webp')),
+                width INTEGER NOT NULL,
+                height INTEGER NOT NULL,
+                source TEXT,
+                imported_at TEXT NOT NULL,
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create book_covers table: {}", e))?;
+
+        tx.execute("CREATE INDEX IF NOT EXISTS idx_book_covers_hash ON book_covers(hash)", [])
+            .map_err(|e| format!("Failed to create index: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit migration: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add active_sessions, the persisted state of the session timer
+    fn migrate_add_active_sessions(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "active_sessions")? {
+            return Ok(());
+        }
+
+        conn.execute(
+            "CREATE TABLE active_sessions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                book_id INTEGER NOT NULL,
+                reading_id INTEGER,
+                edition_id INTEGER,
+                start_page INTEGER,
+                started_at TEXT NOT NULL,
+                paused_at TEXT,
+                paused_seconds INTEGER NOT NULL DEFAULT 0 CHECK(paused_seconds >= 0),
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+                FOREIGN KEY (reading_id) REFERENCES book_readings(id) ON DELETE SET NULL,
+                FOREIGN KEY (edition_id) REFERENCES book_editions(id) ON DELETE SET NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create active_sessions table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add full start/end timestamps and the time zone to reading_sessions
+    /// Also fills in the duration of old sessions that crossed midnight, which was left empty
+    fn migrate_add_session_timestamps(conn: &Connection) -> Result<(), String> {
+        if Self::column_exists(conn, "reading_sessions", "started_at")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for column in ["started_at TEXT", "ended_at TEXT", "timezone TEXT"] {
+            tx.execute(&format!("ALTER TABLE reading_sessions ADD COLUMN {}", column), [])
+                .map_err(|e| format!("Failed to add reading_sessions column {}: {}", column, e))?;
+        }
+
+        tx.execute(
+            "UPDATE reading_sessions
+             SET duration_seconds = CAST(strftime('%s', '2000-01-02 ' || end_time) AS INTEGER)
+                                  - CAST(strftime('%s', '2000-01-01 ' || start_time) AS INTEGER)
+             WHERE duration_seconds IS NULL
+               AND start_time IS NOT NULL AND end_time IS NOT NULL
+               AND end_time < start_time",
+            [],
+        )
+        .map_err(|e| format!("Failed to backfill overnight session durations: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Fills started_at/ended_at for sessions logged before timestamps were stored,
+    /// so they take part in overlap checks. Their times are taken in the user's
+    /// time zone setting (the system zone when unset) and the stored duration is kept.
+    /// Runs once; only rows with both times and no timestamps match.
+    fn backfill_session_timestamps(conn: &Connection) -> Result<(), String> {
+        const NAME: &str = "backfill_session_timestamps";
+        if Self::migration_applied(conn, NAME)? {
+            return Ok(());
+        }
+
+        let setting: Option<String> = conn
+            .query_row("SELECT value FROM settings WHERE key = ?1", [TIMEZONE_SETTING], |row| row.get(0))
+            .map(Some)
+            .or_else(|e| match e {
+                rusqlite::Error::QueryReturnedNoRows => Ok(None),
+                e => Err(e),
+            })
+            .map_err(|e| format!("Failed to read time zone setting: {}", e))?;
+        let timezone = setting
+            .and_then(|value| UserTimezone::parse(&value).ok())
+            .unwrap_or(UserTimezone::System);
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, session_date, start_time, end_time FROM reading_sessions
+                 WHERE started_at IS NULL AND ended_at IS NULL
+                   AND start_time IS NOT NULL AND end_time IS NOT NULL",
+            )
+            .map_err(|e| format!("Failed to prepare session timestamp backfill: {}", e))?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            })
+            .map_err(|e| format!("Failed to read sessions to backfill: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to read sessions to backfill: {}", e))?;
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for (id, date, start, end) in rows {
+            let parsed = (
+                chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d"),
+                chrono::NaiveTime::parse_from_str(&start, "%H:%M:%S"),
+                chrono::NaiveTime::parse_from_str(&end, "%H:%M:%S"),
+            );
+            // Rows with unreadable values are left as they were
+            let (date, start, end) = match parsed {
+                (Ok(date), Ok(start), Ok(end)) => (date, start, end),
+                _ => continue,
+            };
+            let (started_at, ended_at) = match resolve_span(&timezone, date, start, end) {
+                Ok(span) => span,
+                Err(_) => continue,
+            };
+
+            tx.execute(
+                "UPDATE reading_sessions SET started_at = ?1, ended_at = ?2 WHERE id = ?3",
+                rusqlite::params![started_at.to_rfc3339(), ended_at.to_rfc3339(), id],
+            )
+            .map_err(|e| format!("Failed to backfill timestamps of session {}: {}", id, e))?;
+        }
+
+        tx.execute("INSERT INTO applied_migrations (name) VALUES (?1)", [NAME])
+            .map_err(|e| format!("Failed to record migration {}: {}", NAME, e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add progress_corrections, the audit log of corrected book positions
+    fn migrate_add_progress_corrections(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "progress_corrections")? {
+            return Ok(());
+        }
+
+        conn.execute(
+            "CREATE TABLE progress_corrections (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                book_id INTEGER NOT NULL,
+                mode TEXT NOT NULL CHECK(mode IN ('adjustment', 'rebase')),
+                unit TEXT NOT NULL CHECK(unit IN ('pages', 'minutes')),
+                previous_value INTEGER NOT NULL,
+                corrected_value INTEGER NOT NULL,
+                adjustment_session_id INTEGER,
+                changes TEXT NOT NULL DEFAULT '[]',
+                note TEXT,
+                created_at TEXT NOT NULL,
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+                FOREIGN KEY (adjustment_session_id) REFERENCES reading_sessions(id) ON DELETE SET NULL
+            )",
+            [],
+        )
+        .map_err(|e| format!("Failed to create progress_corrections table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add session_photos, page scans linked to reading sessions
+    fn migrate_add_session_photos(conn: &Connection) -> Result<(), String> {
+        if Self::table_exists(conn, "session_photos")? {
+            return Ok(());
+        }
+
+        conn.execute_batch(
+            "CREATE TABLE session_photos (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                session_id INTEGER NOT NULL,
+                hash TEXT NOT NULL,
+                extension TEXT NOT NULL CHECK(extension IN ('jpg', 'png', 'webp')),
+                width INTEGER NOT NULL,
+                height INTEGER NOT NULL,
+                source TEXT,
+                created_at TEXT NOT NULL,
+                FOREIGN KEY (session_id) REFERENCES reading_sessions(id) ON DELETE CASCADE
+            );
+            CREATE INDEX idx_session_photos_session_id ON session_photos(session_id);
+            CREATE INDEX idx_session_photos_hash ON session_photos(hash);",
+        )
+        .map_err(|e| format!("Failed to create session_photos table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to link each image to a session at most once
+    /// Duplicate links from before the constraint keep their oldest row
+    fn migrate_add_session_photo_uniqueness(conn: &Connection) -> Result<(), String> {
+        let exists: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type = 'index' AND name = 'idx_session_photos_session_hash'",
+                [],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to check for session photo index: {}", e))?;
+        if exists > 0 {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute(
+            "DELETE FROM session_photos
+             WHERE id NOT IN (SELECT MIN(id) FROM session_photos GROUP BY session_id, hash)",
+            [],
+        )
+        .map_err(|e| format!("Failed to remove duplicate session photos: {}", e))?;
+
+        tx.execute(
+            "CREATE UNIQUE INDEX idx_session_photos_session_hash ON session_photos(session_id, hash)",
+            [],
+        )
+        .map_err(|e| format!("Failed to create index: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add context columns (location, device, mood/energy, interruptions) to reading_sessions
+    fn migrate_add_session_context(conn: &Connection) -> Result<(), String> {
+        if Self::column_exists(conn, "reading_sessions", "location")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for column in [
+            "location TEXT",
+            "device TEXT CHECK(device IS NULL OR device IN ('paper', 'ereader', 'phone', 'tablet', 'computer', 'audio', 'other'))",
+            "mood_before INTEGER CHECK(mood_before IS NULL OR mood_before BETWEEN 1 AND 5)",
+            "mood_after INTEGER CHECK(mood_after IS NULL OR mood_after BETWEEN 1 AND 5)",
+            "energy_before INTEGER CHECK(energy_before IS NULL OR energy_before BETWEEN 1 AND 5)",
+            "energy_after INTEGER CHECK(energy_after IS NULL OR energy_after BETWEEN 1 AND 5)",
+            "interruptions INTEGER CHECK(interruptions IS NULL OR interruptions >= 0)",
+        ] {
+            tx.execute(&format!("ALTER TABLE reading_sessions ADD COLUMN {}", column), [])
+                .map_err(|e| format!("Failed to add reading_sessions column {}: {}", column, e))?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add audiobook chapter lists and the playback speed of reading sessions
+    fn migrate_add_audiobook_chapters(conn: &Connection) -> Result<(), String> {
+        if !Self::column_exists(conn, "reading_sessions", "playback_speed")? {
+            conn.execute(
+                "ALTER TABLE reading_sessions ADD COLUMN playback_speed REAL CHECK(playback_speed IS NULL OR playback_speed > 0)",
+                [],
+            )
+            .map_err(|e| format!("Failed to add playback_speed column: {}", e))?;
+        }
+
+        if Self::table_exists(conn, "audiobook_chapters")? {
+            return Ok(());
+        }
+
+        conn.execute_batch(
+            "CREATE TABLE audiobook_chapters (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                book_id INTEGER NOT NULL,
+                position INTEGER NOT NULL,
+                title TEXT NOT NULL,
+                start_seconds INTEGER NOT NULL CHECK(start_seconds >= 0),
+                FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+                UNIQUE(book_id, position)
+            );",
+        )
+        .map_err(|e| format!("Failed to create audiobook_chapters table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Migration to add per-book progress units and percentage/location positions to reading_sessions
+    fn migrate_add_progress_units(conn: &Connection) -> Result<(), String> {
+        if Self::column_exists(conn, "books", "progress_unit")? {
+            return Ok(());
+        }
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for (table, column) in [
+            ("books", "progress_unit TEXT NOT NULL DEFAULT 'page' CHECK(progress_unit IN ('page', 'percentage', 'location'))"),
+            ("books", "total_locations INTEGER CHECK(total_locations IS NULL OR total_locations > 0)"),
+            ("reading_sessions", "position_unit TEXT CHECK(position_unit IS NULL OR position_unit IN ('percentage', 'location'))"),
+            ("reading_sessions", "start_position REAL"),
+            ("reading_sessions", "end_position REAL"),
+        ] {
+            tx.execute(&format!("ALTER TABLE {} ADD COLUMN {}", table, column), [])
+                .map_err(|e| format!("Failed to add {} column {}: {}", table, column, e))?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
 }
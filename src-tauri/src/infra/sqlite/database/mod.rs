@@ -3,7 +3,7 @@ pub mod migrations;
 pub mod transaction;
 pub mod integrity;
 
-pub use transaction::with_transaction;
+pub use transaction::{with_transaction, SqliteTransactionManager};
 pub use integrity::{IntegrityChecker, IntegrityReport, IntegrityIssue};
 
 pub use connection::*;
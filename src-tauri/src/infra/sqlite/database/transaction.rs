@@ -1,3 +1,4 @@
+use crate::core::interfaces::secondary::TransactionManager;
 use rusqlite::Connection;
 use std::sync::{Arc, Mutex, MutexGuard};
 
@@ -31,3 +32,36 @@ where
     }
 }
 
+/// SQLite implementation of TransactionManager
+/// Repositories share this connection and lock it per statement, so the transaction is
+/// opened and closed around `work` rather than held locked through it. A savepoint is
+/// used so that calls nest.
+pub struct SqliteTransactionManager {
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl SqliteTransactionManager {
+    pub fn new(connection: Arc<Mutex<Connection>>) -> Self {
+        SqliteTransactionManager { connection }
+    }
+
+    fn execute(&self, sql: &str) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        conn.execute_batch(sql)
+            .map_err(|e| format!("Failed to run {}: {}", sql, e))
+    }
+}
+
+impl TransactionManager for SqliteTransactionManager {
+    fn run(&self, work: &mut dyn FnMut() -> Result<(), String>) -> Result<(), String> {
+        self.execute("SAVEPOINT unit_of_work")?;
+
+        match work() {
+            Ok(()) => self.execute("RELEASE unit_of_work"),
+            Err(e) => {
+                let _ = self.execute("ROLLBACK TO unit_of_work; RELEASE unit_of_work");
+                Err(e)
+            }
+        }
+    }
+}
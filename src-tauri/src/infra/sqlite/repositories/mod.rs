@@ -1,23 +1,43 @@
+pub mod sqlite_active_session_repository;
 pub mod sqlite_agenda_repository;
+pub mod sqlite_author_repository;
 pub mod sqlite_backup_repository;
+pub mod sqlite_book_cover_repository;
 pub mod sqlite_book_repository;
+pub mod sqlite_calibre_library_reader;
+pub mod sqlite_chapter_repository;
 pub mod sqlite_collection_repository;
+pub mod sqlite_edition_repository;
 pub mod sqlite_goal_repository;
 pub mod sqlite_journal_repository;
+pub mod sqlite_metadata_cache_repository;
 pub mod sqlite_note_repository;
+pub mod sqlite_progress_correction_repository;
 pub mod sqlite_reading_repository;
+pub mod sqlite_series_repository;
+pub mod sqlite_session_photo_repository;
 pub mod sqlite_session_repository;
 pub mod sqlite_settings_repository;
 pub mod sqlite_tag_repository;
 
+pub use sqlite_active_session_repository::*;
 pub use sqlite_agenda_repository::*;
+pub use sqlite_author_repository::*;
 pub use sqlite_backup_repository::*;
+pub use sqlite_book_cover_repository::*;
 pub use sqlite_book_repository::*;
+pub use sqlite_calibre_library_reader::*;
+pub use sqlite_chapter_repository::*;
 pub use sqlite_collection_repository::*;
+pub use sqlite_edition_repository::*;
 pub use sqlite_goal_repository::*;
 pub use sqlite_journal_repository::*;
+pub use sqlite_metadata_cache_repository::*;
 pub use sqlite_note_repository::*;
+pub use sqlite_progress_correction_repository::*;
 pub use sqlite_reading_repository::*;
+pub use sqlite_series_repository::*;
+pub use sqlite_session_photo_repository::*;
 pub use sqlite_session_repository::*;
 pub use sqlite_settings_repository::*;
 pub use sqlite_tag_repository::*;
@@ -0,0 +1,105 @@
+use crate::core::domains::active_session::ActiveSession;
+use crate::core::interfaces::secondary::ActiveSessionRepository;
+use rusqlite::{params, Row};
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of ActiveSessionRepository
+pub struct SqliteActiveSessionRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteActiveSessionRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteActiveSessionRepository { connection }
+    }
+
+    fn row_to_session(row: &Row) -> Result<ActiveSession, rusqlite::Error> {
+        let parse = |index: usize, value: String| {
+            chrono::DateTime::parse_from_rfc3339(&value)
+                .map(|d| d.with_timezone(&chrono::Utc))
+                .map_err(|_| rusqlite::Error::InvalidColumnType(index, "Invalid datetime".to_string(), rusqlite::types::Type::Text))
+        };
+
+        let started_at: String = row.get(5)?;
+        let paused_at: Option<String> = row.get(6)?;
+
+        Ok(ActiveSession {
+            id: row.get(0)?,
+            book_id: row.get(1)?,
+            reading_id: row.get(2)?,
+            edition_id: row.get(3)?,
+            start_page: row.get(4)?,
+            started_at: parse(5, started_at)?,
+            paused_at: paused_at.map(|p| parse(6, p)).transpose()?,
+            paused_seconds: row.get(7)?,
+        })
+    }
+}
+
+impl ActiveSessionRepository for SqliteActiveSessionRepository {
+    fn create(&self, session: &mut ActiveSession) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO active_sessions (book_id, reading_id, edition_id, start_page, started_at, paused_at, paused_seconds)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                session.book_id,
+                session.reading_id,
+                session.edition_id,
+                session.start_page,
+                session.started_at.to_rfc3339(),
+                session.paused_at.map(|p| p.to_rfc3339()),
+                session.paused_seconds,
+            ],
+        )
+        .map_err(|e| format!("Failed to create active session: {}", e))?;
+
+        session.id = Some(conn.last_insert_rowid());
+        Ok(())
+    }
+
+    fn update(&self, session: &ActiveSession) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        let id = session.id.ok_or("Active session ID is required for update".to_string())?;
+
+        conn.execute(
+            "UPDATE active_sessions SET start_page = ?1, paused_at = ?2, paused_seconds = ?3 WHERE id = ?4",
+            params![
+                session.start_page,
+                session.paused_at.map(|p| p.to_rfc3339()),
+                session.paused_seconds,
+                id,
+            ],
+        )
+        .map_err(|e| format!("Failed to update active session: {}", e))?;
+
+        Ok(())
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute("DELETE FROM active_sessions WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete active session: {}", e))?;
+
+        Ok(())
+    }
+
+    fn find_current(&self) -> Result<Option<ActiveSession>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, book_id, reading_id, edition_id, start_page, started_at, paused_at, paused_seconds
+                 FROM active_sessions ORDER BY started_at DESC LIMIT 1",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        match stmt.query_row([], Self::row_to_session) {
+            Ok(session) => Ok(Some(session)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to get active session: {}", e)),
+        }
+    }
+}
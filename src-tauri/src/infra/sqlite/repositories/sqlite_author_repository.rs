@@ -0,0 +1,256 @@
+use crate::core::domains::author::{Author, BookContributor, ContributorRole};
+use crate::core::interfaces::secondary::AuthorRepository;
+use rusqlite::params;
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of AuthorRepository
+pub struct SqliteAuthorRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteAuthorRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteAuthorRepository { connection }
+    }
+
+    fn role_to_string(role: &ContributorRole) -> &'static str {
+        match role {
+            ContributorRole::Author => "author",
+            ContributorRole::Translator => "translator",
+            ContributorRole::Editor => "editor",
+            ContributorRole::Narrator => "narrator",
+        }
+    }
+
+    fn string_to_role(s: &str) -> Result<ContributorRole, String> {
+        match s {
+            "author" => Ok(ContributorRole::Author),
+            "translator" => Ok(ContributorRole::Translator),
+            "editor" => Ok(ContributorRole::Editor),
+            "narrator" => Ok(ContributorRole::Narrator),
+            _ => Err(format!("Invalid contributor role: {}", s)),
+        }
+    }
+
+    // Helper function to parse datetime from SQLite (supports both SQLite format and RFC3339)
+    // Rows backfilled by the migration and the updated_at trigger use datetime('now')
+    fn parse_datetime(s: &str, index: usize) -> Result<chrono::DateTime<chrono::Utc>, rusqlite::Error> {
+        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+            return Ok(dt.with_timezone(&chrono::Utc));
+        }
+
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return Ok(dt.and_utc());
+        }
+
+        Err(rusqlite::Error::InvalidColumnType(index, "Invalid datetime".to_string(), rusqlite::types::Type::Text))
+    }
+
+    fn row_to_author(row: &rusqlite::Row) -> Result<Author, rusqlite::Error> {
+        Ok(Author {
+            id: Some(row.get(0)?),
+            name: row.get(1)?,
+            created_at: Self::parse_datetime(&row.get::<_, String>(2)?, 2)?,
+            updated_at: Self::parse_datetime(&row.get::<_, String>(3)?, 3)?,
+        })
+    }
+
+    fn row_to_contributor(row: &rusqlite::Row) -> Result<BookContributor, rusqlite::Error> {
+        let role_str: String = row.get(5)?;
+        let role = Self::string_to_role(&role_str)
+            .map_err(|_| rusqlite::Error::InvalidColumnType(5, "role".to_string(), rusqlite::types::Type::Text))?;
+
+        Ok(BookContributor {
+            author: Self::row_to_author(row)?,
+            book_id: row.get(4)?,
+            role,
+            position: row.get(6)?,
+        })
+    }
+
+    fn query_contributors(&self, sql: &str, id: i64) -> Result<Vec<BookContributor>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let contributor_iter = stmt
+            .query_map(params![id], Self::row_to_contributor)
+            .map_err(|e| format!("Failed to query contributors: {}", e))?;
+
+        let mut contributors = Vec::new();
+        for contributor_result in contributor_iter {
+            contributors.push(contributor_result.map_err(|e| format!("Failed to parse contributor: {}", e))?);
+        }
+
+        Ok(contributors)
+    }
+}
+
+impl AuthorRepository for SqliteAuthorRepository {
+    fn create(&self, author: &mut Author) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let created_at = author.created_at.to_rfc3339();
+        let updated_at = author.updated_at.to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO authors (name, created_at, updated_at) VALUES (?1, ?2, ?3)",
+            params![author.name, created_at, updated_at],
+        )
+        .map_err(|e| format!("Failed to insert author: {}", e))?;
+
+        author.id = Some(conn.last_insert_rowid());
+        Ok(())
+    }
+
+    fn update(&self, author: &Author) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let id = author.id.ok_or("Author ID is required for update".to_string())?;
+        let updated_at = author.updated_at.to_rfc3339();
+
+        conn.execute(
+            "UPDATE authors SET name = ?2, updated_at = ?3 WHERE id = ?1",
+            params![id, author.name, updated_at],
+        )
+        .map_err(|e| format!("Failed to update author: {}", e))?;
+
+        Ok(())
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute("DELETE FROM authors WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete author: {}", e))?;
+
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: i64) -> Result<Option<Author>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, name, created_at, updated_at FROM authors WHERE id = ?1")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let author_result = stmt.query_row(params![id], Self::row_to_author);
+
+        match author_result {
+            Ok(author) => Ok(Some(author)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to find author: {}", e)),
+        }
+    }
+
+    fn find_by_name(&self, name: &str) -> Result<Option<Author>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        // authors.name is declared COLLATE NOCASE, so this comparison ignores case
+        let mut stmt = conn
+            .prepare("SELECT id, name, created_at, updated_at FROM authors WHERE name = ?1")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let author_result = stmt.query_row(params![name], Self::row_to_author);
+
+        match author_result {
+            Ok(author) => Ok(Some(author)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to find author: {}", e)),
+        }
+    }
+
+    fn find_all(&self) -> Result<Vec<Author>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, name, created_at, updated_at FROM authors ORDER BY name")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let author_iter = stmt
+            .query_map([], Self::row_to_author)
+            .map_err(|e| format!("Failed to query authors: {}", e))?;
+
+        let mut authors = Vec::new();
+        for author_result in author_iter {
+            authors.push(author_result.map_err(|e| format!("Failed to parse author: {}", e))?);
+        }
+
+        Ok(authors)
+    }
+
+    fn find_contributors_by_book_id(&self, book_id: i64) -> Result<Vec<BookContributor>, String> {
+        self.query_contributors(
+            "SELECT a.id, a.name, a.created_at, a.updated_at, ba.book_id, ba.role, ba.position
+             FROM book_authors ba
+             INNER JOIN authors a ON a.id = ba.author_id
+             WHERE ba.book_id = ?1
+             ORDER BY ba.position, a.name",
+            book_id,
+        )
+    }
+
+    fn find_contributions_by_author_id(&self, author_id: i64) -> Result<Vec<BookContributor>, String> {
+        self.query_contributors(
+            "SELECT a.id, a.name, a.created_at, a.updated_at, ba.book_id, ba.role, ba.position
+             FROM book_authors ba
+             INNER JOIN authors a ON a.id = ba.author_id
+             WHERE ba.author_id = ?1
+             ORDER BY ba.book_id",
+            author_id,
+        )
+    }
+
+    fn set_book_contributors(&self, book_id: i64, contributors: &[(i64, ContributorRole)]) -> Result<(), String> {
+        let mut conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        // A savepoint, so this also works inside an import's transaction
+        let tx = conn.savepoint()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute("DELETE FROM book_authors WHERE book_id = ?1", params![book_id])
+            .map_err(|e| format!("Failed to clear book contributors: {}", e))?;
+
+        for (position, (author_id, role)) in contributors.iter().enumerate() {
+            tx.execute(
+                "INSERT OR IGNORE INTO book_authors (book_id, author_id, role, position) VALUES (?1, ?2, ?3, ?4)",
+                params![book_id, author_id, Self::role_to_string(role), position as i32],
+            )
+            .map_err(|e| format!("Failed to add contributor to book: {}", e))?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit contributors: {}", e))?;
+
+        Ok(())
+    }
+
+    fn merge(&self, source_id: i64, target_id: i64) -> Result<(), String> {
+        let mut conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        // A savepoint, so this also works inside an import's transaction
+        let tx = conn.savepoint()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        // Links the target already has (same book and role) are dropped with the source
+        tx.execute(
+            "INSERT OR IGNORE INTO book_authors (book_id, author_id, role, position)
+             SELECT book_id, ?2, role, position FROM book_authors WHERE author_id = ?1",
+            params![source_id, target_id],
+        )
+        .map_err(|e| format!("Failed to move book links: {}", e))?;
+
+        tx.execute("DELETE FROM book_authors WHERE author_id = ?1", params![source_id])
+            .map_err(|e| format!("Failed to remove old book links: {}", e))?;
+
+        tx.execute("DELETE FROM authors WHERE id = ?1", params![source_id])
+            .map_err(|e| format!("Failed to delete merged author: {}", e))?;
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit merge: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::core::domains::book_cover::BookCover;
+use crate::core::interfaces::secondary::BookCoverRepository;
+use rusqlite::{params, Row};
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of BookCoverRepository
+pub struct SqliteBookCoverRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteBookCoverRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteBookCoverRepository { connection }
+    }
+
+    fn row_to_cover(row: &Row) -> Result<BookCover, rusqlite::Error> {
+        let imported_at_str: String = row.get(6)?;
+        let imported_at = chrono::DateTime::parse_from_rfc3339(&imported_at_str)
+            .map_err(|_| rusqlite::Error::InvalidColumnType(6, "Invalid datetime".to_string(), rusqlite::types::Type::Text))?
+            .with_timezone(&chrono::Utc);
+
+        Ok(BookCover {
+            book_id: row.get(0)?,
+            hash: row.get(1)?,
+            extension: row.get(2)?,
+            width: row.get(3)?,
+            height: row.get(4)?,
+            source: row.get(5)?,
+            imported_at,
+        })
+    }
+}
+
+impl BookCoverRepository for SqliteBookCoverRepository {
+    fn find_by_book_id(&self, book_id: i64) -> Result<Option<BookCover>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT book_id, hash, extension, width, height, source, imported_at
+                 FROM book_covers WHERE book_id = ?1",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        match stmt.query_row(params![book_id], Self::row_to_cover) {
+            Ok(cover) => Ok(Some(cover)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to get book cover: {}", e)),
+        }
+    }
+
+    fn find_all(&self) -> Result<Vec<BookCover>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT book_id, hash, extension, width, height, source, imported_at
+                 FROM book_covers ORDER BY book_id",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let cover_iter = stmt
+            .query_map([], Self::row_to_cover)
+            .map_err(|e| format!("Failed to query book covers: {}", e))?;
+
+        let mut covers = Vec::new();
+        for cover_result in cover_iter {
+            covers.push(cover_result.map_err(|e| format!("Failed to parse book cover: {}", e))?);
+        }
+
+        Ok(covers)
+    }
+
+    fn save(&self, cover: &BookCover) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO book_covers (book_id, hash, extension, width, height, source, imported_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(book_id) DO UPDATE SET
+                hash = ?2, extension = ?3, width = ?4, height = ?5, source = ?6, imported_at = ?7",
+            params![
+                cover.book_id,
+                cover.hash,
+                cover.extension,
+                cover.width,
+                cover.height,
+                cover.source,
+                cover.imported_at.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to save book cover: {}", e))?;
+
+        Ok(())
+    }
+
+    fn delete_by_book_id(&self, book_id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute("DELETE FROM book_covers WHERE book_id = ?1", params![book_id])
+            .map_err(|e| format!("Failed to delete book cover: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::core::domains::book::{Book, BookStatus, BookType};
+use crate::core::domains::progress_unit::ProgressUnit;
 use crate::core::interfaces::secondary::BookRepository;
 use rusqlite::{params, types::Value};
 use std::sync::{Arc, Mutex};
@@ -98,6 +99,9 @@ impl SqliteBookRepository {
             total_minutes: row.get(8)?,
             current_page_text: row.get(9)?,
             current_minutes_audio: row.get(10)?,
+            progress_unit: ProgressUnit::parse(&row.get::<_, String>(24)?)
+                .map_err(|e| rusqlite::Error::InvalidColumnType(24, e, rusqlite::types::Type::Text))?,
+            total_locations: row.get(25)?,
             status: Self::string_to_status(&row.get::<_, String>(11)?)
                 .map_err(|e| rusqlite::Error::InvalidColumnType(11, e, rusqlite::types::Type::Text))?,
             is_archived: row.get::<_, i32>(12)? != 0,
@@ -108,6 +112,11 @@ impl SqliteBookRepository {
             updated_at: Self::parse_datetime(&row.get::<_, String>(17)?)?,
             status_changed_at: row.get::<_, Option<String>>(18)?
                 .and_then(|s| Self::parse_datetime(&s).ok()),
+            series_id: row.get(19)?,
+            series_index: row.get(20)?,
+            average_rating: row.get(21)?,
+            latest_rating: row.get(22)?,
+            ratings_count: row.get::<_, Option<i32>>(23)?.unwrap_or(0),
         })
     }
 }
@@ -127,8 +136,10 @@ impl BookRepository for SqliteBookRepository {
                 title, author, genre, type, isbn, publication_year,
                 total_pages, total_minutes, current_page_text, current_minutes_audio,
                 status, is_archived, is_wishlist, cover_url, url,
-                added_at, updated_at, status_changed_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+                added_at, updated_at, status_changed_at, series_id, series_index,
+                progress_unit, total_locations
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20,
+                      ?21, ?22)",
             params![
                 book.title,
                 book.author,
@@ -147,7 +158,11 @@ impl BookRepository for SqliteBookRepository {
                 book.url,
                 added_at,
                 updated_at,
-                status_changed_at
+                status_changed_at,
+                book.series_id,
+                book.series_index,
+                book.progress_unit.as_str(),
+                book.total_locations
             ],
         )
         .map_err(|e| format!("Failed to insert book: {}", e))?;
@@ -172,7 +187,9 @@ impl BookRepository for SqliteBookRepository {
                 publication_year = ?7, total_pages = ?8, total_minutes = ?9,
                 current_page_text = ?10, current_minutes_audio = ?11,
                 status = ?12, is_archived = ?13, is_wishlist = ?14,
-                cover_url = ?15, url = ?16, updated_at = ?17, status_changed_at = ?18
+                cover_url = ?15, url = ?16, updated_at = ?17, status_changed_at = ?18,
+                series_id = ?19, series_index = ?20,
+                progress_unit = ?21, total_locations = ?22
             WHERE id = ?1",
             params![
                 id,
@@ -192,7 +209,11 @@ impl BookRepository for SqliteBookRepository {
                 book.cover_url,
                 book.url,
                 updated_at,
-                status_changed_at
+                status_changed_at,
+                book.series_id,
+                book.series_index,
+                book.progress_unit.as_str(),
+                book.total_locations
             ],
         )
         .map_err(|e| format!("Failed to update book: {}", e))?;
@@ -217,13 +238,14 @@ impl BookRepository for SqliteBookRepository {
                 "SELECT id, title, author, genre, type, isbn, publication_year,
                  total_pages, total_minutes, current_page_text, current_minutes_audio,
                  status, is_archived, is_wishlist, cover_url, url,
-                 added_at, updated_at, status_changed_at
-                 FROM books WHERE id = ?1"
+                 added_at, updated_at, status_changed_at, series_id, series_index,
+                 br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                 FROM books LEFT JOIN book_ratings br ON br.book_id = books.id WHERE id = ?1"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let book_result = stmt
-            .query_row(params![id], |row| Self::row_to_book(row));
+            .query_row(params![id], Self::row_to_book);
 
         match book_result {
             Ok(book) => Ok(Some(book)),
@@ -240,13 +262,14 @@ impl BookRepository for SqliteBookRepository {
                 "SELECT id, title, author, genre, type, isbn, publication_year,
                  total_pages, total_minutes, current_page_text, current_minutes_audio,
                  status, is_archived, is_wishlist, cover_url, url,
-                 added_at, updated_at, status_changed_at
-                 FROM books ORDER BY added_at DESC"
+                 added_at, updated_at, status_changed_at, series_id, series_index,
+                 br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                 FROM books LEFT JOIN book_ratings br ON br.book_id = books.id ORDER BY added_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let book_iter = stmt
-            .query_map([], |row| Self::row_to_book(row))
+            .query_map([], Self::row_to_book)
             .map_err(|e| format!("Failed to query books: {}", e))?;
 
         let mut books = Vec::new();
@@ -266,13 +289,14 @@ impl BookRepository for SqliteBookRepository {
                 "SELECT id, title, author, genre, type, isbn, publication_year,
                  total_pages, total_minutes, current_page_text, current_minutes_audio,
                  status, is_archived, is_wishlist, cover_url, url,
-                 added_at, updated_at, status_changed_at
-                 FROM books WHERE status = ?1 ORDER BY added_at DESC"
+                 added_at, updated_at, status_changed_at, series_id, series_index,
+                 br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                 FROM books LEFT JOIN book_ratings br ON br.book_id = books.id WHERE status = ?1 ORDER BY added_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let book_iter = stmt
-            .query_map(params![status_str], |row| Self::row_to_book(row))
+            .query_map(params![status_str], Self::row_to_book)
             .map_err(|e| format!("Failed to query books: {}", e))?;
 
         let mut books = Vec::new();
@@ -292,13 +316,41 @@ impl BookRepository for SqliteBookRepository {
                 "SELECT id, title, author, genre, type, isbn, publication_year,
                  total_pages, total_minutes, current_page_text, current_minutes_audio,
                  status, is_archived, is_wishlist, cover_url, url,
-                 added_at, updated_at, status_changed_at
-                 FROM books WHERE type = ?1 ORDER BY added_at DESC"
+                 added_at, updated_at, status_changed_at, series_id, series_index,
+                 br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                 FROM books LEFT JOIN book_ratings br ON br.book_id = books.id WHERE type = ?1 ORDER BY added_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let book_iter = stmt
-            .query_map(params![type_str], |row| Self::row_to_book(row))
+            .query_map(params![type_str], Self::row_to_book)
+            .map_err(|e| format!("Failed to query books: {}", e))?;
+
+        let mut books = Vec::new();
+        for book_result in book_iter {
+            books.push(book_result.map_err(|e| format!("Failed to parse book: {}", e))?);
+        }
+
+        Ok(books)
+    }
+
+    fn find_by_series_id(&self, series_id: i64) -> Result<Vec<Book>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, title, author, genre, type, isbn, publication_year,
+                 total_pages, total_minutes, current_page_text, current_minutes_audio,
+                 status, is_archived, is_wishlist, cover_url, url,
+                 added_at, updated_at, status_changed_at, series_id, series_index,
+                 br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                 FROM books LEFT JOIN book_ratings br ON br.book_id = books.id WHERE series_id = ?1
+                 ORDER BY series_index IS NULL, series_index ASC, added_at ASC"
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let book_iter = stmt
+            .query_map(params![series_id], Self::row_to_book)
             .map_err(|e| format!("Failed to query books: {}", e))?;
 
         let mut books = Vec::new();
@@ -327,16 +379,19 @@ impl BookRepository for SqliteBookRepository {
             "SELECT DISTINCT b.id, b.title, b.author, b.genre, b.type, b.isbn, b.publication_year,
                          b.total_pages, b.total_minutes, b.current_page_text, b.current_minutes_audio,
                          b.status, b.is_archived, b.is_wishlist, b.cover_url, b.url,
-                         b.added_at, b.updated_at, b.status_changed_at
+                         b.added_at, b.updated_at, b.status_changed_at, b.series_id, b.series_index,
+                         br.average_rating, br.latest_rating, br.ratings_count, b.progress_unit, b.total_locations
                          FROM books b
+                         LEFT JOIN book_ratings br ON br.book_id = b.id
                          INNER JOIN book_collections bc ON b.id = bc.book_id
                          WHERE 1=1".to_string()
         } else {
             "SELECT id, title, author, genre, type, isbn, publication_year,
                          total_pages, total_minutes, current_page_text, current_minutes_audio,
                          status, is_archived, is_wishlist, cover_url, url,
-                         added_at, updated_at, status_changed_at
-                         FROM books WHERE 1=1".to_string()
+                         added_at, updated_at, status_changed_at, series_id, series_index,
+                         br.average_rating, br.latest_rating, br.ratings_count, progress_unit, total_locations
+                         FROM books LEFT JOIN book_ratings br ON br.book_id = books.id WHERE 1=1".to_string()
         };
         
         // Build parameters vector dynamically using Value enum
@@ -0,0 +1,124 @@
+use crate::core::domains::calibre_library::CalibreBook;
+use crate::core::interfaces::secondary::CalibreLibraryReader;
+use chrono::NaiveDate;
+use rusqlite::{Connection, OpenFlags};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Reads a Calibre metadata.db read-only; Calibre may have the library open
+pub struct SqliteCalibreLibraryReader;
+
+impl Default for SqliteCalibreLibraryReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SqliteCalibreLibraryReader {
+    pub fn new() -> Self {
+        SqliteCalibreLibraryReader
+    }
+
+    /// Runs a (book id, value) query and groups the values by book, keeping row order
+    fn values_by_book(conn: &Connection, sql: &str) -> Result<HashMap<i64, Vec<String>>, String> {
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| format!("Failed to query Calibre library: {}", e))?;
+
+        let mut map: HashMap<i64, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (book_id, value) = row.map_err(|e| format!("Failed to read Calibre row: {}", e))?;
+            map.entry(book_id).or_default().push(value);
+        }
+        Ok(map)
+    }
+}
+
+impl CalibreLibraryReader for SqliteCalibreLibraryReader {
+    fn read_books(&self, library_path: &str) -> Result<Vec<CalibreBook>, String> {
+        let path = Path::new(library_path);
+        let db_path = if path.is_dir() { path.join("metadata.db") } else { path.to_path_buf() };
+        if !db_path.exists() {
+            return Err(format!("Calibre database not found: {}", db_path.display()));
+        }
+
+        let conn = Connection::open_with_flags(&db_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Failed to open Calibre database: {}", e))?;
+
+        let authors = Self::values_by_book(
+            &conn,
+            "SELECT l.book, a.name FROM books_authors_link l
+             JOIN authors a ON a.id = l.author ORDER BY l.book, l.id",
+        )?;
+        let tags = Self::values_by_book(
+            &conn,
+            "SELECT l.book, t.name FROM books_tags_link l
+             JOIN tags t ON t.id = l.tag ORDER BY l.book, t.name",
+        )?;
+        let series = Self::values_by_book(
+            &conn,
+            "SELECT l.book, s.name FROM books_series_link l JOIN series s ON s.id = l.series",
+        )?;
+        let identifiers = Self::values_by_book(
+            &conn,
+            "SELECT book, type || ':' || val FROM identifiers ORDER BY book, type",
+        )?;
+        let formats = Self::values_by_book(&conn, "SELECT book, format FROM data ORDER BY book, format")?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, title, series_index, pubdate, timestamp FROM books ORDER BY id")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<f64>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                ))
+            })
+            .map_err(|e| format!("Failed to query Calibre books: {}", e))?;
+
+        let mut books = Vec::new();
+        for row in rows {
+            let (id, title, series_index, pubdate, timestamp) =
+                row.map_err(|e| format!("Failed to read Calibre book: {}", e))?;
+            let series_name = series.get(&id).and_then(|s| s.first().cloned());
+
+            books.push(CalibreBook {
+                calibre_id: id,
+                title,
+                authors: authors.get(&id).cloned().unwrap_or_default(),
+                series_index: if series_name.is_some() { series_index } else { None },
+                series: series_name,
+                tags: tags.get(&id).cloned().unwrap_or_default(),
+                identifiers: identifiers
+                    .get(&id)
+                    .map(|ids| {
+                        ids.iter()
+                            .filter_map(|i| i.split_once(':'))
+                            .map(|(kind, value)| (kind.to_string(), value.to_string()))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                formats: formats.get(&id).cloned().unwrap_or_default(),
+                // Calibre stores an unknown publication date as year 101
+                publication_year: pubdate
+                    .as_deref()
+                    .and_then(|d| d.get(..4))
+                    .and_then(|y| y.parse::<i32>().ok())
+                    .filter(|y| *y > 1000),
+                added_on: timestamp
+                    .as_deref()
+                    .and_then(|t| t.get(..10))
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok()),
+            });
+        }
+
+        Ok(books)
+    }
+}
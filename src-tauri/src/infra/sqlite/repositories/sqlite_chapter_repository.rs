@@ -0,0 +1,73 @@
+use crate::core::domains::audiobook_chapter::AudiobookChapter;
+use crate::core::interfaces::secondary::ChapterRepository;
+use rusqlite::params;
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of ChapterRepository
+pub struct SqliteChapterRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteChapterRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteChapterRepository { connection }
+    }
+
+    fn row_to_chapter(row: &rusqlite::Row) -> Result<AudiobookChapter, rusqlite::Error> {
+        Ok(AudiobookChapter {
+            id: Some(row.get(0)?),
+            book_id: row.get(1)?,
+            position: row.get(2)?,
+            title: row.get(3)?,
+            start_seconds: row.get(4)?,
+        })
+    }
+}
+
+impl ChapterRepository for SqliteChapterRepository {
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<AudiobookChapter>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, book_id, position, title, start_seconds
+                 FROM audiobook_chapters WHERE book_id = ?1 ORDER BY position",
+            )
+            .map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+        let chapter_iter = stmt
+            .query_map(params![book_id], Self::row_to_chapter)
+            .map_err(|e| format!("Failed to query chapters: {}", e))?;
+
+        let mut chapters = Vec::new();
+        for chapter_result in chapter_iter {
+            chapters.push(chapter_result.map_err(|e| format!("Failed to parse chapter: {}", e))?);
+        }
+
+        Ok(chapters)
+    }
+
+    fn replace_for_book(&self, book_id: i64, chapters: &mut [AudiobookChapter]) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        tx.execute("DELETE FROM audiobook_chapters WHERE book_id = ?1", params![book_id])
+            .map_err(|e| format!("Failed to clear chapters: {}", e))?;
+
+        for chapter in chapters.iter_mut() {
+            tx.execute(
+                "INSERT INTO audiobook_chapters (book_id, position, title, start_seconds) VALUES (?1, ?2, ?3, ?4)",
+                params![book_id, chapter.position, chapter.title, chapter.start_seconds],
+            )
+            .map_err(|e| format!("Failed to insert chapter: {}", e))?;
+            chapter.id = Some(tx.last_insert_rowid());
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit chapters: {}", e))?;
+
+        Ok(())
+    }
+}
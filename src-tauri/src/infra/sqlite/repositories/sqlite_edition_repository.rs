@@ -0,0 +1,171 @@
+use crate::core::domains::book::BookType;
+use crate::core::domains::edition::Edition;
+use crate::core::interfaces::secondary::EditionRepository;
+use rusqlite::params;
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of EditionRepository
+pub struct SqliteEditionRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteEditionRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteEditionRepository { connection }
+    }
+
+    fn type_to_string(book_type: &BookType) -> &'static str {
+        match book_type {
+            BookType::PhysicalBook => "physical_book",
+            BookType::Ebook => "ebook",
+            BookType::Audiobook => "audiobook",
+            BookType::Article => "article",
+            BookType::Pdf => "PDF",
+            BookType::Comic => "comic",
+        }
+    }
+
+    fn string_to_type(s: &str) -> Result<BookType, String> {
+        match s {
+            "physical_book" => Ok(BookType::PhysicalBook),
+            "ebook" => Ok(BookType::Ebook),
+            "audiobook" => Ok(BookType::Audiobook),
+            "article" => Ok(BookType::Article),
+            "PDF" => Ok(BookType::Pdf),
+            "comic" => Ok(BookType::Comic),
+            _ => Err(format!("Invalid book type: {}", s)),
+        }
+    }
+
+    // Helper function to parse datetime from SQLite (supports both SQLite format and RFC3339)
+    fn parse_datetime(s: &str, index: usize) -> Result<chrono::DateTime<chrono::Utc>, rusqlite::Error> {
+        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+            return Ok(dt.with_timezone(&chrono::Utc));
+        }
+
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return Ok(dt.and_utc());
+        }
+
+        Err(rusqlite::Error::InvalidColumnType(index, "Invalid datetime".to_string(), rusqlite::types::Type::Text))
+    }
+
+    fn row_to_edition(row: &rusqlite::Row) -> Result<Edition, rusqlite::Error> {
+        Ok(Edition {
+            id: Some(row.get(0)?),
+            book_id: row.get(1)?,
+            format: Self::string_to_type(&row.get::<_, String>(2)?)
+                .map_err(|e| rusqlite::Error::InvalidColumnType(2, e, rusqlite::types::Type::Text))?,
+            label: row.get(3)?,
+            isbn: row.get(4)?,
+            total_pages: row.get(5)?,
+            total_minutes: row.get(6)?,
+            created_at: Self::parse_datetime(&row.get::<_, String>(7)?, 7)?,
+            updated_at: Self::parse_datetime(&row.get::<_, String>(8)?, 8)?,
+        })
+    }
+}
+
+impl EditionRepository for SqliteEditionRepository {
+    fn create(&self, edition: &mut Edition) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let created_at = edition.created_at.to_rfc3339();
+        let updated_at = edition.updated_at.to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO book_editions (
+                book_id, format, label, isbn, total_pages, total_minutes, created_at, updated_at
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                edition.book_id,
+                Self::type_to_string(&edition.format),
+                edition.label,
+                edition.isbn,
+                edition.total_pages,
+                edition.total_minutes,
+                created_at,
+                updated_at
+            ],
+        )
+        .map_err(|e| format!("Failed to insert edition: {}", e))?;
+
+        edition.id = Some(conn.last_insert_rowid());
+        Ok(())
+    }
+
+    fn update(&self, edition: &Edition) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let id = edition.id.ok_or("Edition ID is required for update".to_string())?;
+        let updated_at = edition.updated_at.to_rfc3339();
+
+        conn.execute(
+            "UPDATE book_editions SET
+                format = ?2, label = ?3, isbn = ?4, total_pages = ?5, total_minutes = ?6, updated_at = ?7
+            WHERE id = ?1",
+            params![
+                id,
+                Self::type_to_string(&edition.format),
+                edition.label,
+                edition.isbn,
+                edition.total_pages,
+                edition.total_minutes,
+                updated_at
+            ],
+        )
+        .map_err(|e| format!("Failed to update edition: {}", e))?;
+
+        Ok(())
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute("DELETE FROM book_editions WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete edition: {}", e))?;
+
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: i64) -> Result<Option<Edition>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, book_id, format, label, isbn, total_pages, total_minutes, created_at, updated_at
+                 FROM book_editions WHERE id = ?1"
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let edition_result = stmt.query_row(params![id], Self::row_to_edition);
+
+        match edition_result {
+            Ok(edition) => Ok(Some(edition)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to find edition: {}", e)),
+        }
+    }
+
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<Edition>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, book_id, format, label, isbn, total_pages, total_minutes, created_at, updated_at
+                 FROM book_editions WHERE book_id = ?1 ORDER BY created_at ASC"
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let edition_iter = stmt
+            .query_map(params![book_id], Self::row_to_edition)
+            .map_err(|e| format!("Failed to query editions: {}", e))?;
+
+        let mut editions = Vec::new();
+        for edition_result in edition_iter {
+            editions.push(edition_result.map_err(|e| format!("Failed to parse edition: {}", e))?);
+        }
+
+        Ok(editions)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core::domains::book_metadata::BookMetadata;
+use crate::core::interfaces::secondary::MetadataCacheRepository;
+use rusqlite::params;
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of MetadataCacheRepository
+pub struct SqliteMetadataCacheRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteMetadataCacheRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteMetadataCacheRepository { connection }
+    }
+}
+
+impl MetadataCacheRepository for SqliteMetadataCacheRepository {
+    fn find_by_isbn(&self, isbn: &str) -> Result<Option<BookMetadata>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT isbn, title, authors, total_pages, publication_year, cover_url, genre, source, fetched_at
+                 FROM book_metadata_cache WHERE isbn = ?1",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let result = stmt.query_row(params![isbn], |row| {
+            let authors_json: String = row.get(2)?;
+            let fetched_at_str: String = row.get(8)?;
+            let fetched_at = chrono::DateTime::parse_from_rfc3339(&fetched_at_str)
+                .map_err(|_| rusqlite::Error::InvalidColumnType(8, "Invalid datetime".to_string(), rusqlite::types::Type::Text))?
+                .with_timezone(&chrono::Utc);
+
+            Ok(BookMetadata {
+                isbn: row.get(0)?,
+                title: row.get(1)?,
+                authors: serde_json::from_str(&authors_json).unwrap_or_default(),
+                total_pages: row.get(3)?,
+                publication_year: row.get(4)?,
+                cover_url: row.get(5)?,
+                genre: row.get(6)?,
+                source: row.get(7)?,
+                fetched_at,
+            })
+        });
+
+        match result {
+            Ok(metadata) => Ok(Some(metadata)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to get cached metadata: {}", e)),
+        }
+    }
+
+    fn save(&self, metadata: &BookMetadata) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let authors_json = serde_json::to_string(&metadata.authors)
+            .map_err(|e| format!("Failed to serialize authors: {}", e))?;
+
+        conn.execute(
+            "INSERT INTO book_metadata_cache
+                (isbn, title, authors, total_pages, publication_year, cover_url, genre, source, fetched_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT(isbn) DO UPDATE SET
+                title = ?2, authors = ?3, total_pages = ?4, publication_year = ?5,
+                cover_url = ?6, genre = ?7, source = ?8, fetched_at = ?9",
+            params![
+                metadata.isbn,
+                metadata.title,
+                authors_json,
+                metadata.total_pages,
+                metadata.publication_year,
+                metadata.cover_url,
+                metadata.genre,
+                metadata.source,
+                metadata.fetched_at.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to cache metadata: {}", e))?;
+
+        Ok(())
+    }
+}
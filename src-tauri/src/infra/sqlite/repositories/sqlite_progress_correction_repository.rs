@@ -0,0 +1,118 @@
+use crate::core::domains::progress_correction::{CorrectionMode, CorrectionUnit, ProgressCorrection, SessionChange};
+use crate::core::domains::session::ReadingSession;
+use crate::core::interfaces::secondary::ProgressCorrectionRepository;
+use crate::infra::sqlite::repositories::sqlite_session_repository::SqliteSessionRepository;
+use rusqlite::{params, Row};
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of ProgressCorrectionRepository
+pub struct SqliteProgressCorrectionRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteProgressCorrectionRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteProgressCorrectionRepository { connection }
+    }
+
+    fn row_to_correction(row: &Row) -> Result<ProgressCorrection, rusqlite::Error> {
+        let invalid = |index: usize, message: String| {
+            rusqlite::Error::InvalidColumnType(index, message, rusqlite::types::Type::Text)
+        };
+
+        let mode: String = row.get(2)?;
+        let unit: String = row.get(3)?;
+        let changes_json: String = row.get(7)?;
+        let created_at: String = row.get(9)?;
+
+        let changes: Vec<SessionChange> = serde_json::from_str(&changes_json)
+            .map_err(|e| invalid(7, format!("Invalid changes: {}", e)))?;
+
+        Ok(ProgressCorrection {
+            id: row.get(0)?,
+            book_id: row.get(1)?,
+            mode: CorrectionMode::parse(&mode).map_err(|e| invalid(2, e))?,
+            unit: CorrectionUnit::parse(&unit).map_err(|e| invalid(3, e))?,
+            previous_value: row.get(4)?,
+            corrected_value: row.get(5)?,
+            adjustment_session_id: row.get(6)?,
+            changes,
+            note: row.get(8)?,
+            created_at: chrono::DateTime::parse_from_rfc3339(&created_at)
+                .map(|d| d.with_timezone(&chrono::Utc))
+                .map_err(|_| invalid(9, "Invalid datetime".to_string()))?,
+        })
+    }
+}
+
+impl ProgressCorrectionRepository for SqliteProgressCorrectionRepository {
+    fn create(
+        &self,
+        correction: &mut ProgressCorrection,
+        adjustment: Option<&mut ReadingSession>,
+        updated: &[ReadingSession],
+    ) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        if let Some(session) = adjustment {
+            SqliteSessionRepository::insert_session(&tx, session)?;
+            correction.adjustment_session_id = session.id;
+            for change in correction.changes.iter_mut() {
+                change.session_id = session.id;
+            }
+        }
+        for session in updated {
+            SqliteSessionRepository::update_session(&tx, session)?;
+        }
+
+        let changes_json = serde_json::to_string(&correction.changes)
+            .map_err(|e| format!("Failed to serialize correction changes: {}", e))?;
+
+        tx.execute(
+            "INSERT INTO progress_corrections
+                (book_id, mode, unit, previous_value, corrected_value, adjustment_session_id, changes, note, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                correction.book_id,
+                correction.mode.as_str(),
+                correction.unit.as_str(),
+                correction.previous_value,
+                correction.corrected_value,
+                correction.adjustment_session_id,
+                changes_json,
+                correction.note,
+                correction.created_at.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to record progress correction: {}", e))?;
+        let id = tx.last_insert_rowid();
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        correction.id = Some(id);
+        Ok(())
+    }
+
+    fn find_by_book_id(&self, book_id: i64) -> Result<Vec<ProgressCorrection>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, book_id, mode, unit, previous_value, corrected_value, adjustment_session_id, changes, note, created_at
+                 FROM progress_corrections WHERE book_id = ?1 ORDER BY created_at DESC, id DESC",
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let corrections = stmt
+            .query_map(params![book_id], Self::row_to_correction)
+            .map_err(|e| format!("Failed to query progress corrections: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to map progress corrections: {}", e))?;
+
+        Ok(corrections)
+    }
+}
@@ -58,7 +58,7 @@ impl SqliteReadingRepository {
             .with_timezone(&Utc);
 
         let status = Self::string_to_status(&status_str)
-            .map_err(|e| rusqlite::Error::InvalidColumnType(5, "status".to_string(), rusqlite::types::Type::Text))?;
+            .map_err(|_| rusqlite::Error::InvalidColumnType(5, "status".to_string(), rusqlite::types::Type::Text))?;
 
         Ok(Reading {
             id: Some(id),
@@ -68,6 +68,8 @@ impl SqliteReadingRepository {
             completed_at,
             status,
             created_at,
+            rating: row.get(7)?,
+            review: row.get(8)?,
         })
     }
 }
@@ -77,8 +79,8 @@ impl ReadingRepository for SqliteReadingRepository {
         let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
         
         conn.execute(
-            "INSERT INTO book_readings (book_id, reading_number, started_at, completed_at, status, created_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO book_readings (book_id, reading_number, started_at, completed_at, status, created_at, rating, review)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
             params![
                 reading.book_id,
                 reading.reading_number,
@@ -86,6 +88,8 @@ impl ReadingRepository for SqliteReadingRepository {
                 reading.completed_at.map(|dt| dt.to_rfc3339()),
                 Self::status_to_string(&reading.status),
                 reading.created_at.to_rfc3339(),
+                reading.rating,
+                reading.review,
             ],
         )
         .map_err(|e| format!("Failed to create reading: {}", e))?;
@@ -101,6 +105,8 @@ impl ReadingRepository for SqliteReadingRepository {
             completed_at: reading.completed_at,
             status: reading.status.clone(),
             created_at: reading.created_at,
+            rating: reading.rating,
+            review: reading.review.clone(),
         })
     }
 
@@ -110,12 +116,14 @@ impl ReadingRepository for SqliteReadingRepository {
         
         conn.execute(
             "UPDATE book_readings
-             SET started_at = ?1, completed_at = ?2, status = ?3
-             WHERE id = ?4",
+             SET started_at = ?1, completed_at = ?2, status = ?3, rating = ?4, review = ?5
+             WHERE id = ?6",
             params![
                 reading.started_at.map(|dt| dt.to_rfc3339()),
                 reading.completed_at.map(|dt| dt.to_rfc3339()),
                 Self::status_to_string(&reading.status),
+                reading.rating,
+                reading.review,
                 id,
             ],
         )
@@ -140,7 +148,7 @@ impl ReadingRepository for SqliteReadingRepository {
         
         let mut stmt = conn
             .prepare(
-                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at
+                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at, rating, review
                  FROM book_readings WHERE id = ?1"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
@@ -159,7 +167,7 @@ impl ReadingRepository for SqliteReadingRepository {
         
         let mut stmt = conn
             .prepare(
-                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at
+                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at, rating, review
                  FROM book_readings ORDER BY book_id, reading_number ASC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
@@ -184,7 +192,7 @@ impl ReadingRepository for SqliteReadingRepository {
         
         let mut stmt = conn
             .prepare(
-                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at
+                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at, rating, review
                  FROM book_readings WHERE book_id = ?1 ORDER BY reading_number ASC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
@@ -209,7 +217,7 @@ impl ReadingRepository for SqliteReadingRepository {
         
         let mut stmt = conn
             .prepare(
-                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at
+                "SELECT id, book_id, reading_number, started_at, completed_at, status, created_at, rating, review
                  FROM book_readings 
                  WHERE book_id = ?1 AND status IN ('reading', 'paused')
                  ORDER BY reading_number DESC
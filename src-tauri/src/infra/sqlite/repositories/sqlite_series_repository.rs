@@ -0,0 +1,134 @@
+use crate::core::domains::series::Series;
+use crate::core::interfaces::secondary::SeriesRepository;
+use rusqlite::params;
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of SeriesRepository
+pub struct SqliteSeriesRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteSeriesRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteSeriesRepository { connection }
+    }
+
+    // Helper function to parse datetime from SQLite (supports both SQLite format and RFC3339)
+    // The updated_at trigger writes datetime('now')
+    fn parse_datetime(s: &str, index: usize) -> Result<chrono::DateTime<chrono::Utc>, rusqlite::Error> {
+        if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+            return Ok(dt.with_timezone(&chrono::Utc));
+        }
+
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return Ok(dt.and_utc());
+        }
+
+        Err(rusqlite::Error::InvalidColumnType(index, "Invalid datetime".to_string(), rusqlite::types::Type::Text))
+    }
+
+    fn row_to_series(row: &rusqlite::Row) -> Result<Series, rusqlite::Error> {
+        Ok(Series {
+            id: Some(row.get(0)?),
+            name: row.get(1)?,
+            author: row.get(2)?,
+            expected_volumes: row.get(3)?,
+            created_at: Self::parse_datetime(&row.get::<_, String>(4)?, 4)?,
+            updated_at: Self::parse_datetime(&row.get::<_, String>(5)?, 5)?,
+        })
+    }
+}
+
+impl SeriesRepository for SqliteSeriesRepository {
+    fn create(&self, series: &mut Series) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let created_at = series.created_at.to_rfc3339();
+        let updated_at = series.updated_at.to_rfc3339();
+
+        conn.execute(
+            "INSERT INTO series (name, author, expected_volumes, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![series.name, series.author, series.expected_volumes, created_at, updated_at],
+        )
+        .map_err(|e| format!("Failed to insert series: {}", e))?;
+
+        series.id = Some(conn.last_insert_rowid());
+        Ok(())
+    }
+
+    fn update(&self, series: &Series) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let id = series.id.ok_or("Series ID is required for update".to_string())?;
+        let updated_at = series.updated_at.to_rfc3339();
+
+        conn.execute(
+            "UPDATE series SET name = ?2, author = ?3, expected_volumes = ?4, updated_at = ?5 WHERE id = ?1",
+            params![id, series.name, series.author, series.expected_volumes, updated_at],
+        )
+        .map_err(|e| format!("Failed to update series: {}", e))?;
+
+        Ok(())
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        conn.execute("DELETE FROM series WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete series: {}", e))?;
+
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: i64) -> Result<Option<Series>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let mut stmt = conn
+            .prepare("SELECT id, name, author, expected_volumes, created_at, updated_at FROM series WHERE id = ?1")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let series_result = stmt.query_row(params![id], Self::row_to_series);
+
+        match series_result {
+            Ok(series) => Ok(Some(series)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to find series: {}", e)),
+        }
+    }
+
+    fn find_by_name(&self, name: &str) -> Result<Option<Series>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let mut stmt = conn
+            .prepare("SELECT id, name, author, expected_volumes, created_at, updated_at FROM series WHERE name = ?1")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let series_result = stmt.query_row(params![name], Self::row_to_series);
+
+        match series_result {
+            Ok(series) => Ok(Some(series)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(format!("Failed to find series: {}", e)),
+        }
+    }
+
+    fn find_all(&self) -> Result<Vec<Series>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        
+        let mut stmt = conn
+            .prepare("SELECT id, name, author, expected_volumes, created_at, updated_at FROM series ORDER BY name")
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let series_iter = stmt
+            .query_map([], Self::row_to_series)
+            .map_err(|e| format!("Failed to query series: {}", e))?;
+
+        let mut series_list = Vec::new();
+        for series_result in series_iter {
+            series_list.push(series_result.map_err(|e| format!("Failed to parse series: {}", e))?);
+        }
+
+        Ok(series_list)
+    }
+}
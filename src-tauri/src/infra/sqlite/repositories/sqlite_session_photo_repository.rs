@@ -0,0 +1,112 @@
+use crate::core::domains::session_photo::SessionPhoto;
+use crate::core::interfaces::secondary::SessionPhotoRepository;
+use rusqlite::{params, Row};
+use std::sync::{Arc, Mutex};
+
+/// SQLite implementation of SessionPhotoRepository
+pub struct SqliteSessionPhotoRepository {
+    connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+impl SqliteSessionPhotoRepository {
+    pub fn new(connection: Arc<Mutex<rusqlite::Connection>>) -> Self {
+        SqliteSessionPhotoRepository { connection }
+    }
+
+    fn row_to_photo(row: &Row) -> Result<SessionPhoto, rusqlite::Error> {
+        let created_at_str: String = row.get(7)?;
+        let created_at = chrono::DateTime::parse_from_rfc3339(&created_at_str)
+            .map_err(|_| rusqlite::Error::InvalidColumnType(7, "Invalid datetime".to_string(), rusqlite::types::Type::Text))?
+            .with_timezone(&chrono::Utc);
+
+        Ok(SessionPhoto {
+            id: row.get(0)?,
+            session_id: row.get(1)?,
+            hash: row.get(2)?,
+            extension: row.get(3)?,
+            width: row.get(4)?,
+            height: row.get(5)?,
+            source: row.get(6)?,
+            created_at,
+        })
+    }
+
+    fn query(&self, filter: &str, values: &[&dyn rusqlite::ToSql]) -> Result<Vec<SessionPhoto>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT id, session_id, hash, extension, width, height, source, created_at
+                 FROM session_photos {} ORDER BY created_at, id",
+                filter
+            ))
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let photos = stmt
+            .query_map(values, Self::row_to_photo)
+            .map_err(|e| format!("Failed to query session photos: {}", e))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("Failed to map session photos: {}", e))?;
+
+        Ok(photos)
+    }
+}
+
+impl SessionPhotoRepository for SqliteSessionPhotoRepository {
+    fn create(&self, photo: &mut SessionPhoto) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let inserted = conn.execute(
+            "INSERT INTO session_photos (session_id, hash, extension, width, height, source, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(session_id, hash) DO NOTHING",
+            params![
+                photo.session_id,
+                photo.hash,
+                photo.extension,
+                photo.width,
+                photo.height,
+                photo.source,
+                photo.created_at.to_rfc3339(),
+            ],
+        )
+        .map_err(|e| format!("Failed to save session photo: {}", e))?;
+
+        if inserted > 0 {
+            photo.id = Some(conn.last_insert_rowid());
+            return Ok(());
+        }
+
+        // Same image already linked to this session
+        *photo = conn
+            .query_row(
+                "SELECT id, session_id, hash, extension, width, height, source, created_at
+                 FROM session_photos WHERE session_id = ?1 AND hash = ?2",
+                params![photo.session_id, photo.hash],
+                Self::row_to_photo,
+            )
+            .map_err(|e| format!("Failed to load existing session photo: {}", e))?;
+        Ok(())
+    }
+
+    fn find_by_id(&self, id: i64) -> Result<Option<SessionPhoto>, String> {
+        Ok(self.query("WHERE id = ?1", &[&id])?.into_iter().next())
+    }
+
+    fn find_by_session_id(&self, session_id: i64) -> Result<Vec<SessionPhoto>, String> {
+        self.query("WHERE session_id = ?1", &[&session_id])
+    }
+
+    fn find_all(&self) -> Result<Vec<SessionPhoto>, String> {
+        self.query("", &[])
+    }
+
+    fn delete(&self, id: i64) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        conn.execute("DELETE FROM session_photos WHERE id = ?1", params![id])
+            .map_err(|e| format!("Failed to delete session photo: {}", e))?;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use crate::core::domains::progress_unit::ProgressUnit;
+use crate::core::domains::reading_speed::{SpeedSample, SpeedUnit};
 use crate::core::domains::session::ReadingSession;
+use crate::core::domains::session_context::{ReadingDevice, SessionContext};
 use crate::core::interfaces::secondary::SessionRepository;
 use rusqlite::params;
 use std::sync::{Arc, Mutex};
@@ -36,50 +39,8 @@ impl SqliteSessionRepository {
         ))
     }
 
-    fn row_to_session(row: &rusqlite::Row) -> Result<ReadingSession, rusqlite::Error> {
-        let session_date_str: String = row.get(3)?;
-        let session_date = chrono::NaiveDate::parse_from_str(&session_date_str, "%Y-%m-%d")
-            .map_err(|_| rusqlite::Error::InvalidColumnType(3, "Invalid date".to_string(), rusqlite::types::Type::Text))?;
-
-        let start_time_str: Option<String> = row.get(4)?;
-        let start_time = start_time_str
-            .and_then(|s| chrono::NaiveTime::parse_from_str(&s, "%H:%M:%S").ok());
-
-        let end_time_str: Option<String> = row.get(5)?;
-        let end_time = end_time_str
-            .and_then(|s| chrono::NaiveTime::parse_from_str(&s, "%H:%M:%S").ok());
-
-        let created_at_str: String = row.get(12)?;
-        let created_at = Self::parse_datetime(&created_at_str)
-            .map_err(|e| rusqlite::Error::InvalidColumnType(12, format!("Invalid created_at: {}", e), rusqlite::types::Type::Text))?;
-
-        let updated_at_str: String = row.get(13)?;
-        let updated_at = Self::parse_datetime(&updated_at_str)
-            .map_err(|e| rusqlite::Error::InvalidColumnType(13, format!("Invalid updated_at: {}", e), rusqlite::types::Type::Text))?;
-
-        Ok(ReadingSession {
-            id: Some(row.get(0)?),
-            book_id: row.get(1)?,
-            reading_id: row.get(2)?,
-            session_date,
-            start_time,
-            end_time,
-            start_page: row.get(6)?,
-            end_page: row.get(7)?,
-            pages_read: row.get(8)?,
-            minutes_read: row.get(9)?,
-            duration_seconds: row.get(10)?,
-            photo_path: row.get(11)?,
-            created_at,
-            updated_at,
-        })
-    }
-}
-
-impl SessionRepository for SqliteSessionRepository {
-    fn create(&self, session: &mut ReadingSession) -> Result<(), String> {
-        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
-        
+    /// Inserts a session on the given connection (or transaction) and sets its ID
+    pub(crate) fn insert_session(conn: &rusqlite::Connection, session: &mut ReadingSession) -> Result<(), String> {
         let session_date_str = session.session_date.format("%Y-%m-%d").to_string();
         let start_time_str = session.start_time.map(|t| t.format("%H:%M:%S").to_string());
         let end_time_str = session.end_time.map(|t| t.format("%H:%M:%S").to_string());
@@ -90,8 +51,11 @@ impl SessionRepository for SqliteSessionRepository {
             "INSERT INTO reading_sessions (
                 book_id, reading_id, session_date, start_time, end_time,
                 start_page, end_page, pages_read, minutes_read, duration_seconds,
-                photo_path, created_at, updated_at
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+                photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                playback_speed, position_unit, start_position, end_position
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                      ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28)",
             params![
                 session.book_id,
                 session.reading_id,
@@ -105,7 +69,22 @@ impl SessionRepository for SqliteSessionRepository {
                 session.duration_seconds,
                 session.photo_path,
                 created_at,
-                updated_at
+                updated_at,
+                session.edition_id,
+                session.started_at.map(|d| d.to_rfc3339()),
+                session.ended_at.map(|d| d.to_rfc3339()),
+                session.timezone,
+                session.context.location,
+                session.context.device.map(|d| d.as_str()),
+                session.context.mood_before,
+                session.context.mood_after,
+                session.context.energy_before,
+                session.context.energy_after,
+                session.context.interruptions,
+                session.playback_speed,
+                session.position_unit.map(|u| u.as_str()),
+                session.start_position,
+                session.end_position
             ],
         )
         .map_err(|e| format!("Failed to insert session: {}", e))?;
@@ -114,9 +93,8 @@ impl SessionRepository for SqliteSessionRepository {
         Ok(())
     }
 
-    fn update(&self, session: &ReadingSession) -> Result<(), String> {
-        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
-        
+    /// Updates a session on the given connection (or transaction)
+    pub(crate) fn update_session(conn: &rusqlite::Connection, session: &ReadingSession) -> Result<(), String> {
         let id = session.id.ok_or("Session ID is required for update".to_string())?;
         let session_date_str = session.session_date.format("%Y-%m-%d").to_string();
         let start_time_str = session.start_time.map(|t| t.format("%H:%M:%S").to_string());
@@ -127,7 +105,11 @@ impl SessionRepository for SqliteSessionRepository {
             "UPDATE reading_sessions SET
                 book_id = ?2, reading_id = ?3, session_date = ?4, start_time = ?5, end_time = ?6,
                 start_page = ?7, end_page = ?8, pages_read = ?9, minutes_read = ?10,
-                duration_seconds = ?11, photo_path = ?12, updated_at = ?13
+                duration_seconds = ?11, photo_path = ?12, updated_at = ?13, edition_id = ?14,
+                started_at = ?15, ended_at = ?16, timezone = ?17,
+                location = ?18, device = ?19, mood_before = ?20, mood_after = ?21,
+                energy_before = ?22, energy_after = ?23, interruptions = ?24,
+                playback_speed = ?25, position_unit = ?26, start_position = ?27, end_position = ?28
             WHERE id = ?1",
             params![
                 id,
@@ -142,7 +124,22 @@ impl SessionRepository for SqliteSessionRepository {
                 session.minutes_read,
                 session.duration_seconds,
                 session.photo_path,
-                updated_at
+                updated_at,
+                session.edition_id,
+                session.started_at.map(|d| d.to_rfc3339()),
+                session.ended_at.map(|d| d.to_rfc3339()),
+                session.timezone,
+                session.context.location,
+                session.context.device.map(|d| d.as_str()),
+                session.context.mood_before,
+                session.context.mood_after,
+                session.context.energy_before,
+                session.context.energy_after,
+                session.context.interruptions,
+                session.playback_speed,
+                session.position_unit.map(|u| u.as_str()),
+                session.start_position,
+                session.end_position
             ],
         )
         .map_err(|e| format!("Failed to update session: {}", e))?;
@@ -150,6 +147,126 @@ impl SessionRepository for SqliteSessionRepository {
         Ok(())
     }
 
+    fn row_to_session(row: &rusqlite::Row) -> Result<ReadingSession, rusqlite::Error> {
+        let session_date_str: String = row.get(3)?;
+        let session_date = chrono::NaiveDate::parse_from_str(&session_date_str, "%Y-%m-%d")
+            .map_err(|_| rusqlite::Error::InvalidColumnType(3, "Invalid date".to_string(), rusqlite::types::Type::Text))?;
+
+        let start_time_str: Option<String> = row.get(4)?;
+        let start_time = start_time_str
+            .and_then(|s| chrono::NaiveTime::parse_from_str(&s, "%H:%M:%S").ok());
+
+        let end_time_str: Option<String> = row.get(5)?;
+        let end_time = end_time_str
+            .and_then(|s| chrono::NaiveTime::parse_from_str(&s, "%H:%M:%S").ok());
+
+        let created_at_str: String = row.get(12)?;
+        let created_at = Self::parse_datetime(&created_at_str)
+            .map_err(|e| rusqlite::Error::InvalidColumnType(12, format!("Invalid created_at: {}", e), rusqlite::types::Type::Text))?;
+
+        let updated_at_str: String = row.get(13)?;
+        let updated_at = Self::parse_datetime(&updated_at_str)
+            .map_err(|e| rusqlite::Error::InvalidColumnType(13, format!("Invalid updated_at: {}", e), rusqlite::types::Type::Text))?;
+
+        let started_at_str: Option<String> = row.get(15)?;
+        let started_at = started_at_str
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok());
+
+        let ended_at_str: Option<String> = row.get(16)?;
+        let ended_at = ended_at_str
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok());
+
+        let device_str: Option<String> = row.get(19)?;
+        let device = device_str
+            .map(|d| ReadingDevice::parse(&d))
+            .transpose()
+            .map_err(|e| rusqlite::Error::InvalidColumnType(19, e, rusqlite::types::Type::Text))?;
+
+        let position_unit_str: Option<String> = row.get(26)?;
+        let position_unit = position_unit_str
+            .map(|u| ProgressUnit::parse(&u))
+            .transpose()
+            .map_err(|e| rusqlite::Error::InvalidColumnType(26, e, rusqlite::types::Type::Text))?;
+
+        Ok(ReadingSession {
+            id: Some(row.get(0)?),
+            book_id: row.get(1)?,
+            reading_id: row.get(2)?,
+            edition_id: row.get(14)?,
+            session_date,
+            start_time,
+            end_time,
+            started_at,
+            ended_at,
+            timezone: row.get(17)?,
+            start_page: row.get(6)?,
+            end_page: row.get(7)?,
+            pages_read: row.get(8)?,
+            position_unit,
+            start_position: row.get(27)?,
+            end_position: row.get(28)?,
+            minutes_read: row.get(9)?,
+            playback_speed: row.get(25)?,
+            duration_seconds: row.get(10)?,
+            photo_path: row.get(11)?,
+            context: SessionContext {
+                location: row.get(18)?,
+                device,
+                mood_before: row.get(20)?,
+                mood_after: row.get(21)?,
+                energy_before: row.get(22)?,
+                energy_after: row.get(23)?,
+                interruptions: row.get(24)?,
+            },
+            created_at,
+            updated_at,
+        })
+    }
+}
+
+impl SessionRepository for SqliteSessionRepository {
+    fn create(&self, session: &mut ReadingSession) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        Self::insert_session(&conn, session)
+    }
+
+    fn create_many(&self, sessions: &mut [ReadingSession]) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for session in sessions.iter_mut() {
+            Self::insert_session(&tx, session)?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
+    fn update(&self, session: &ReadingSession) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+        Self::update_session(&conn, session)
+    }
+
+    fn update_many(&self, sessions: &[ReadingSession]) -> Result<(), String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let tx = conn.unchecked_transaction()
+            .map_err(|e| format!("Failed to begin transaction: {}", e))?;
+
+        for session in sessions {
+            Self::update_session(&tx, session)?;
+        }
+
+        tx.commit()
+            .map_err(|e| format!("Failed to commit transaction: {}", e))?;
+
+        Ok(())
+    }
+
     fn delete(&self, id: i64) -> Result<(), String> {
         let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
         
@@ -166,13 +283,15 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions WHERE id = ?1"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_result = stmt
-            .query_row(params![id], |row| Self::row_to_session(row));
+            .query_row(params![id], Self::row_to_session);
 
         match session_result {
             Ok(session) => Ok(Some(session)),
@@ -188,13 +307,15 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions ORDER BY session_date DESC, created_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_iter = stmt
-            .query_map([], |row| Self::row_to_session(row))
+            .query_map([], Self::row_to_session)
             .map_err(|e| format!("Failed to query sessions: {}", e))?;
 
         let mut sessions = Vec::new();
@@ -205,6 +326,39 @@ impl SessionRepository for SqliteSessionRepository {
         Ok(sessions)
     }
 
+    fn find_speed_samples(&self) -> Result<Vec<SpeedSample>, String> {
+        let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT b.type = 'audiobook',
+                 CASE WHEN b.type = 'audiobook' THEN s.minutes_read ELSE s.pages_read END AS units,
+                 s.duration_seconds
+                 FROM reading_sessions s
+                 JOIN books b ON b.id = s.book_id
+                 WHERE s.edition_id IS NULL AND s.duration_seconds > 0 AND units > 0"
+            )
+            .map_err(|e| format!("Failed to prepare statement: {}", e))?;
+
+        let sample_iter = stmt
+            .query_map([], |row| {
+                let is_audio: bool = row.get(0)?;
+                Ok(SpeedSample {
+                    unit: if is_audio { SpeedUnit::AudioMinutes } else { SpeedUnit::Pages },
+                    units: row.get(1)?,
+                    duration_seconds: row.get(2)?,
+                })
+            })
+            .map_err(|e| format!("Failed to query speed samples: {}", e))?;
+
+        let mut samples = Vec::new();
+        for sample_result in sample_iter {
+            samples.push(sample_result.map_err(|e| format!("Failed to parse speed sample: {}", e))?);
+        }
+
+        Ok(samples)
+    }
+
     fn find_by_book_id(&self, book_id: i64) -> Result<Vec<ReadingSession>, String> {
         let conn = self.connection.lock().map_err(|e| format!("Lock error: {}", e))?;
         
@@ -212,14 +366,16 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions WHERE book_id = ?1
                  ORDER BY session_date DESC, created_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_iter = stmt
-            .query_map(params![book_id], |row| Self::row_to_session(row))
+            .query_map(params![book_id], Self::row_to_session)
             .map_err(|e| format!("Failed to query sessions: {}", e))?;
 
         let mut sessions = Vec::new();
@@ -237,14 +393,16 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions WHERE reading_id = ?1
                  ORDER BY session_date DESC, created_at DESC"
             )
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_iter = stmt
-            .query_map(params![reading_id], |row| Self::row_to_session(row))
+            .query_map(params![reading_id], Self::row_to_session)
             .map_err(|e| format!("Failed to query sessions: {}", e))?;
 
         let mut sessions = Vec::new();
@@ -269,7 +427,9 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions 
                  WHERE session_date >= ?1 AND session_date <= ?2
                  ORDER BY session_date DESC, created_at DESC"
@@ -277,7 +437,7 @@ impl SessionRepository for SqliteSessionRepository {
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_iter = stmt
-            .query_map(params![start_str, end_str], |row| Self::row_to_session(row))
+            .query_map(params![start_str, end_str], Self::row_to_session)
             .map_err(|e| format!("Failed to query sessions: {}", e))?;
 
         let mut sessions = Vec::new();
@@ -303,7 +463,9 @@ impl SessionRepository for SqliteSessionRepository {
             .prepare(
                 "SELECT id, book_id, reading_id, session_date, start_time, end_time,
                  start_page, end_page, pages_read, minutes_read, duration_seconds,
-                 photo_path, created_at, updated_at
+                 photo_path, created_at, updated_at, edition_id, started_at, ended_at, timezone,
+                 location, device, mood_before, mood_after, energy_before, energy_after, interruptions,
+                 playback_speed, position_unit, start_position, end_position
                  FROM reading_sessions 
                  WHERE book_id = ?1 AND session_date >= ?2 AND session_date <= ?3
                  ORDER BY session_date DESC, created_at DESC"
@@ -311,7 +473,7 @@ impl SessionRepository for SqliteSessionRepository {
             .map_err(|e| format!("Failed to prepare statement: {}", e))?;
 
         let session_iter = stmt
-            .query_map(params![book_id, start_str, end_str], |row| Self::row_to_session(row))
+            .query_map(params![book_id, start_str, end_str], Self::row_to_session)
             .map_err(|e| format!("Failed to query sessions: {}", e))?;
 
         let mut sessions = Vec::new();
@@ -14,17 +14,35 @@ use app::state::AppState;
 use app::commands::{
     create_book, get_book, list_books, update_book, delete_book,
     create_session, get_session, list_sessions, update_session, delete_session,
+    validate_new_session, validate_session_update,
+    create_sessions_bulk, preview_sessions_csv_import, import_sessions_csv,
+    correct_progress, list_progress_corrections,
+    start_session_timer, pause_session_timer, resume_session_timer,
+    get_active_session_timer, finish_session_timer, cancel_session_timer,
     create_note, get_note, list_notes, update_note, delete_note,
-    create_goal, get_goal, list_goals, delete_goal, get_statistics,
+    create_goal, get_goal, list_goals, delete_goal, get_statistics, get_context_statistics,
     get_setting, set_setting, get_all_settings,
     create_tag, list_tags, delete_tag, add_tags_to_book, remove_tag_from_book,
     create_collection, list_collections, update_collection, delete_collection,
-    add_books_to_collection, remove_book_from_collection,
+    add_books_to_collection, remove_book_from_collection, get_collection_stats,
+    create_series, list_series, get_series, update_series, delete_series, get_next_in_series,
+    create_author, list_authors, get_author, update_author, delete_author, merge_authors,
+    get_book_contributors, set_book_contributors,
+    create_edition, list_editions, update_edition, delete_edition, get_work_progress,
+    list_chapters, set_chapters, get_chapter_progress,
+    preview_library_import, import_library, export_library, import_kindle_clippings,
+    import_koreader_annotations, import_calibre_library, export_markdown,
+    export_book_pdf, export_table, export_citations, import_citations,
+    validate_isbn, lookup_isbn_metadata,
+    import_book_cover, import_remote_covers, get_book_cover, remove_book_cover,
+    cleanup_orphaned_covers, backup_book_covers, restore_book_covers,
+    attach_session_photo, list_session_photos, get_session_photo, delete_session_photo,
+    cleanup_orphaned_session_photos, backup_session_photos, restore_session_photos,
     create_journal_entry, update_journal_entry, delete_journal_entry,
     get_journal_entry, list_journal_entries,
     create_agenda_block, update_agenda_block, delete_agenda_block,
     get_agenda_block, list_agenda_blocks, mark_agenda_block_completed,
-    create_reading, list_readings, get_reading, get_current_reading,
+    create_reading, list_readings, get_reading, get_current_reading, rate_reading,
     register_backup, get_last_backup_date, get_backup_metadata,            validate_backup_json,
            check_integrity,
            generate_book_summary,
@@ -61,6 +79,19 @@ pub fn run() {
             list_sessions,
             update_session,
             delete_session,
+            validate_new_session,
+            validate_session_update,
+            create_sessions_bulk,
+            preview_sessions_csv_import,
+            import_sessions_csv,
+            correct_progress,
+            list_progress_corrections,
+            start_session_timer,
+            pause_session_timer,
+            resume_session_timer,
+            get_active_session_timer,
+            finish_session_timer,
+            cancel_session_timer,
             create_note,
             get_note,
             list_notes,
@@ -71,6 +102,7 @@ pub fn run() {
             list_goals,
             delete_goal,
             get_statistics,
+            get_context_statistics,
             get_setting,
             set_setting,
             get_all_settings,
@@ -85,6 +117,56 @@ pub fn run() {
             delete_collection,
             add_books_to_collection,
             remove_book_from_collection,
+            get_collection_stats,
+            create_series,
+            list_series,
+            get_series,
+            update_series,
+            delete_series,
+            get_next_in_series,
+            create_author,
+            list_authors,
+            get_author,
+            update_author,
+            delete_author,
+            merge_authors,
+            get_book_contributors,
+            set_book_contributors,
+            create_edition,
+            list_editions,
+            update_edition,
+            delete_edition,
+            get_work_progress,
+            list_chapters,
+            set_chapters,
+            get_chapter_progress,
+            preview_library_import,
+            import_library,
+            export_library,
+            import_kindle_clippings,
+            import_koreader_annotations,
+            import_calibre_library,
+            export_markdown,
+            export_book_pdf,
+            export_table,
+            export_citations,
+            import_citations,
+            validate_isbn,
+            lookup_isbn_metadata,
+            import_book_cover,
+            import_remote_covers,
+            get_book_cover,
+            remove_book_cover,
+            cleanup_orphaned_covers,
+            backup_book_covers,
+            restore_book_covers,
+            attach_session_photo,
+            list_session_photos,
+            get_session_photo,
+            delete_session_photo,
+            cleanup_orphaned_session_photos,
+            backup_session_photos,
+            restore_session_photos,
             create_journal_entry,
             update_journal_entry,
             delete_journal_entry,
@@ -100,6 +182,7 @@ pub fn run() {
             list_readings,
             get_reading,
             get_current_reading,
+            rate_reading,
             register_backup,
             get_last_backup_date,
             get_backup_metadata,
@@ -36,7 +36,13 @@ export function StatisticsOverview({ statistics, loading, error }: StatisticsOve
     <Section padding="md">
       <Stack spacing="md">
         <Heading level={3}>Statistics Overview</Heading>
-        <div className="grid grid-cols-2 md:grid-cols-4 gap-4">
+        <div className="grid grid-cols-2 md:grid-cols-3 gap-4">
+          <HandDrawnBox borderRadius={6} strokeWidth={1} linearCorners={true} className="p-4 bg-background-surface">
+            <MetaText className="block mb-1">Minutes Today</MetaText>
+            <Paragraph className="text-2xl font-bold">
+              {Math.round(statistics.today.duration_seconds / 60)}
+            </Paragraph>
+          </HandDrawnBox>
           <HandDrawnBox borderRadius={6} strokeWidth={1} linearCorners={true} className="p-4 bg-background-surface">
             <MetaText className="block mb-1">Total Pages</MetaText>
             <Paragraph className="text-2xl font-bold">{statistics.total_pages_read}</Paragraph>
@@ -55,6 +61,21 @@ export function StatisticsOverview({ statistics, loading, error }: StatisticsOve
               {statistics.average_pages_per_session.toFixed(1)}
             </Paragraph>
           </HandDrawnBox>
+          <HandDrawnBox borderRadius={6} strokeWidth={1} linearCorners={true} className="p-4 bg-background-surface">
+            <MetaText className="block mb-1">Listened This Month</MetaText>
+            <Paragraph className="text-2xl font-bold">{statistics.this_month.listening_minutes} min</Paragraph>
+            {statistics.this_month.average_playback_speed !== null && (
+              <MetaText className="block mt-1">
+                {statistics.this_month.minutes_read} min of audio at {statistics.this_month.average_playback_speed}x
+              </MetaText>
+            )}
+          </HandDrawnBox>
+          <HandDrawnBox borderRadius={6} strokeWidth={1} linearCorners={true} className="p-4 bg-background-surface">
+            <MetaText className="block mb-1">Pages/Hour</MetaText>
+            <Paragraph className="text-2xl font-bold">
+              {statistics.reading_speed.pages_per_hour?.toFixed(1) ?? '-'}
+            </Paragraph>
+          </HandDrawnBox>
         </div>
       </Stack>
     </Section>
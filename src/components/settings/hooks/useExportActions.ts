@@ -26,11 +26,13 @@ export function useExportActions({
     try {
       setExporting(true);
       
-      const [booksData, sessionsData, notesData, goalsData] = await Promise.all([
+      const [booksData, sessionsData, notesData, goalsData, coversData, sessionPhotosData] = await Promise.all([
         invoke<any[]>('list_books', { filters: null }),
         invoke<any[]>('list_sessions', { filters: null }),
         invoke<any[]>('list_notes', { filters: null }),
         invoke<any[]>('list_goals', { filters: { include_inactive: true } }),
+        invoke<any[]>('backup_book_covers'),
+        invoke<any[]>('backup_session_photos'),
       ]);
 
       const backupData = {
@@ -41,6 +43,8 @@ export function useExportActions({
           sessions: sessionsData,
           notes: notesData,
           goals: goalsData,
+          covers: coversData,
+          session_photos: sessionPhotosData,
         },
       };
 
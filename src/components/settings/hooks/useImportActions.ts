@@ -32,7 +32,9 @@ export function useImportActions() {
               `- Books: ${backupData.data?.books?.length || 0}\n` +
               `- Sessions: ${backupData.data?.sessions?.length || 0}\n` +
               `- Notes: ${backupData.data?.notes?.length || 0}\n` +
-              `- Goals: ${backupData.data?.goals?.length || 0}\n\n` +
+              `- Goals: ${backupData.data?.goals?.length || 0}\n` +
+              `- Covers: ${backupData.data?.covers?.length || 0}\n` +
+              `- Session photos: ${backupData.data?.session_photos?.length || 0}\n\n` +
               `What would you like to do?`;
             
             confirm(preview + '\n\nOK = Merge with existing data\nCancel = Overwrite all data');
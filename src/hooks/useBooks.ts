@@ -1,6 +1,8 @@
 import { invoke } from '@tauri-apps/api/core';
 import { useState, useEffect, useCallback } from 'react';
 
+export type ProgressUnit = 'page' | 'percentage' | 'location';
+
 export interface BookDto {
   id: number | null;
   title: string;
@@ -13,6 +15,9 @@ export interface BookDto {
   total_minutes: number | null;
   current_page_text: number;
   current_minutes_audio: number;
+  progress_unit: ProgressUnit;
+  total_locations: number | null; // Kindle locations, for the location unit
+  current_position: number | null; // Current page as a percentage or location, for those units
   status: string;
   is_archived: boolean;
   is_wishlist: boolean;
@@ -22,6 +27,20 @@ export interface BookDto {
   updated_at: string;
   status_changed_at: string | null;
   progress_percentage: number;
+  prediction?: FinishPredictionDto | null;
+  next_in_series?: BookDto | null; // Next unread volume, set when an update completes the book
+}
+
+export interface FinishPredictionDto {
+  unit: 'pages' | 'minutes';
+  remaining: number;
+  pages_per_hour: number | null;
+  listening_speed: number | null; // Audio minutes per minute listened
+  speed_source: 'book' | 'global' | null;
+  per_day: number | null;
+  time_remaining_seconds: number | null;
+  time_remaining_formatted: string | null;
+  estimated_finish_date: string | null; // YYYY-MM-DD
 }
 
 export interface CreateBookCommand {
@@ -37,6 +56,8 @@ export interface CreateBookCommand {
   url?: string;
   is_wishlist?: boolean;
   status?: string;
+  progress_unit?: ProgressUnit; // Defaults to the defaultProgressUnit setting
+  total_locations?: number; // Required for the location unit
 }
 
 export function useBooks(filters?: {
@@ -211,11 +232,15 @@ export interface UpdateBookCommand {
   total_minutes?: number;
   current_page_text?: number;
   current_minutes_audio?: number;
+  progress_unit?: ProgressUnit;
+  total_locations?: number;
   status?: string;
   is_archived?: boolean;
   is_wishlist?: boolean;
   cover_url?: string;
   url?: string;
+  series_id?: number | null; // null removes the book from its series
+  series_index?: number | null; // null clears the volume number
 }
 
 export async function createBook(command: CreateBookCommand): Promise<BookDto> {
@@ -230,3 +255,46 @@ export async function deleteBook(id: number): Promise<void> {
   return await invoke<void>('delete_book', { id });
 }
 
+export interface ChapterDto {
+  id: number | null;
+  book_id: number;
+  position: number;
+  title: string;
+  start_seconds: number;
+  start_timestamp: string; // H:MM:SS
+}
+
+export interface ChapterInput {
+  title: string;
+  start: string; // H:MM:SS, M:SS or seconds into the recording
+}
+
+export interface ChapterProgressItemDto {
+  chapter: ChapterDto;
+  end_seconds: number | null;
+  listened_seconds: number;
+  percentage: number | null;
+  status: 'finished' | 'current' | 'upcoming';
+}
+
+export interface ChapterProgressDto {
+  book_id: number;
+  position_seconds: number;
+  total_seconds: number | null;
+  chapters_finished: number;
+  chapters_total: number;
+  current_chapter: ChapterProgressItemDto | null;
+  chapters: ChapterProgressItemDto[];
+}
+
+export async function listChapters(bookId: number): Promise<ChapterDto[]> {
+  return await invoke<ChapterDto[]>('list_chapters', { bookId });
+}
+
+export async function setChapters(bookId: number, chapters: ChapterInput[]): Promise<ChapterDto[]> {
+  return await invoke<ChapterDto[]>('set_chapters', { command: { book_id: bookId, chapters } });
+}
+
+export async function getChapterProgress(bookId: number): Promise<ChapterProgressDto> {
+  return await invoke<ChapterProgressDto>('get_chapter_progress', { bookId });
+}
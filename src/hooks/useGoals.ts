@@ -22,7 +22,34 @@ export interface CreateGoalCommand {
   is_active?: boolean;
 }
 
+export interface TodayStatistics {
+  date: string; // Local date in the user's time zone
+  pages_read: number;
+  minutes_read: number; // Audiobook minutes covered
+  listening_minutes: number; // Time spent listening, at the playback speeds used
+  sessions_count: number;
+  duration_seconds: number; // Includes the part of overnight sessions that fell on this day
+}
+
+export interface MonthStatistics {
+  pages_read: number;
+  minutes_read: number; // Audiobook minutes covered
+  listening_minutes: number; // Time spent listening, at the playback speeds used
+  average_playback_speed: number | null; // Minutes covered per minute listened
+  sessions_count: number;
+  books_completed: number;
+}
+
+export interface ReadingSpeedStatistics {
+  pages_per_hour: number | null;
+  listening_speed: number | null; // Audio minutes per minute listened
+  page_sessions_used: number;
+  audio_sessions_used: number;
+}
+
 export interface StatisticsDto {
+  today: TodayStatistics;
+  this_month: MonthStatistics;
   pages_read_this_month: number;
   total_pages_read: number;
   books_completed: number;
@@ -33,6 +60,7 @@ export interface StatisticsDto {
     month: number;
     pages: number;
   }>;
+  reading_speed: ReadingSpeedStatistics;
 }
 
 export function useGoals(includeInactive: boolean = false) {
@@ -1,6 +1,8 @@
 import { invoke } from '@tauri-apps/api/core';
 import { useState, useEffect, useCallback } from 'react';
 
+export type ReadingDevice = 'paper' | 'ereader' | 'phone' | 'tablet' | 'computer' | 'audio' | 'other';
+
 export interface SessionDto {
   id: number | null;
   book_id: number;
@@ -8,12 +10,27 @@ export interface SessionDto {
   session_date: string;
   start_time: string | null;
   end_time: string | null;
+  started_at: string | null; // RFC3339 with the local offset
+  ended_at: string | null; // RFC3339, may be after midnight
+  timezone: string | null; // IANA zone
   start_page: number | null;
   end_page: number | null;
   pages_read: number | null;
+  position_unit: 'percentage' | 'location' | null; // Set when positions were recorded instead of pages
+  start_position: number | null;
+  end_position: number | null;
   minutes_read: number | null;
   duration_seconds: number | null;
   photo_path: string | null;
+  playback_speed: number | null; // Audiobooks, e.g. 1.5
+  listening_seconds: number | null; // Audiobooks: time spent listening at the playback speed
+  location: string | null;
+  device: ReadingDevice | null;
+  mood_before: number | null; // 1-5
+  mood_after: number | null;
+  energy_before: number | null; // 1-5
+  energy_after: number | null;
+  interruptions: number | null;
   created_at: string;
   updated_at: string;
   duration_formatted: string;
@@ -24,20 +41,58 @@ export interface CreateSessionCommand {
   reading_id?: number | null;
   session_date: string; // YYYY-MM-DD
   start_time?: string | null; // HH:MM:SS
-  end_time?: string | null; // HH:MM:SS
+  end_time?: string | null; // HH:MM:SS, before start_time means after midnight
+  timezone?: string | null; // IANA zone, defaults to the timezone setting
   start_page?: number | null;
   end_page?: number | null;
-  minutes_read?: number | null;
+  start_position?: number | null; // Percentage or location books, instead of pages
+  end_position?: number | null;
+  minutes_read?: number | null; // Audiobooks; defaults to the timed minutes at the playback speed
+  playback_speed?: number | null; // Audiobooks, 0.5 to 4.0
+  confirm_warnings?: boolean; // Save despite overlap/page-order warnings
+  photo_paths?: string[] | null; // Page scans to copy into the app and attach
+  location?: string | null;
+  device?: ReadingDevice | null;
+  mood_before?: number | null; // 1-5
+  mood_after?: number | null;
+  energy_before?: number | null; // 1-5
+  energy_after?: number | null;
+  interruptions?: number | null;
 }
 
 export interface UpdateSessionCommand {
   id: number;
   session_date?: string; // YYYY-MM-DD
   start_time?: string | null; // HH:MM:SS
-  end_time?: string | null; // HH:MM:SS
+  end_time?: string | null; // HH:MM:SS, before start_time means after midnight
+  timezone?: string | null; // IANA zone, defaults to the timezone setting
   start_page?: number | null;
   end_page?: number | null;
+  start_position?: number | null; // Replaces the pages; pages given instead clear recorded positions
+  end_position?: number | null;
   minutes_read?: number | null;
+  playback_speed?: number | null;
+  confirm_warnings?: boolean;
+  location?: string | null; // An empty label clears it
+  device?: ReadingDevice | '' | null; // An empty value clears it
+  mood_before?: number | null;
+  mood_after?: number | null;
+  energy_before?: number | null;
+  energy_after?: number | null;
+  interruptions?: number | null;
+}
+
+export interface SessionIssueDto {
+  code: 'invalid_time' | 'page_out_of_range' | 'overlap' | 'page_regression';
+  severity: 'error' | 'warning';
+  message: string;
+  conflicting_session_id: number | null;
+}
+
+export interface SessionValidationDto {
+  is_valid: boolean;
+  needs_confirmation: boolean;
+  issues: SessionIssueDto[];
 }
 
 export function useSessions(filters?: {
@@ -168,12 +223,152 @@ export function useSession(id: number | null) {
   return { session, loading, error, refresh };
 }
 
+export async function validateNewSession(command: CreateSessionCommand): Promise<SessionValidationDto> {
+  return await invoke<SessionValidationDto>('validate_new_session', { command });
+}
+
+export async function validateSessionUpdate(command: UpdateSessionCommand): Promise<SessionValidationDto> {
+  return await invoke<SessionValidationDto>('validate_session_update', { command });
+}
+
+// Asks the user to confirm warnings; errors are left for the save to report
+function confirmSessionWarnings(validation: SessionValidationDto): boolean {
+  const warnings = validation.issues.filter((issue) => issue.severity === 'warning');
+  if (!validation.is_valid || warnings.length === 0) return false;
+  return confirm(`${warnings.map((w) => w.message).join('\n')}\n\nSave this session anyway?`);
+}
+
 export async function createSession(command: CreateSessionCommand): Promise<SessionDto> {
-  return await invoke<SessionDto>('create_session', { command });
+  const validation = await validateNewSession(command);
+  const confirm_warnings = command.confirm_warnings ?? confirmSessionWarnings(validation);
+  return await invoke<SessionDto>('create_session', { command: { ...command, confirm_warnings } });
 }
 
 export async function updateSession(command: UpdateSessionCommand): Promise<SessionDto> {
-  return await invoke<SessionDto>('update_session', { command });
+  const validation = await validateSessionUpdate(command);
+  const confirm_warnings = command.confirm_warnings ?? confirmSessionWarnings(validation);
+  return await invoke<SessionDto>('update_session', { command: { ...command, confirm_warnings } });
+}
+
+export interface ImportSessionsCsvCommand {
+  content: string; // Columns: title or isbn, date, start_time, end_time, start_page, end_page, minutes
+  timezone?: string | null;
+  confirm_warnings?: boolean;
+}
+
+export interface SessionCsvRowDto {
+  row_number: number;
+  title: string | null;
+  isbn: string | null;
+  book_id: number | null;
+  book_title: string | null;
+  session_date: string | null;
+  start_time: string | null;
+  end_time: string | null;
+  start_page: number | null;
+  end_page: number | null;
+  minutes_read: number | null;
+  warnings: SessionIssueDto[];
+  error: string | null;
+}
+
+export interface SessionCsvPreviewDto {
+  total_rows: number;
+  importable_count: number;
+  warning_count: number;
+  error_count: number;
+  rows: SessionCsvRowDto[];
+}
+
+export interface BulkSessionsResultDto {
+  created_count: number;
+  sessions: SessionDto[];
+  recalculated_book_ids: number[];
+  errors: { row_number: number; title: string | null; error: string }[];
+}
+
+export async function createSessionsBulk(sessions: CreateSessionCommand[], confirmWarnings = false): Promise<BulkSessionsResultDto> {
+  return await invoke<BulkSessionsResultDto>('create_sessions_bulk', {
+    command: { sessions, confirm_warnings: confirmWarnings },
+  });
+}
+
+export async function previewSessionsCsvImport(command: ImportSessionsCsvCommand): Promise<SessionCsvPreviewDto> {
+  return await invoke<SessionCsvPreviewDto>('preview_sessions_csv_import', { command });
+}
+
+export async function importSessionsCsv(command: ImportSessionsCsvCommand): Promise<BulkSessionsResultDto> {
+  return await invoke<BulkSessionsResultDto>('import_sessions_csv', { command });
+}
+
+export interface CorrectProgressCommand {
+  book_id: number;
+  mode: 'adjustment' | 'rebase'; // Add a session, or scale later sessions to end at the corrected value
+  current_page?: number | null; // Text books
+  current_minutes?: number | null; // Audiobooks
+  from_session_id?: number | null; // Rebase: first session to scale (default: all)
+  note?: string | null;
+}
+
+export interface SessionChangeDto {
+  session_id: number | null;
+  field: 'start_page' | 'end_page' | 'pages_read' | 'minutes_read';
+  old_value: number | null;
+  new_value: number | null;
+}
+
+export interface ProgressCorrectionDto {
+  id: number | null;
+  book_id: number;
+  mode: 'adjustment' | 'rebase';
+  unit: 'pages' | 'minutes';
+  previous_value: number;
+  corrected_value: number;
+  adjustment_session_id: number | null;
+  changes: SessionChangeDto[];
+  note: string | null;
+  created_at: string;
+}
+
+export async function correctProgress(command: CorrectProgressCommand): Promise<ProgressCorrectionDto> {
+  return await invoke<ProgressCorrectionDto>('correct_progress', { command });
+}
+
+export async function listProgressCorrections(bookId: number): Promise<ProgressCorrectionDto[]> {
+  return await invoke<ProgressCorrectionDto[]>('list_progress_corrections', { bookId });
+}
+
+export interface SessionPhotoDto {
+  id: number | null;
+  session_id: number;
+  hash: string;
+  mime_type: string;
+  width: number;
+  height: number;
+  source: string | null;
+  created_at: string;
+}
+
+export interface SessionPhotoImageDto {
+  photo_id: number;
+  mime_type: string;
+  data_url: string; // Usable as an <img> src
+}
+
+export async function attachSessionPhoto(sessionId: number, source: string): Promise<SessionPhotoDto> {
+  return await invoke<SessionPhotoDto>('attach_session_photo', { command: { session_id: sessionId, source } });
+}
+
+export async function listSessionPhotos(sessionId: number): Promise<SessionPhotoDto[]> {
+  return await invoke<SessionPhotoDto[]>('list_session_photos', { sessionId });
+}
+
+export async function getSessionPhoto(photoId: number, thumbnail = false): Promise<SessionPhotoImageDto> {
+  return await invoke<SessionPhotoImageDto>('get_session_photo', { photoId, thumbnail });
+}
+
+export async function deleteSessionPhoto(photoId: number): Promise<void> {
+  return await invoke<void>('delete_session_photo', { photoId });
 }
 
 export async function deleteSession(id: number): Promise<void> {
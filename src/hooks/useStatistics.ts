@@ -1,59 +1,27 @@
 import { invoke } from '@tauri-apps/api/core';
-import { useState, useEffect } from 'react';
 
-export interface StatisticsDto {
-  today: TodayStatistics;
-  this_month: MonthStatistics;
-  current_book: CurrentBookStatistics | null;
-}
-
-export interface TodayStatistics {
+export interface ContextBucketDto {
+  key: string; // Location label, device or morning/afternoon/evening/night
+  session_count: number;
   pages_read: number;
-  minutes_read: number;
-  sessions_count: number;
-  duration_seconds: number;
+  minutes_listened: number;
+  reading_seconds: number;
+  pages_per_hour: number | null;
+  average_mood_change: number | null; // After minus before
+  average_energy_change: number | null;
+  average_interruptions: number | null;
 }
 
-export interface MonthStatistics {
-  pages_read: number;
-  minutes_read: number;
-  sessions_count: number;
-  books_completed: number;
+export interface ContextStatisticsDto {
+  by_location: ContextBucketDto[];
+  by_device: ContextBucketDto[];
+  by_time_of_day: ContextBucketDto[];
 }
 
-export interface CurrentBookStatistics {
-  book_id: number;
-  title: string;
-  author: string | null;
-  cover_url: string | null;
-  current_page: number;
-  total_pages: number | null;
-  progress_percentage: number;
-  status: string;
+export async function getContextStatistics(filters?: {
+  book_id?: number;
+  start_date?: string;
+  end_date?: string;
+}): Promise<ContextStatisticsDto> {
+  return await invoke<ContextStatisticsDto>('get_context_statistics', { filters: filters ?? null });
 }
-
-export function useStatistics() {
-  const [statistics, setStatistics] = useState<StatisticsDto | null>(null);
-  const [loading, setLoading] = useState(true);
-  const [error, setError] = useState<string | null>(null);
-
-  useEffect(() => {
-    loadStatistics();
-  }, []);
-
-  const loadStatistics = async () => {
-    try {
-      setLoading(true);
-      setError(null);
-      const result = await invoke<StatisticsDto>('get_statistics');
-      setStatistics(result);
-    } catch (err) {
-      setError(err instanceof Error ? err.message : 'Failed to load statistics');
-    } finally {
-      setLoading(false);
-    }
-  };
-
-  return { statistics, loading, error, refresh: loadStatistics };
-}
-